@@ -0,0 +1,29 @@
+use std::env;
+use std::process;
+
+use graphviz_parser::diagnostic::Severity;
+use graphviz_parser::{DotGraph, ParseOptions};
+
+/// Usage: `cargo run --example lint -- <files>`
+/// Prints the problems found in each file, and fails if any of them is an error.
+fn main() {
+    let mut failed = false;
+    for filename in env::args().skip(1) {
+        let source = std::fs::read_to_string(&filename).expect("Cannot read file");
+        let options = ParseOptions {
+            recover: true,
+            ..ParseOptions::default()
+        };
+        let (graph, mut diagnostics) =
+            DotGraph::parse_with_options(&source, options).expect("Cannot parse file");
+        diagnostics.extend(graph.lint());
+        for diagnostic in diagnostics {
+            let (line, column) = diagnostic.line_col(&source);
+            println!("{filename}:{line}:{column}: {diagnostic}");
+            failed |= diagnostic.severity == Severity::Error;
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
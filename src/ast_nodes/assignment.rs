@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use super::ID;
 use crate::parse::Constructable;
 
@@ -6,10 +8,16 @@ use crate::lex::{Peekable, PeekableLexer, Token};
 /// This is the primary node capable of parsing
 /// constructs of the form `'ID' = 'ID'`
 /// from the this [spec](https://graphviz.org/doc/info/lang.html)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Assignment {
     pub lhs: ID,
     pub rhs: ID,
+    /// Whether `rhs` was written as an HTML string like `<<b>bold</b>>`,
+    /// rather than as a plain or quoted one
+    pub is_html: bool,
+    /// The byte range of the assignment in the input it was parsed from, which
+    /// is empty for assignments that weren't parsed
+    pub span: Range<usize>,
 }
 
 impl Assignment {
@@ -18,22 +26,40 @@ impl Assignment {
         Assignment {
             lhs: crate::lex::unquote_string(lhs),
             rhs: crate::lex::unquote_string(rhs),
+            is_html: crate::lex::is_html_string(rhs),
+            span: 0..0,
         }
     }
 }
 
+/// Assignments are equal if they assign the same value, wherever they are
+impl PartialEq for Assignment {
+    fn eq(&self, other: &Self) -> bool {
+        self.lhs == other.lhs && self.rhs == other.rhs && self.is_html == other.is_html
+    }
+}
+
 impl Constructable for Assignment {
     type Output = Self;
 
     fn from_lexer(
         mut lexer: PeekableLexer<'_>,
-    ) -> Result<(Self::Output, PeekableLexer), anyhow::Error> {
+    ) -> Result<(Self::Output, PeekableLexer<'_>), anyhow::Error> {
         if let Some(Token::ID(lhs)) = lexer.next() {
+            let start = lexer.span().start;
             if let Some(Token::Equals) = lexer.next() {
                 if let Some(Token::ID(rhs)) = lexer.next() {
                     let lhs = crate::lex::unquote_string(lhs);
+                    let is_html = crate::lex::is_html_string(rhs);
                     let rhs = crate::lex::unquote_string(rhs);
-                    return Ok((Self { lhs, rhs }, lexer));
+                    let span = start..lexer.span().end;
+                    let assignment = Self {
+                        lhs,
+                        rhs,
+                        is_html,
+                        span,
+                    };
+                    return Ok((assignment, lexer));
                 }
             }
         }
@@ -51,12 +77,13 @@ impl Constructable for AttributeList {
 
     fn from_lexer(
         mut token_stream: PeekableLexer<'_>,
-    ) -> Result<(Self::Output, PeekableLexer), anyhow::Error> {
+    ) -> Result<(Self::Output, PeekableLexer<'_>), anyhow::Error> {
         let mut result = vec![];
         if token_stream.peek() != Some(&Token::OpenBracket) {
             return Err(anyhow::anyhow!("Invalid token to construct attributeList"));
         }
-        while let Some(Token::OpenBracket) = token_stream.next() {
+        while let Some(Token::OpenBracket) = token_stream.peek() {
+            token_stream.next();
             token_stream.clear_filler();
             let agroup = AssignmentGroup::from_lexer(token_stream.clone())?;
             token_stream.clear_filler();
@@ -81,7 +108,7 @@ impl Constructable for AssignmentGroup {
 
     fn from_lexer(
         mut token_stream: PeekableLexer<'_>,
-    ) -> Result<(Self::Output, PeekableLexer), anyhow::Error> {
+    ) -> Result<(Self::Output, PeekableLexer<'_>), anyhow::Error> {
         let mut result = vec![];
         while let Ok((assignment, stream)) = Assignment::from_lexer(token_stream.clone()) {
             result.push(assignment);
@@ -148,6 +175,7 @@ mod tests {
         let assignment = Assignment::from_lexer(plexer).unwrap().0;
         assert_eq!(assignment.lhs, String::from("color"));
         assert_eq!(assignment.rhs, String::from("red"));
+        assert_eq!(assignment.span, 0..11);
     }
 
     #[test]
@@ -165,22 +193,13 @@ mod tests {
         let results: Vec<Assignment> = Vec::<Assignment>::from_lexer(plexer).unwrap().0;
         assert_eq!(results[0].lhs, String::from("color"));
         assert_eq!(results[0].rhs, String::from("red"));
-        assert_eq!(
-            results[1],
-            Assignment {
-                lhs: String::from("width"),
-                rhs: String::from("hello")
-            }
-        );
+        assert_eq!(results[1], Assignment::new("width", "hello"));
     }
 
     #[test]
     fn assignment_attribute_list_sanity_test() {
         let test_str = "[ color = red ][ color = red ]";
-        let valid = vec![Assignment {
-            lhs: String::from("color"),
-            rhs: String::from("red"),
-        }];
+        let valid = vec![Assignment::new("color", "red")];
         let plexer = PeekableLexer::from(test_str);
         let result: AttributeList = AttributeList::from_lexer(plexer).unwrap().0;
         assert_eq!(result[0], valid);
@@ -190,10 +209,7 @@ mod tests {
     #[test]
     fn assignment_attribute_list_sanity2_test() {
         let test_str = "[ color = red, color = red ][ color = red ]";
-        let valid = vec![Assignment {
-            lhs: String::from("color"),
-            rhs: String::from("red"),
-        }];
+        let valid = vec![Assignment::new("color", "red")];
         let plexer = PeekableLexer::from(test_str);
         let result: AttributeList = AttributeList::from_lexer(plexer).unwrap().0;
 
@@ -210,6 +226,15 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn assignment_attribute_list_stops_after_bracket_test() {
+        use crate::lex::{Peekable, Token};
+        let test_str = "[ color = red ] }";
+        let plexer = PeekableLexer::from(test_str);
+        let (_, mut rest) = AttributeList::from_lexer(plexer).unwrap();
+        assert_eq!(rest.peek(), Some(&Token::CloseParen));
+    }
+
     #[test]
     fn assignment_attr_test_penwidth() {
         let test_str = "[penwidth=5,arrowhead=none]";
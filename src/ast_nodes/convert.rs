@@ -0,0 +1,352 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{
+    AttributeList, Directed, Edge, EdgeLHS, EdgeRHS, Graph, GraphDirection, Statement, Subgraph,
+    Undirected, ID,
+};
+use crate::fold::{self, Fold};
+use crate::visit::{self, Visit};
+
+/// ReverseEdges decides what happens to edges that connect the same two nodes
+/// once a strict directed graph is converted into an undirected one
+///
+/// In a strict undirected graph `a -- b` and `b -- a` are the same edge, so
+/// converting `strict digraph { a -> b; b -> a }` produces a duplicate.
+///
+/// Only edge statements that directly connect two nodes without ports are
+/// considered. Edge chains, edges with subgraph endpoints and edges with
+/// ports are always kept, even when they duplicate another edge, as in
+/// `a -> b -> a` or `a:n -> b; b -> a`. Dropping or merging those would
+/// mean splitting statements up or losing their ports, so resolving the
+/// converted graph is what merges them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReverseEdges {
+    /// Keep every edge statement, and leave it to graphviz to merge them
+    Keep,
+    /// Drop every edge whose two nodes were already connected by an earlier edge
+    Drop,
+    /// Like `Drop`, but the attributes of the dropped edges are appended to the
+    /// first edge connecting the two nodes, so that later values take precedence
+    Merge,
+}
+
+impl<T> Statement<T> {
+    /// Re-types this statement for a graph of direction `U`, keeping its contents as is
+    pub fn into_direction<U: GraphDirection>(self) -> Statement<U> {
+        match self {
+            Statement::Node(node) => Statement::Node(node),
+            Statement::Edge(edge) => Statement::Edge(Box::new(edge.into_direction())),
+            Statement::Attribute(attribute) => Statement::Attribute(attribute),
+            Statement::Assignment(assignment) => Statement::Assignment(assignment),
+            Statement::Subgraph(subgraph) => {
+                Statement::Subgraph(Box::new(subgraph.into_direction()))
+            }
+            Statement::Error(error) => Statement::Error(error),
+        }
+    }
+}
+
+impl<T> Subgraph<T> {
+    /// Re-types this subgraph, and all statements inside of it, for a graph of direction `U`
+    pub fn into_direction<U: GraphDirection>(mut self) -> Subgraph<U> {
+        Subgraph {
+            id: self.id.take(),
+            statements: std::mem::take(&mut self.statements)
+                .into_iter()
+                .map(Statement::into_direction)
+                .collect(),
+        }
+    }
+}
+
+impl<T> Edge<T> {
+    /// Re-types this edge for a graph of direction `U`, so that `a -> b` becomes
+    /// `a -- b` and vice versa
+    ///
+    /// Edge chains are re-typed in a loop, so that long chains can't overflow the stack.
+    pub fn into_direction<U: GraphDirection>(self) -> Edge<U> {
+        let mut edges = vec![];
+        let mut edge = self;
+        let mut rhs: EdgeRHS<U> = loop {
+            let Edge {
+                lhs,
+                rhs,
+                attr_list,
+                ..
+            } = edge;
+            edges.push((lhs.into_direction(), attr_list));
+            match *rhs {
+                EdgeRHS::Edge(next) => edge = next,
+                EdgeRHS::Node(node) => break EdgeRHS::Node(node),
+                EdgeRHS::Subgraph(subgraph) => break EdgeRHS::Subgraph(subgraph.into_direction()),
+            }
+        };
+        loop {
+            let (lhs, attr_list) = edges.pop().expect("An edge has a left hand side");
+            let edge = Edge::new(lhs, rhs, attr_list);
+            if edges.is_empty() {
+                return edge;
+            }
+            rhs = EdgeRHS::Edge(edge);
+        }
+    }
+}
+
+impl<T> EdgeLHS<T> {
+    /// Re-types this endpoint for a graph of direction `U`
+    pub fn into_direction<U: GraphDirection>(self) -> EdgeLHS<U> {
+        match self {
+            EdgeLHS::Node(node) => EdgeLHS::Node(node),
+            EdgeLHS::Subgraph(subgraph) => EdgeLHS::Subgraph(subgraph.into_direction()),
+        }
+    }
+}
+
+impl<T> EdgeRHS<T> {
+    /// Re-types this endpoint for a graph of direction `U`
+    pub fn into_direction<U: GraphDirection>(self) -> EdgeRHS<U> {
+        match self {
+            EdgeRHS::Edge(edge) => EdgeRHS::Edge(edge.into_direction()),
+            EdgeRHS::Node(node) => EdgeRHS::Node(node),
+            EdgeRHS::Subgraph(subgraph) => EdgeRHS::Subgraph(subgraph.into_direction()),
+        }
+    }
+}
+
+impl Graph<Directed> {
+    /// Converts this `digraph` into an undirected `graph`
+    ///
+    /// `reverse_edges` is only consulted for strict graphs, as multi-edges are
+    /// allowed otherwise.
+    ///
+    /// ```
+    /// use graphviz_parser::DotGraph;
+    /// use graphviz_parser::ast_nodes::ReverseEdges;
+    /// use std::str::FromStr;
+    ///
+    /// let graph_str = "strict digraph G { a -> b; b -> a }";
+    /// if let DotGraph::Directed(graph) = DotGraph::from_str(graph_str).unwrap() {
+    ///     let graph = graph.into_undirected(ReverseEdges::Drop);
+    ///     assert_eq!(graph.statements.len(), 1);
+    /// }
+    /// ```
+    pub fn into_undirected(self, reverse_edges: ReverseEdges) -> Graph<Undirected> {
+        let graph = if self.is_strict && reverse_edges != ReverseEdges::Keep {
+            let mut duplicates = DuplicateFinder::default();
+            duplicates.visit_graph(&self);
+            let mut filter = DuplicateFilter {
+                policy: reverse_edges,
+                duplicates,
+                ordinal: 0,
+            };
+            filter.fold_graph(self)
+        } else {
+            self
+        };
+        let mut graph = graph;
+        Graph::new(
+            std::mem::take(&mut graph.id),
+            graph.is_strict,
+            std::mem::take(&mut graph.statements)
+                .into_iter()
+                .map(Statement::into_direction)
+                .collect(),
+        )
+    }
+}
+
+impl Graph<Undirected> {
+    /// Converts this undirected `graph` into a `digraph`, turning every `a -- b` into `a -> b`
+    pub fn into_directed(self) -> Graph<Directed> {
+        let mut graph = self;
+        Graph::new(
+            std::mem::take(&mut graph.id),
+            graph.is_strict,
+            std::mem::take(&mut graph.statements)
+                .into_iter()
+                .map(Statement::into_direction)
+                .collect(),
+        )
+    }
+}
+
+/// Returns the unordered pair of nodes an edge statement connects, if it
+/// connects exactly two nodes, neither of them with a port
+fn node_pair<T>(edge: &Edge<T>) -> Option<(ID, ID)> {
+    if edge.lhs.port().is_some() || edge.rhs.port().is_some() {
+        return None;
+    }
+    match (&edge.lhs, &*edge.rhs) {
+        (EdgeLHS::Node(lhs), EdgeRHS::Node(rhs)) if lhs.id <= rhs.id => {
+            Some((lhs.id.clone(), rhs.id.clone()))
+        }
+        (EdgeLHS::Node(lhs), EdgeRHS::Node(rhs)) => Some((rhs.id.clone(), lhs.id.clone())),
+        _ => None,
+    }
+}
+
+/// Numbers node to node edge statements in traversal order, and records which
+/// of them duplicate an earlier one
+#[derive(Default)]
+struct DuplicateFinder {
+    ordinal: usize,
+    first_seen: HashMap<(ID, ID), usize>,
+    dropped: HashSet<usize>,
+    merged: HashMap<usize, AttributeList>,
+}
+
+impl<'ast> Visit<'ast, Directed> for DuplicateFinder {
+    fn visit_statement(&mut self, statement: &'ast Statement<Directed>) {
+        if let Statement::Edge(edge) = statement {
+            if let Some(pair) = node_pair(edge) {
+                let ordinal = self.ordinal;
+                self.ordinal += 1;
+                if let Some(first) = self.first_seen.get(&pair) {
+                    self.dropped.insert(ordinal);
+                    self.merged
+                        .entry(*first)
+                        .or_default()
+                        .extend(edge.attr_list.iter().cloned());
+                } else {
+                    self.first_seen.insert(pair, ordinal);
+                }
+                return;
+            }
+        }
+        visit::visit_statement(self, statement);
+    }
+}
+
+/// Removes the duplicates found by [`DuplicateFinder`], merging their attributes if requested
+struct DuplicateFilter {
+    policy: ReverseEdges,
+    duplicates: DuplicateFinder,
+    ordinal: usize,
+}
+
+impl Fold<Directed> for DuplicateFilter {
+    fn fold_statement(&mut self, statement: Statement<Directed>) -> Vec<Statement<Directed>> {
+        match statement {
+            Statement::Edge(mut edge) if node_pair(&edge).is_some() => {
+                let ordinal = self.ordinal;
+                self.ordinal += 1;
+                if self.duplicates.dropped.contains(&ordinal) {
+                    return vec![];
+                }
+                if self.policy == ReverseEdges::Merge {
+                    if let Some(attributes) = self.duplicates.merged.remove(&ordinal) {
+                        edge.attr_list.extend(attributes);
+                    }
+                }
+                vec![Statement::Edge(edge)]
+            }
+            statement => fold::fold_statement(self, statement),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReverseEdges;
+    use crate::ast_nodes::{Assignment, Directed, EdgeLHS, Graph, Statement, Undirected};
+    use crate::lex::PeekableLexer;
+    use crate::parse::Constructable;
+
+    fn parse_directed(s: &str) -> Graph<Directed> {
+        Graph::<Directed>::from_lexer(PeekableLexer::from(s))
+            .unwrap()
+            .0
+    }
+
+    fn edges(graph: &Graph<Undirected>) -> Vec<(String, Vec<Assignment>)> {
+        graph
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::Edge(edge) => match &edge.lhs {
+                    EdgeLHS::Node(node) => Some((
+                        node.id.clone(),
+                        edge.attr_list.iter().flatten().cloned().collect(),
+                    )),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn convert_directed_round_trip_test() {
+        let g = parse_directed("digraph G { a -> b -> c\n subgraph s { { d } -> e } }");
+        let g = g.into_undirected(ReverseEdges::Drop);
+        assert_eq!(g.id, "G");
+        assert!(!g.is_directed());
+        assert_eq!(g.statements.len(), 2);
+        let g = g.into_directed();
+        assert!(g.is_directed());
+        assert_eq!(g.statements.len(), 2);
+    }
+
+    #[test]
+    fn convert_non_strict_keeps_reverse_edges_test() {
+        let g = parse_directed("digraph G { a -> b; b -> a }");
+        let g = g.into_undirected(ReverseEdges::Drop);
+        assert_eq!(g.statements.len(), 2);
+    }
+
+    #[test]
+    fn convert_strict_keep_reverse_edges_test() {
+        let g = parse_directed("strict digraph G { a -> b; b -> a }");
+        let g = g.into_undirected(ReverseEdges::Keep);
+        assert_eq!(g.statements.len(), 2);
+    }
+
+    #[test]
+    fn convert_strict_drop_reverse_edges_test() {
+        let g = parse_directed("strict digraph G { a -> b [color = red]; { b -> a } a -> c }");
+        let g = g.into_undirected(ReverseEdges::Drop);
+        assert_eq!(g.statements.len(), 3);
+        assert_eq!(
+            edges(&g),
+            vec![
+                ("a".to_string(), vec![Assignment::new("color", "red")]),
+                ("a".to_string(), vec![])
+            ]
+        );
+        if let Statement::Subgraph(subgraph) = &g.statements[1] {
+            assert!(subgraph.statements.is_empty());
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn convert_strict_chains_and_ports_are_kept_test() {
+        let g = parse_directed("strict digraph G { a -> b -> a; b -> a; a:n -> b; b -> a:s }");
+        let g = g.into_undirected(ReverseEdges::Drop);
+        // The chain is kept whole, and only counts as an edge of its own
+        assert_eq!(g.statements.len(), 4);
+        assert!(matches!(&g.statements[0], Statement::Edge(edge) if edge.rhs.port().is_none()));
+        let resolved = crate::DotGraph::Undirected(Box::new(g)).resolve();
+        assert_eq!(resolved.edges().len(), 1);
+    }
+
+    #[test]
+    fn convert_strict_merge_reverse_edges_test() {
+        let g = parse_directed(
+            "strict digraph G { a -> b [color = red]; b -> a [color = blue, weight = 2] }",
+        );
+        let g = g.into_undirected(ReverseEdges::Merge);
+        assert_eq!(
+            edges(&g),
+            vec![(
+                "a".to_string(),
+                vec![
+                    Assignment::new("color", "red"),
+                    Assignment::new("color", "blue"),
+                    Assignment::new("weight", "2"),
+                ]
+            )]
+        );
+    }
+}
@@ -1,14 +1,52 @@
 use super::assignment::AttributeList;
-use super::Node;
 use super::Subgraph;
+use super::{Node, Port};
 use std::marker::PhantomData;
 
 use crate::lex::Token;
 
-use crate::parse::{Constructable, ParseOR};
+use crate::lex::Peekable;
+use crate::parse::Constructable;
 
-pub(crate) trait GraphDirection {
-    fn token() -> Token<'static>;
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Directed {}
+    impl Sealed for super::Undirected {}
+}
+
+///
+/// GraphDirection is implemented by the [`Directed`] and [`Undirected`] marker types,
+/// and allows code to be written once for both kinds of graphs
+///
+/// ```
+/// use graphviz_parser::ast_nodes::{Graph, GraphDirection, Statement};
+///
+/// fn count_edges<T: GraphDirection>(graph: &Graph<T>) -> usize {
+///     graph
+///         .statements
+///         .iter()
+///         .filter(|statement| matches!(statement, Statement::Edge(_)))
+///         .count()
+/// }
+/// ```
+///
+/// This trait is sealed, and can't be implemented outside of this crate.
+pub trait GraphDirection: sealed::Sealed {
+    /// Whether edges in graphs of this direction have a tail and a head
+    fn is_directed() -> bool;
+
+    /// The edge operator used by graphs of this direction, i.e. `->` or `--`
+    fn edge_op() -> &'static str;
+}
+
+/// The edge operator token of the direction `T`
+pub(crate) fn edge_token<T: GraphDirection>() -> Token<'static> {
+    if T::is_directed() {
+        Token::DirectedEdge
+    } else {
+        Token::UndirectedEdge
+    }
 }
 
 ///
@@ -22,8 +60,12 @@ pub(crate) trait GraphDirection {
 pub struct Directed;
 
 impl GraphDirection for Directed {
-    fn token() -> Token<'static> {
-        Token::DirectedEdge
+    fn is_directed() -> bool {
+        true
+    }
+
+    fn edge_op() -> &'static str {
+        "->"
     }
 }
 
@@ -39,8 +81,12 @@ impl GraphDirection for Directed {
 pub struct Undirected;
 
 impl GraphDirection for Undirected {
-    fn token() -> Token<'static> {
-        Token::UndirectedEdge
+    fn is_directed() -> bool {
+        false
+    }
+
+    fn edge_op() -> &'static str {
+        "--"
     }
 }
 
@@ -50,30 +96,49 @@ pub enum EdgeLHS<T> {
     Subgraph(Subgraph<T>),
 }
 
+impl<T> EdgeLHS<T> {
+    /// The port of this endpoint, if it is a node with a port
+    pub fn port(&self) -> Option<&Port> {
+        match self {
+            EdgeLHS::Node(node) => node.port.as_ref(),
+            EdgeLHS::Subgraph(_) => None,
+        }
+    }
+}
+
+impl<T: GraphDirection> EdgeLHS<T> {
+    /// Parses a node, or a subgraph starting with `subgraph` or `{`
+    pub(crate) fn parse_endpoint(
+        mut token_stream: crate::lex::PeekableLexer,
+    ) -> anyhow::Result<(Self, crate::lex::PeekableLexer)> {
+        match token_stream.peek() {
+            Some(Token::Subgraph) | Some(Token::OpenParen) => {
+                let (subgraph, tok_s) = Subgraph::<T>::from_lexer(token_stream)?;
+                Ok((EdgeLHS::Subgraph(subgraph), tok_s))
+            }
+            _ => {
+                let (node, tok_s) = Node::from_lexer(token_stream)?;
+                Ok((EdgeLHS::Node(node), tok_s))
+            }
+        }
+    }
+}
+
 impl<T: GraphDirection> Constructable for EdgeLHS<T> {
     type Output = Self;
 
     fn from_lexer(
         token_stream: crate::lex::PeekableLexer,
     ) -> anyhow::Result<(Self::Output, crate::lex::PeekableLexer), anyhow::Error> {
-        let option = ParseOR::<Subgraph<T>, Node>::from_lexer(token_stream.clone())?;
-        match option {
-            (
-                ParseOR {
-                    t_val: None,
-                    v_val: Some(node),
-                },
-                tok_s,
-            ) => Ok((EdgeLHS::Node(node), tok_s)),
-            (
-                ParseOR {
-                    t_val: Some(subgraph),
-                    v_val: None,
-                },
-                tok_s,
-            ) => Ok((EdgeLHS::Subgraph(subgraph), tok_s)),
-            _ => Err(anyhow::anyhow!("Couldn't parse Edge LHS")),
+        // An id directly followed by a block is taken to name a subgraph as well
+        let mut lookahead = token_stream.clone();
+        if let Some(Token::ID(_)) = lookahead.next() {
+            if lookahead.peek() == Some(&Token::OpenParen) {
+                let (subgraph, tok_s) = Subgraph::<T>::from_lexer(token_stream)?;
+                return Ok((EdgeLHS::Subgraph(subgraph), tok_s));
+            }
         }
+        Self::parse_endpoint(token_stream)
     }
 }
 
@@ -84,36 +149,43 @@ pub enum EdgeRHS<T> {
     Subgraph(Subgraph<T>),
 }
 
+impl<T> EdgeRHS<T> {
+    /// The port of the endpoint directly following the edge operator,
+    /// if it is a node with a port
+    pub fn port(&self) -> Option<&Port> {
+        match self {
+            EdgeRHS::Edge(edge) => edge.lhs.port(),
+            EdgeRHS::Node(node) => node.port.as_ref(),
+            EdgeRHS::Subgraph(_) => None,
+        }
+    }
+}
+
 impl<T: GraphDirection> Constructable for EdgeRHS<T> {
     type Output = Self;
 
     fn from_lexer(
         token_stream: crate::lex::PeekableLexer,
     ) -> anyhow::Result<(Self::Output, crate::lex::PeekableLexer), anyhow::Error> {
-        let (options, token_stream) =
-            ParseOR::<Edge<T>, ParseOR<Node, Subgraph<T>>>::from_lexer(token_stream)?;
-        match options {
-            ParseOR {
-                t_val: Some(edge),
-                v_val: None,
-            } => Ok((EdgeRHS::Edge(edge), token_stream)),
-            ParseOR {
-                t_val: None,
-                v_val:
-                    Some(ParseOR {
-                        t_val: Some(node),
-                        v_val: None,
-                    }),
-            } => Ok((EdgeRHS::Node(node), token_stream)),
-            ParseOR {
-                t_val: None,
-                v_val:
-                    Some(ParseOR {
-                        t_val: None,
-                        v_val: Some(subgraph),
-                    }),
-            } => Ok((EdgeRHS::Subgraph(subgraph), token_stream)),
-            _ => Err(anyhow::anyhow!("Couldn't construct EdgeRHS")),
+        let (endpoint, mut token_stream) = EdgeLHS::<T>::from_lexer(token_stream)?;
+        match token_stream.peek() {
+            Some(Token::DirectedEdge) | Some(Token::UndirectedEdge) => {
+                let (endpoints, attr_list, tok_s) = Edge::<T>::parse_chain(token_stream)?;
+                Ok((
+                    EdgeRHS::Edge(Edge::from_chain(endpoint, endpoints, attr_list)),
+                    tok_s,
+                ))
+            }
+            _ => Ok((endpoint.into(), token_stream)),
+        }
+    }
+}
+
+impl<T> From<EdgeLHS<T>> for EdgeRHS<T> {
+    fn from(endpoint: EdgeLHS<T>) -> Self {
+        match endpoint {
+            EdgeLHS::Node(node) => EdgeRHS::Node(node),
+            EdgeLHS::Subgraph(subgraph) => EdgeRHS::Subgraph(subgraph),
         }
     }
 }
@@ -131,6 +203,8 @@ impl<T: GraphDirection> Constructable for EdgeRHS<T> {
 /// For example, the graph `{A B} -> {D E}` defines the following edges:
 /// (A, D), (A,E), (B, D), (B, E)
 ///
+/// The attributes of a chain like `a -> b -> c [color=red]` apply to all of
+/// its edges, but are only stored in the `attr_list` of the outermost edge.
 ///
 ///
 #[derive(Debug)]
@@ -141,57 +215,88 @@ pub struct Edge<T> {
     pub attr_list: AttributeList,
 }
 
+/// The deviation reported for an edge operator that doesn't match a graph of direction `T`
+pub(crate) fn mismatched_operator<T: GraphDirection>() -> &'static str {
+    if T::is_directed() {
+        "`--` used in a directed graph; use `->` instead, or declare the graph with `graph`"
+    } else {
+        "`->` used in an undirected graph; use `--` instead, or declare the graph with `digraph`"
+    }
+}
+
+impl<T> Edge<T> {
+    /// Creates a new edge from its endpoints and attributes
+    pub fn new(lhs: EdgeLHS<T>, rhs: EdgeRHS<T>, attr_list: AttributeList) -> Self {
+        Self {
+            lhs,
+            rhs: Box::new(rhs),
+            ty: PhantomData,
+            attr_list,
+        }
+    }
+}
+
+impl<T: GraphDirection> Edge<T> {
+    /// Parses the part of an edge statement following its first endpoint,
+    /// i.e. the remaining endpoints and the attributes of the statement
+    ///
+    /// The chain is parsed in a loop rather than recursively, so that long
+    /// chains like `a -> b -> c -> ...` can't overflow the stack.
+    pub(crate) fn parse_chain(
+        mut token_stream: crate::lex::PeekableLexer,
+    ) -> anyhow::Result<(Vec<EdgeLHS<T>>, AttributeList, crate::lex::PeekableLexer)> {
+        let mut endpoints = vec![];
+        while let Some(Token::DirectedEdge) | Some(Token::UndirectedEdge) = token_stream.peek() {
+            if token_stream.next() != Some(edge_token::<T>()) {
+                let span = token_stream.span();
+                token_stream.deviation(span, mismatched_operator::<T>().to_string())?;
+            }
+            let (endpoint, tok_s) = EdgeLHS::<T>::parse_endpoint(token_stream)?;
+            endpoints.push(endpoint);
+            token_stream = tok_s;
+        }
+        if endpoints.is_empty() {
+            return Err(anyhow::anyhow!("Couldn't find {} for Edge", T::edge_op()));
+        }
+        // A node at the end of the chain has already consumed the attributes
+        let mut attr_list = match endpoints.last_mut() {
+            Some(EdgeLHS::Node(node)) => node.attribute_list.take().unwrap_or_default(),
+            _ => vec![],
+        };
+        if let Ok((attributes, tok_s)) = AttributeList::from_lexer(token_stream.clone()) {
+            attr_list = attributes;
+            token_stream = tok_s;
+        }
+        Ok((endpoints, attr_list, token_stream))
+    }
+
+    /// Builds the edge going from `lhs` through all of `endpoints`, where
+    /// the attributes of the statement are kept on the outermost edge only
+    pub(crate) fn from_chain(
+        lhs: EdgeLHS<T>,
+        mut endpoints: Vec<EdgeLHS<T>>,
+        attr_list: AttributeList,
+    ) -> Self {
+        let mut rhs: EdgeRHS<T> = endpoints
+            .pop()
+            .expect("An edge has at least two endpoints")
+            .into();
+        while let Some(endpoint) = endpoints.pop() {
+            rhs = EdgeRHS::Edge(Edge::new(endpoint, rhs, vec![]));
+        }
+        Edge::new(lhs, rhs, attr_list)
+    }
+}
+
 impl<T: GraphDirection> Constructable for Edge<T> {
     type Output = Self;
 
     fn from_lexer(
         token_stream: crate::lex::PeekableLexer,
     ) -> anyhow::Result<(Self::Output, crate::lex::PeekableLexer), anyhow::Error> {
-        let (lhs, mut token_stream) = EdgeLHS::<T>::from_lexer(token_stream)?;
-        if Some(T::token()) == token_stream.next() {
-            let (mut rhs, mut token_stream) = EdgeRHS::<T>::from_lexer(token_stream)?;
-            let mut attributes = vec![];
-            match AttributeList::from_lexer(token_stream.clone()) {
-                Ok((attr_list, t_stream)) => {
-                    attributes = attr_list;
-                    token_stream = t_stream;
-                }
-                Err(_) => {} // TODO: Address issues
-            };
-            if let EdgeRHS::Edge(Edge {
-                lhs: _,
-                rhs: _,
-                ty: _,
-                attr_list: attribs,
-            }) = &rhs
-            {
-                attributes = attribs.clone();
-            } else if let EdgeRHS::Node(Node {
-                id: _,
-                port: _,
-                attribute_list: attribs,
-            }) = &mut rhs
-            {
-                let moved_attributes = attribs.take();
-                attributes = match moved_attributes {
-                    Some(v) => v,
-                    None => vec![],
-                };
-            }
-            Ok((
-                Self {
-                    lhs,
-                    rhs: Box::new(rhs),
-                    ty: PhantomData,
-                    attr_list: attributes,
-                },
-                token_stream,
-            ))
-        } else {
-            Err(anyhow::anyhow!(
-                "Couldn't find directed edge for Edge<Directed>"
-            ))
-        }
+        let (lhs, token_stream) = EdgeLHS::<T>::from_lexer(token_stream)?;
+        let (endpoints, attr_list, token_stream) = Self::parse_chain(token_stream)?;
+        Ok((Self::from_chain(lhs, endpoints, attr_list), token_stream))
     }
 }
 
@@ -199,10 +304,28 @@ impl<T: GraphDirection> Constructable for Edge<T> {
 mod tests {
 
     use super::{EdgeLHS, EdgeRHS};
-    use crate::ast_nodes::{edge::Directed, Edge};
+    use crate::ast_nodes::{edge::Directed, CompassPoint, Edge};
     use crate::lex::PeekableLexer;
     use crate::parse::Constructable;
 
+    #[test]
+    fn edge_endpoint_ports_test() {
+        let test_str = "A:f0:ne -> B:s -> {C}";
+        let pb = PeekableLexer::from(test_str);
+        let edge = Edge::<Directed>::from_lexer(pb).unwrap().0;
+        let tail = edge.lhs.port().unwrap();
+        assert_eq!(tail.id.as_deref(), Some("f0"));
+        assert_eq!(tail.compass_point, Some(CompassPoint::NorthEast));
+        let head = edge.rhs.port().unwrap();
+        assert_eq!(head.id, None);
+        assert_eq!(head.compass_point, Some(CompassPoint::South));
+        if let EdgeRHS::Edge(next) = &*edge.rhs {
+            assert!(next.rhs.port().is_none());
+        } else {
+            panic!("Expected an edge chain");
+        }
+    }
+
     #[test]
     fn edge_directed_statement_sanity_node_test() {
         let test_str = "A -> B";
@@ -296,4 +419,41 @@ mod tests {
             unreachable!()
         }
     }
+
+    #[test]
+    fn edge_chain_attributes_test() {
+        let pb = PeekableLexer::from("A -> B -> C [color=red]");
+        let edge = Edge::<Directed>::from_lexer(pb).unwrap().0;
+        assert_eq!(edge.attr_list[0].len(), 1);
+        if let EdgeRHS::Edge(next) = &*edge.rhs {
+            assert!(next.attr_list.is_empty());
+        } else {
+            panic!("Expected an edge chain");
+        }
+    }
+
+    #[test]
+    fn edge_mismatched_operator_test() {
+        use crate::ast_nodes::Undirected;
+        use crate::{Conformance, ParseOptions};
+
+        let pb = PeekableLexer::from("a -> b -- c");
+        let (edge, pb) = Edge::<Undirected>::from_lexer(pb).unwrap();
+        assert!(matches!(&*edge.rhs, EdgeRHS::Edge(_)));
+        let warnings = pb.warnings.to_vec();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].span, 2..4);
+        assert_eq!(
+            warnings[0].message,
+            super::mismatched_operator::<Undirected>()
+        );
+
+        let options = ParseOptions {
+            conformance: Conformance::Strict,
+            ..ParseOptions::default()
+        };
+        let pb = PeekableLexer::with_options("a -- b", options);
+        let error = Edge::<Directed>::from_lexer(pb).unwrap_err();
+        assert_eq!(error.to_string(), super::mismatched_operator::<Directed>());
+    }
 }
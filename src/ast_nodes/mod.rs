@@ -16,9 +16,9 @@
 //! let dot_graph = DotGraph::from_str("graph G { a; b; c; }").unwrap();
 //! let mut node_ids = vec![];
 //! if let DotGraph::Directed(graph) = dot_graph {
-//!    for statement in graph.statements {
+//!    for statement in &graph.statements {
 //!       if let Node(n) = statement {
-//!             node_ids.push(n.id);
+//!             node_ids.push(n.id.as_str());
 //!       }
 //!    }
 //!    assert_eq!(node_ids, vec!["a", "b", "c"]);
@@ -26,6 +26,7 @@
 //! ```
 
 mod assignment;
+mod convert;
 mod edge;
 mod node;
 mod statement;
@@ -33,11 +34,16 @@ mod subgraph;
 
 use std::marker::PhantomData;
 
-pub use assignment::{Assignment, AssignmentGroup, AttributeList};
-pub use edge::{Directed, Undirected};
+pub use assignment::{Assignment, AssignmentGroup, AttributeList, AttributeStatement};
+pub use convert::ReverseEdges;
+#[cfg(test)]
+pub(crate) use edge::mismatched_operator;
+pub use edge::{Directed, GraphDirection, Undirected};
 pub use edge::{Edge, EdgeLHS, EdgeRHS};
-pub use node::Node;
+pub use node::{CompassPoint, Node, Port};
+pub use statement::ErrorStatement;
 pub use statement::Statement;
+pub(crate) use statement::COMMA_SEPARATOR;
 pub use subgraph::Subgraph;
 
 /// An ID represents any identifier used inside
@@ -86,51 +92,91 @@ pub struct Graph<T> {
     _pd: PhantomData<T>,
 }
 
-impl Constructable for Graph<Directed> {
-    type Output = Self;
-    fn from_lexer(
-        mut token_stream: PeekableLexer,
-    ) -> anyhow::Result<(Self::Output, PeekableLexer), anyhow::Error> {
-        let mut is_strict = false;
-        if token_stream.peek() == Some(&Token::Strict) {
-            token_stream.next();
-            is_strict = true;
+impl<T> Graph<T> {
+    /// Creates a new graph from its parts
+    pub fn new(id: ID, is_strict: bool, statements: Vec<Statement<T>>) -> Self {
+        Self {
+            id,
+            is_strict,
+            statements,
+            _pd: PhantomData,
         }
-        match token_stream.next() {
-            Some(Token::Digraph) => {
-		let graph_id = match token_stream.peek() {
-		    Some(Token::ID(graph_id)) => {
-			let graph_id = crate::lex::unquote_string(graph_id);
-			token_stream.next();
-			graph_id
-		    }
-		    _ => String::new(), // Missing graph id is interpreted to be empty
-		};
-		match token_stream.next() {
-                    Some(Token::OpenParen) => {
-			let (statements, tstream) =
-                            Vec::<Statement<Directed>>::from_lexer(token_stream)?;
-			Ok((
-                            Self {
-				id: graph_id,
-				statements,
-				is_strict,
-				_pd: PhantomData,
-                            },
-                            tstream,
-			))
-                    }
-                    _ => {
-			Err(anyhow::anyhow!("Error; expected either graph name or {"))
-                    }
-		}
-	    },
-            _ => Err(anyhow::anyhow!("Error; invalid start token")),
+    }
+}
+
+impl<T: GraphDirection> Graph<T> {
+    /// Returns true if this is a `digraph`
+    pub fn is_directed(&self) -> bool {
+        T::is_directed()
+    }
+}
+
+/// Dropping a long edge chain recursively could overflow the stack, so the
+/// graphs and subgraphs owning them take the chains apart one edge at a time
+fn unlink_chains<T>(statements: &mut [Statement<T>]) {
+    for statement in statements {
+        if let Statement::Edge(edge) = statement {
+            let mut rhs = std::mem::replace(&mut *edge.rhs, EdgeRHS::Node(Node::empty()));
+            while let EdgeRHS::Edge(next) = rhs {
+                rhs = *next.rhs;
+            }
         }
     }
 }
 
-impl Constructable for Graph<Undirected> {
+impl<T> Drop for Graph<T> {
+    fn drop(&mut self) {
+        unlink_chains(&mut self.statements);
+    }
+}
+
+/// Rejects a graph whose blocks are nested deeper than its parse options allow,
+/// before any of it is parsed
+fn check_nesting(token_stream: &PeekableLexer) -> anyhow::Result<()> {
+    let max_depth = token_stream.options.max_depth;
+    if 1 + token_stream.nesting_depth() > max_depth {
+        return Err(anyhow::anyhow!(
+            "Error; blocks are nested more than {} levels deep",
+            max_depth
+        ));
+    }
+    Ok(())
+}
+
+/// Consumes the `}` closing a graph
+///
+/// When [recovering](crate::ParseOptions::recover) from errors, whatever
+/// couldn't be parsed before it is skipped and recorded as an error statement.
+fn close_graph<'a, T>(
+    mut token_stream: PeekableLexer<'a>,
+    statements: &mut Vec<Statement<T>>,
+) -> anyhow::Result<PeekableLexer<'a>> {
+    token_stream.clear_filler();
+    if token_stream.peek() == Some(&Token::CloseParen) {
+        token_stream.next();
+        return Ok(token_stream);
+    }
+    if !token_stream.options.recover {
+        return Err(anyhow::anyhow!(
+            "Error; expected }} at the end of the graph"
+        ));
+    }
+    let mut rest = token_stream.clone();
+    rest.next();
+    let start = rest.span().start;
+    if !token_stream.skip_past_block_end() {
+        return Err(anyhow::anyhow!(
+            "Error; expected }} at the end of the graph"
+        ));
+    }
+    let rest = token_stream.source()[start..token_stream.span().start].trim_end();
+    let span = start..start + rest.len();
+    let message = format!("Couldn't parse the rest of the graph: {}", rest);
+    statements.push(Statement::Error(Box::new(ErrorStatement { span, message })));
+    Ok(token_stream)
+}
+
+impl<T: GraphDirection> Constructable for Graph<T> {
     type Output = Self;
     fn from_lexer(
         mut token_stream: PeekableLexer,
@@ -140,27 +186,82 @@ impl Constructable for Graph<Undirected> {
             token_stream.next();
             is_strict = true;
         }
-
+        let graph_token = if T::is_directed() {
+            Token::Digraph
+        } else {
+            Token::Graph
+        };
+        if token_stream.next() != Some(graph_token) {
+            return Err(anyhow::anyhow!("Error; invalid start token"));
+        }
+        let graph_id = match token_stream.peek() {
+            Some(Token::ID(graph_id)) => {
+                let graph_id = crate::lex::unquote_string(graph_id);
+                token_stream.next();
+                graph_id
+            }
+            _ => String::new(), // Missing graph id is interpreted to be empty
+        };
         match token_stream.next() {
-            Some(Token::Graph) => match (token_stream.next(), token_stream.next()) {
-                (Some(Token::ID(graph_id)), Some(Token::OpenParen)) => {
-                    let (statements, tstream) =
-                        Vec::<Statement<Undirected>>::from_lexer(token_stream)?;
-                    Ok((
-                        Self {
-                            id: crate::lex::unquote_string(graph_id),
-                            statements,
-                            is_strict,
-                            _pd: PhantomData,
-                        },
-                        tstream,
-                    ))
-                }
-                _ => {
-                    todo!()
-                }
-            },
-            _ => Err(anyhow::anyhow!("Error; invalid start token")),
+            Some(Token::OpenParen) => {
+                check_nesting(&token_stream)?;
+                let (mut statements, tstream) = Vec::<Statement<T>>::from_lexer(token_stream)?;
+                let tstream = close_graph(tstream, &mut statements)?;
+                Ok((
+                    Self {
+                        id: graph_id,
+                        statements,
+                        is_strict,
+                        _pd: PhantomData,
+                    },
+                    tstream,
+                ))
+            }
+            _ => Err(anyhow::anyhow!("Error; expected either graph name or {{")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{close_graph, Directed, Graph, Statement};
+    use crate::diagnostic::Severity;
+    use crate::lex::{Peekable, PeekableLexer};
+    use crate::{Conformance, ParseOptions};
+
+    #[test]
+    fn close_graph_leftovers_test() {
+        let source = "a ] { b } } c";
+        for conformance in [Conformance::Lenient, Conformance::Strict] {
+            let options = ParseOptions {
+                conformance,
+                ..ParseOptions::default()
+            };
+            let mut statements = Vec::<Statement<Directed>>::new();
+            let lexer = PeekableLexer::with_options(source, options);
+            let error = close_graph(lexer, &mut statements).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                "Error; expected } at the end of the graph"
+            );
+
+            let options = ParseOptions {
+                recover: true,
+                ..options
+            };
+            let mut statements = Vec::<Statement<Directed>>::new();
+            let mut rest = close_graph(
+                PeekableLexer::with_options(source, options),
+                &mut statements,
+            )
+            .unwrap();
+            assert!(rest.warnings.to_vec().is_empty());
+            assert!(rest.peek().is_some());
+            let graph = Graph::new(String::new(), false, statements);
+            let diagnostics = crate::diagnostic::collect(&graph);
+            assert_eq!(diagnostics.len(), 1);
+            assert_eq!(diagnostics[0].severity, Severity::Error);
+            assert_eq!(&source[diagnostics[0].span.clone()], "a ] { b }");
         }
     }
 }
@@ -1,12 +1,142 @@
+use std::ops::Range;
+
 use crate::parse::Constructable;
 
 use super::{assignment::AttributeList, ID};
 use crate::lex::{Peekable, Token};
 
-#[derive(Debug)]
+/// A compass point names the side or corner of a node an edge attaches to
+///
+/// These correspond to the compass_pt production in the graphviz
+/// [spec](https://graphviz.org/doc/info/lang.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompassPoint {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+    /// `c`, the center of the node
+    Center,
+    /// `_`, any side of the node that is appropriate
+    Any,
+}
+
+impl CompassPoint {
+    /// The identifier of this compass point as it is written in dot files
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::North => "n",
+            Self::NorthEast => "ne",
+            Self::East => "e",
+            Self::SouthEast => "se",
+            Self::South => "s",
+            Self::SouthWest => "sw",
+            Self::West => "w",
+            Self::NorthWest => "nw",
+            Self::Center => "c",
+            Self::Any => "_",
+        }
+    }
+}
+
+impl std::str::FromStr for CompassPoint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "n" => Ok(Self::North),
+            "ne" => Ok(Self::NorthEast),
+            "e" => Ok(Self::East),
+            "se" => Ok(Self::SouthEast),
+            "s" => Ok(Self::South),
+            "sw" => Ok(Self::SouthWest),
+            "w" => Ok(Self::West),
+            "nw" => Ok(Self::NorthWest),
+            "c" => Ok(Self::Center),
+            "_" => Ok(Self::Any),
+            other => Err(anyhow::anyhow!("Invalid compass point value: {}", other)),
+        }
+    }
+}
+
+impl std::fmt::Display for CompassPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A port names the place of a node an edge attaches to,
+/// as in `node:port`, `node:port:ne` or `node:ne`
+///
+/// Following the spec, a port that consists of a single identifier
+/// is a compass point if the identifier is one, and a port name otherwise.
+#[derive(Debug, Clone)]
 pub struct Port {
-    pub id: ID,
-    pub compass_point: Option<ID>,
+    pub id: Option<ID>,
+    pub compass_point: Option<CompassPoint>,
+    /// The byte range of the port, including its leading `:`, in the input
+    /// it was parsed from, which is empty for ports that weren't parsed
+    pub span: Range<usize>,
+}
+
+/// Ports are equal if they name the same place, wherever they are
+impl PartialEq for Port {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.compass_point == other.compass_point
+    }
+}
+
+impl Port {
+    /// Builds a port out of the one or two identifiers following a node id
+    fn from_ids(id: ID, compass_id: Option<ID>) -> anyhow::Result<Self> {
+        match compass_id {
+            Some(compass_id) => Ok(Self {
+                id: Some(id),
+                compass_point: Some(compass_id.parse()?),
+                span: 0..0,
+            }),
+            None => match id.parse::<CompassPoint>() {
+                Ok(compass_point) => Ok(Self {
+                    id: None,
+                    compass_point: Some(compass_point),
+                    span: 0..0,
+                }),
+                Err(_) => Ok(Self {
+                    id: Some(id),
+                    compass_point: None,
+                    span: 0..0,
+                }),
+            },
+        }
+    }
+}
+
+/// Parses a port as it is given to the `tailport` and `headport` attributes,
+/// i.e. `port`, `port:compass_point` or `compass_point`
+impl std::str::FromStr for Port {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some((id, compass_id)) => Self::from_ids(id.to_string(), Some(compass_id.to_string())),
+            None => Self::from_ids(s.to_string(), None),
+        }
+    }
+}
+
+impl std::fmt::Display for Port {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.id, &self.compass_point) {
+            (Some(id), Some(compass_point)) => write!(f, "{}:{}", id, compass_point),
+            (Some(id), None) => write!(f, "{}", id),
+            (None, Some(compass_point)) => write!(f, "{}", compass_point),
+            (None, None) => Ok(()),
+        }
+    }
 }
 
 impl Constructable for Port {
@@ -14,33 +144,24 @@ impl Constructable for Port {
     fn from_lexer(
         mut token_stream: crate::lex::PeekableLexer,
     ) -> anyhow::Result<(Self::Output, crate::lex::PeekableLexer), anyhow::Error> {
-        // format: ':' ID [ ':' port ]
+        // format: ':' ID [ ':' compass_pt ] | ':' compass_pt
         if let Some(Token::Colon) = token_stream.next() {
+            let start = token_stream.span().start;
             if let Some(Token::ID(id)) = token_stream.next() {
                 let id = crate::lex::unquote_string(id);
-                if let Some(Token::Colon) = token_stream.peek() {
+                let mut port = if let Some(Token::Colon) = token_stream.peek() {
                     token_stream.next();
                     if let Some(Token::ID(compass_id)) = token_stream.next() {
                         let compass_id = crate::lex::unquote_string(compass_id);
-                        Ok((
-                            Self {
-                                id,
-                                compass_point: Some(compass_id),
-                            },
-                            token_stream,
-                        ))
+                        Self::from_ids(id, Some(compass_id))?
                     } else {
-                        Err(anyhow::anyhow!("Invalid compass point value"))
+                        return Err(anyhow::anyhow!("Invalid compass point value"));
                     }
                 } else {
-                    Ok((
-                        Self {
-                            id,
-                            compass_point: None,
-                        },
-                        token_stream,
-                    ))
-                }
+                    Self::from_ids(id, None)?
+                };
+                port.span = start..token_stream.span().end;
+                Ok((port, token_stream))
             } else {
                 Err(anyhow::anyhow!("Invalid syntax for port"))
             }
@@ -61,6 +182,17 @@ pub struct Node {
     pub attribute_list: Option<AttributeList>,
 }
 
+impl Node {
+    /// A node without an id, which doesn't allocate
+    pub(crate) fn empty() -> Self {
+        Self {
+            id: String::new(),
+            port: None,
+            attribute_list: None,
+        }
+    }
+}
+
 impl Constructable for Node {
     type Output = Self;
     fn from_lexer(
@@ -107,7 +239,7 @@ mod tests {
     use crate::lex::PeekableLexer;
     use crate::parse::Constructable;
 
-    use super::{Node, Port};
+    use super::{CompassPoint, Node, Port};
 
     /*
      * PORT TESTS
@@ -117,9 +249,9 @@ mod tests {
         let test_str = ":tst:nw";
         let pb = PeekableLexer::from(test_str);
         let port = Port::from_lexer(pb).unwrap().0;
-        assert_eq!(port.id, String::from("tst"));
+        assert_eq!(port.id, Some(String::from("tst")));
         let cmp = port.compass_point.unwrap();
-        assert_eq!(cmp, String::from("nw"));
+        assert_eq!(cmp, CompassPoint::NorthWest);
     }
 
     #[test]
@@ -127,10 +259,62 @@ mod tests {
         let test_str = ":tst";
         let pb = PeekableLexer::from(test_str);
         let port = Port::from_lexer(pb).unwrap().0;
-        assert_eq!(port.id, String::from("tst"));
+        assert_eq!(port.id, Some(String::from("tst")));
         assert_eq!(port.compass_point, None);
     }
 
+    #[test]
+    fn port_statement_only_compass_test() {
+        let test_str = ":n";
+        let pb = PeekableLexer::from(test_str);
+        let port = Port::from_lexer(pb).unwrap().0;
+        assert_eq!(port.id, None);
+        assert_eq!(port.compass_point, Some(CompassPoint::North));
+        assert_eq!(port.span, 0..2);
+    }
+
+    #[test]
+    fn port_statement_compass_named_port_test() {
+        let test_str = ":n:_";
+        let pb = PeekableLexer::from(test_str);
+        let port = Port::from_lexer(pb).unwrap().0;
+        assert_eq!(port.id, Some(String::from("n")));
+        assert_eq!(port.compass_point, Some(CompassPoint::Any));
+    }
+
+    #[test]
+    fn port_statement_invalid_compass_test() {
+        let test_str = ":tst:north";
+        let pb = PeekableLexer::from(test_str);
+        assert!(Port::from_lexer(pb).is_err());
+    }
+
+    #[test]
+    fn port_from_str_test() {
+        let port: Port = "f0:se".parse().unwrap();
+        assert_eq!(port.id, Some(String::from("f0")));
+        assert_eq!(port.compass_point, Some(CompassPoint::SouthEast));
+        assert_eq!(port.to_string(), "f0:se");
+
+        let port: Port = "c".parse().unwrap();
+        assert_eq!(port.id, None);
+        assert_eq!(port.compass_point, Some(CompassPoint::Center));
+        assert_eq!(port.to_string(), "c");
+
+        assert!("f0:up".parse::<Port>().is_err());
+    }
+
+    #[test]
+    fn node_statement_compass_port_test() {
+        let test_str = "A:sw [color = red]";
+        let lexer = PeekableLexer::from(test_str);
+        let node = Node::from_lexer(lexer).unwrap().0;
+        let port = node.port.unwrap();
+        assert_eq!(port.id, None);
+        assert_eq!(port.compass_point, Some(CompassPoint::SouthWest));
+        assert!(node.attribute_list.is_some());
+    }
+
     /*
      * NODE TESTS
      */
@@ -1,12 +1,18 @@
+use std::ops::Range;
+
+use crate::diagnostic::Deviation;
 use crate::lex::Peekable;
 use crate::parse::Constructable;
 
 use super::assignment::*;
 use super::edge::GraphDirection;
-use super::{Edge, Node, Subgraph};
+use super::{Edge, EdgeLHS, Node, Subgraph};
 
 use crate::lex::Token;
 
+/// The warning about statements separated by `,`, which graphviz accepts
+pub(crate) const COMMA_SEPARATOR: &str = "Statements separated by `,` instead of `;`";
+
 #[derive(Debug)]
 pub enum Statement<T> {
     Node(Box<Node>),
@@ -14,6 +20,17 @@ pub enum Statement<T> {
     Attribute(Box<AttributeStatement>),
     Assignment(Box<Assignment>),
     Subgraph(Box<Subgraph<T>>),
+    /// A statement that couldn't be parsed, only produced when
+    /// [recovering](crate::ParseOptions::recover) from errors
+    Error(Box<ErrorStatement>),
+}
+
+/// The input skipped over when recovering from a malformed statement
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorStatement {
+    /// The byte range of the skipped input
+    pub span: Range<usize>,
+    pub message: String,
 }
 
 impl<T: GraphDirection> Constructable for Statement<T> {
@@ -23,24 +40,58 @@ impl<T: GraphDirection> Constructable for Statement<T> {
         mut token_stream: crate::lex::PeekableLexer,
     ) -> anyhow::Result<(Self, crate::lex::PeekableLexer), anyhow::Error> {
         token_stream.clear_filler();
-        if let Ok((assignment, tok_stream)) = Assignment::from_lexer(token_stream.clone()) {
-            Ok((Self::Assignment(Box::new(assignment)), tok_stream))
-        } else if let Ok((edge, tok_stream)) = Edge::<T>::from_lexer(token_stream.clone()) {
-            Ok((Self::Edge(Box::new(edge)), tok_stream))
-        } else if let Ok((node, tok_stream)) = Node::from_lexer(token_stream.clone()) {
-            Ok((Self::Node(Box::new(node)), tok_stream))
-        } else if let Ok((attribute, tok_stream)) =
-            AttributeStatement::from_lexer(token_stream.clone())
-        {
-            Ok((Self::Attribute(Box::new(attribute)), tok_stream))
-        } else if let Ok((subgraph, tok_stream)) = Subgraph::<T>::from_lexer(token_stream.clone()) {
-            Ok((Self::Subgraph(Box::new(subgraph)), tok_stream))
-        } else {
-            Err(anyhow::anyhow!("Invalid statement"))
+        // The first token decides the kind of statement, so that nothing is
+        // parsed twice. Otherwise nested subgraphs would take exponential time.
+        match token_stream.peek() {
+            Some(Token::Graph) | Some(Token::Node) | Some(Token::Edge) => {
+                let (attribute, tok_stream) = AttributeStatement::from_lexer(token_stream)?;
+                Ok((Self::Attribute(Box::new(attribute)), tok_stream))
+            }
+            Some(Token::ID(_)) | Some(Token::Subgraph) | Some(Token::OpenParen) => {
+                if let Ok((assignment, tok_stream)) = Assignment::from_lexer(token_stream.clone()) {
+                    return Ok((Self::Assignment(Box::new(assignment)), tok_stream));
+                }
+                // Either a node or subgraph statement, or the first endpoint of an edge statement
+                let (lhs, mut token_stream) = EdgeLHS::<T>::parse_endpoint(token_stream)?;
+                if let Some(Token::DirectedEdge) | Some(Token::UndirectedEdge) = token_stream.peek()
+                {
+                    // Past an edge operator this can only be an edge statement
+                    let (endpoints, attr_list, tok_stream) = Edge::<T>::parse_chain(token_stream)?;
+                    return Ok((
+                        Self::Edge(Box::new(Edge::from_chain(lhs, endpoints, attr_list))),
+                        tok_stream,
+                    ));
+                }
+                match lhs {
+                    EdgeLHS::Node(node) => Ok((Self::Node(Box::new(node)), token_stream)),
+                    EdgeLHS::Subgraph(subgraph) => {
+                        Ok((Self::Subgraph(Box::new(subgraph)), token_stream))
+                    }
+                }
+            }
+            _ => Err(anyhow::anyhow!("Invalid statement")),
         }
     }
 }
 
+/// Checks that a statement is followed by a separator, the end of the block,
+/// or the start of another statement, rather than by leftovers of itself
+fn ends_statement(token_stream: &crate::lex::PeekableLexer) -> bool {
+    matches!(
+        token_stream.clone().peek(),
+        None | Some(Token::SemiColon)
+            | Some(Token::Comma)
+            | Some(Token::NewLine)
+            | Some(Token::CloseParen)
+            | Some(Token::OpenParen)
+            | Some(Token::ID(_))
+            | Some(Token::Subgraph)
+            | Some(Token::Graph)
+            | Some(Token::Node)
+            | Some(Token::Edge)
+    )
+}
+
 impl<T: GraphDirection> Constructable for Vec<Statement<T>> {
     type Output = Self;
 
@@ -48,12 +99,46 @@ impl<T: GraphDirection> Constructable for Vec<Statement<T>> {
         mut token_stream: crate::lex::PeekableLexer,
     ) -> anyhow::Result<(Self::Output, crate::lex::PeekableLexer), anyhow::Error> {
         let mut statements = vec![];
-        while let Ok(statement) = Statement::<T>::from_lexer(token_stream.clone()) {
-            token_stream = statement.1;
-            statements.push(statement.0);
+        let recover = token_stream.options.recover;
+        loop {
+            token_stream.clear_filler();
+            if let None | Some(&Token::CloseParen) = token_stream.peek() {
+                break;
+            }
+            match Statement::<T>::from_lexer(token_stream.clone()) {
+                Ok((statement, tok_stream)) if !recover || ends_statement(&tok_stream) => {
+                    token_stream = tok_stream;
+                    statements.push(statement);
+                }
+                Err(error) if !recover => return Err(error),
+                result => {
+                    let span = token_stream.skip_statement();
+                    let message = match result {
+                        Err(error) if error.is::<Deviation>() => error.to_string(),
+                        _ => format!(
+                            "Invalid statement: {}",
+                            &token_stream.source()[span.clone()]
+                        ),
+                    };
+                    statements.push(Statement::Error(Box::new(ErrorStatement { span, message })));
+                }
+            }
             match token_stream.peek() {
-                Some(&Token::SemiColon) | Some(&Token::Comma) => {
+                Some(&Token::SemiColon) => {
+                    token_stream.next();
+                }
+                Some(&Token::Comma) => {
                     token_stream.next();
+                    let span = token_stream.span();
+                    let message = String::from(COMMA_SEPARATOR);
+                    if let Err(error) = token_stream.deviation(span.clone(), message) {
+                        if !recover {
+                            return Err(error);
+                        }
+                        let message = error.to_string();
+                        statements
+                            .push(Statement::Error(Box::new(ErrorStatement { span, message })));
+                    }
                 }
                 _ => {} // Intentional no-op
             };
@@ -94,4 +179,58 @@ mod tests {
         let result = Statement::<Directed>::from_lexer(pbl).unwrap().0;
         assert!(matches!(result, Statement::Assignment { .. }));
     }
+
+    #[test]
+    fn statement_list_recovery_test() {
+        use super::super::ErrorStatement;
+        use crate::ParseOptions;
+
+        let test_str = "a -> ; b [color = red]\n c -> @ d { e ] }\n f";
+        let options = ParseOptions {
+            recover: true,
+            ..ParseOptions::default()
+        };
+        let pbl = PeekableLexer::with_options(test_str, options);
+        let (statements, mut rest) = Vec::<Statement<Directed>>::from_lexer(pbl).unwrap();
+        assert_eq!(statements.len(), 4);
+        assert!(matches!(&statements[0], Statement::Error(e) if e.span == (0..4)));
+        assert!(matches!(&statements[1], Statement::Node(_)));
+        match &statements[2] {
+            Statement::Error(e) => {
+                let ErrorStatement { span, message } = &**e;
+                assert_eq!(&test_str[span.clone()], "c -> @ d { e ] }");
+                assert_eq!(message, "Invalid statement: c -> @ d { e ] }");
+            }
+            other => panic!("Expected an error statement, got {:?}", other),
+        }
+        assert!(matches!(&statements[3], Statement::Node(_)));
+        assert!(rest.next().is_none());
+
+        // Without recovery the first malformed statement is an error
+        assert!(Vec::<Statement<Directed>>::from_lexer(PeekableLexer::from(test_str)).is_err());
+    }
+
+    #[test]
+    fn statement_list_errors_test() {
+        use crate::{DotGraph, ParseOptions};
+        use std::str::FromStr;
+
+        for source in [
+            "digraph { a -> ; b }",
+            "digraph { a; $$ ; b; c }",
+            "digraph { a [color=red; b }",
+            "digraph { a -> }",
+            "digraph { { a } -> }",
+        ] {
+            assert!(DotGraph::from_str(source).is_err(), "{}", source);
+            let options = ParseOptions::default();
+            assert!(
+                DotGraph::parse_with_options(source, options).is_err(),
+                "{}",
+                source
+            );
+        }
+        let graph = DotGraph::from_str("digraph { a; b -> c\n { d } }").unwrap();
+        assert_eq!(graph.statements().len(), 3);
+    }
 }
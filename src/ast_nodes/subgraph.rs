@@ -12,6 +12,12 @@ pub struct Subgraph<T> {
     pub statements: Vec<Statement<T>>,
 }
 
+impl<T> Drop for Subgraph<T> {
+    fn drop(&mut self) {
+        super::unlink_chains(&mut self.statements);
+    }
+}
+
 impl<T: GraphDirection> Constructable for Subgraph<T> {
     type Output = Self;
 
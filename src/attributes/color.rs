@@ -0,0 +1,478 @@
+//! Colors, in the formats documented [here](https://graphviz.org/docs/attr-types/color/)
+//!
+//! A color is either given by its RGB(A) or HSV components, or by a name that
+//! is looked up in a color scheme:
+//!
+//! ```
+//! use graphviz_parser::attributes::color::{Color, Rgba};
+//! use std::str::FromStr;
+//!
+//! let red = Rgba::rgb(255, 0, 0);
+//! assert_eq!(Color::from_str("#ff0000").unwrap().to_rgba(None), Some(red));
+//! assert_eq!(Color::from_str("0.000 1.000 1.000").unwrap().to_rgba(None), Some(red));
+//! assert_eq!(Color::from_str("Red").unwrap().to_rgba(None), Some(red));
+//! assert_eq!(Color::from_str("/set13/1").unwrap().to_rgba(None), Some(Rgba::rgb(228, 26, 28)));
+//! // `colorscheme` applies to names without a scheme of their own
+//! assert_eq!(Color::from_str("1").unwrap().to_rgba(Some("set13")), Some(Rgba::rgb(228, 26, 28)));
+//! ```
+//!
+//! The X11, SVG and Brewer schemes are all complete, so the Brewer schemes can
+//! be qualitative like `set13`, sequential like `blues9` or diverging like
+//! `rdylgn11`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail};
+
+use super::color_names::{BREWER, BREWER_SCALES, SVG, X11};
+
+/// A color given by its red, green, blue and alpha components
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgba {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: u8,
+}
+
+impl Rgba {
+    /// An opaque color
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Rgba {
+            red,
+            green,
+            blue,
+            alpha: 255,
+        }
+    }
+
+    /// The color of `transparent`, `invis` and `none`
+    pub const TRANSPARENT: Self = Rgba {
+        red: 255,
+        green: 255,
+        blue: 254,
+        alpha: 0,
+    };
+
+    fn from_components([red, green, blue]: [u8; 3]) -> Self {
+        Self::rgb(red, green, blue)
+    }
+}
+
+/// Parses `#rrggbb` or `#rrggbbaa`, where the components may be separated by
+/// whitespace
+impl FromStr for Rgba {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s
+            .trim()
+            .strip_prefix('#')
+            .ok_or_else(|| anyhow!("Expected a color starting with #, found {}", s))?;
+        let digits: String = hex.split_whitespace().collect();
+        if !(digits.len() == 6 || digits.len() == 8) || !digits.is_ascii() {
+            bail!("Expected 6 or 8 hexadecimal digits in color {}", s);
+        }
+        let mut components = [255; 4];
+        for (component, index) in components.iter_mut().zip((0..digits.len()).step_by(2)) {
+            *component = u8::from_str_radix(&digits[index..index + 2], 16)
+                .map_err(|_| anyhow!("Expected 6 or 8 hexadecimal digits in color {}", s))?;
+        }
+        let [red, green, blue, alpha] = components;
+        Ok(Rgba {
+            red,
+            green,
+            blue,
+            alpha,
+        })
+    }
+}
+
+impl fmt::Display for Rgba {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)?;
+        if self.alpha != 255 {
+            write!(f, "{:02x}", self.alpha)?;
+        }
+        Ok(())
+    }
+}
+
+/// A color, as written in an attribute value
+#[derive(Debug, Clone, PartialEq)]
+pub enum Color {
+    Rgba(Rgba),
+    /// HSV components, each between 0 and 1
+    Hsv {
+        hue: f64,
+        saturation: f64,
+        value: f64,
+    },
+    /// A color name, which is looked up in `scheme` if it's written as
+    /// `/scheme/name`, and in the `colorscheme` attribute otherwise
+    ///
+    /// A name written as `//name` has an empty scheme, which stands for X11.
+    Named {
+        scheme: Option<String>,
+        name: String,
+    },
+}
+
+/// Looks up a name in a color scheme, both of which are case-insensitive
+fn lookup(scheme: &str, name: &str) -> Option<Rgba> {
+    let scheme = scheme.to_ascii_lowercase();
+    let name = name.to_ascii_lowercase();
+    let table = match scheme.as_str() {
+        "" | "x11" => X11,
+        "svg" => SVG,
+        _ => return brewer(&scheme, &name),
+    };
+    table
+        .binary_search_by(|(entry, _)| (*entry).cmp(name.as_str()))
+        .ok()
+        .map(|index| Rgba::from_components(table[index].1))
+}
+
+/// Looks up the `index`th color of a Brewer scheme like `accent5` or `blues9`
+fn brewer(scheme: &str, index: &str) -> Option<Rgba> {
+    let index = usize::from_str(index).ok()?;
+    let size = |palette: &str| usize::from_str(scheme.strip_prefix(palette)?).ok();
+    let qualitative = BREWER.iter().find_map(|(palette, smallest, colors)| {
+        let size = size(palette)?;
+        if !(*smallest..=colors.len()).contains(&size) {
+            return None;
+        }
+        Some(&colors[..size])
+    });
+    let colors = qualitative.or_else(|| {
+        BREWER_SCALES
+            .iter()
+            .find_map(|(palette, sizes)| sizes.get(size(palette)?.checked_sub(3)?).copied())
+    })?;
+    if !(1..=colors.len()).contains(&index) {
+        return None;
+    }
+    Some(Rgba::from_components(colors[index - 1]))
+}
+
+/// Converts HSV components between 0 and 1 to RGB
+fn hsv_to_rgba(hue: f64, saturation: f64, value: f64) -> Rgba {
+    let to_u8 = |component: f64| (component * 255.0).round() as u8;
+    let hue = hue * 6.0 % 6.0;
+    let sector = hue.floor();
+    let fraction = hue - sector;
+    let p = value * (1.0 - saturation);
+    let q = value * (1.0 - saturation * fraction);
+    let t = value * (1.0 - saturation * (1.0 - fraction));
+    let (red, green, blue) = match sector as u8 {
+        0 => (value, t, p),
+        1 => (q, value, p),
+        2 => (p, value, t),
+        3 => (p, q, value),
+        4 => (t, p, value),
+        _ => (value, p, q),
+    };
+    Rgba::rgb(to_u8(red), to_u8(green), to_u8(blue))
+}
+
+impl Color {
+    /// Converts the color to RGBA, looking names without a scheme up in
+    /// `colorscheme`
+    ///
+    /// As in graphviz, names that aren't in `colorscheme` are looked up in
+    /// X11. Returns `None` if the name is unknown.
+    pub fn to_rgba(&self, colorscheme: Option<&str>) -> Option<Rgba> {
+        match self {
+            Color::Rgba(rgba) => Some(*rgba),
+            Color::Hsv {
+                hue,
+                saturation,
+                value,
+            } => Some(hsv_to_rgba(*hue, *saturation, *value)),
+            Color::Named { name, .. }
+                if ["transparent", "invis", "none"]
+                    .iter()
+                    .any(|keyword| keyword.eq_ignore_ascii_case(name)) =>
+            {
+                Some(Rgba::TRANSPARENT)
+            }
+            Color::Named {
+                scheme: Some(scheme),
+                name,
+            } => lookup(scheme, name),
+            Color::Named { scheme: None, name } => colorscheme
+                .and_then(|scheme| lookup(scheme, name))
+                .or_else(|| lookup("x11", name)),
+        }
+    }
+
+    /// Returns false if the color is a name no `colorscheme` could resolve
+    pub(super) fn could_resolve(&self) -> bool {
+        match self {
+            Color::Named { scheme: None, name } => {
+                self.to_rgba(Some("svg")).is_some() || usize::from_str(name).is_ok()
+            }
+            color => color.to_rgba(None).is_some(),
+        }
+    }
+}
+
+/// Parses HSV components separated by commas and/or whitespace
+fn hsv(s: &str) -> Option<anyhow::Result<Color>> {
+    let components: Vec<_> = s
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|component| !component.is_empty())
+        .collect();
+    if components.len() != 3 {
+        return None;
+    }
+    let mut values = [0.0; 3];
+    for (value, component) in values.iter_mut().zip(components) {
+        *value = f64::from_str(component).ok()?;
+    }
+    if values.iter().any(|value| !(0.0..=1.0).contains(value)) {
+        return Some(Err(anyhow!(
+            "HSV components must be between 0 and 1 in color {}",
+            s
+        )));
+    }
+    let [hue, saturation, value] = values;
+    Some(Ok(Color::Hsv {
+        hue,
+        saturation,
+        value,
+    }))
+}
+
+impl FromStr for Color {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.starts_with('#') {
+            return Ok(Color::Rgba(Rgba::from_str(trimmed)?));
+        }
+        if trimmed.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+            if let Some(color) = hsv(trimmed) {
+                return color;
+            }
+        }
+        let (scheme, name) = match trimmed.strip_prefix('/') {
+            Some(rest) => {
+                let (scheme, name) = rest
+                    .split_once('/')
+                    .ok_or_else(|| anyhow!("Expected a color like /scheme/name, found {}", s))?;
+                (Some(scheme.to_string()), name)
+            }
+            None => (None, trimmed),
+        };
+        if name.is_empty() || name.contains(|c: char| c.is_whitespace() || c == '/') {
+            bail!("Invalid color name {}", s);
+        }
+        Ok(Color::Named {
+            scheme,
+            name: name.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Rgba(rgba) => rgba.fmt(f),
+            Color::Hsv {
+                hue,
+                saturation,
+                value,
+            } => write!(f, "{:.3} {:.3} {:.3}", hue, saturation, value),
+            Color::Named {
+                scheme: Some(scheme),
+                name,
+            } => write!(f, "/{}/{}", scheme, name),
+            Color::Named { scheme: None, name } => f.write_str(name),
+        }
+    }
+}
+
+/// A color in a [`ColorList`], with the fraction of the area it fills
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedColor {
+    pub color: Color,
+    pub weight: Option<f64>,
+}
+
+/// Colors separated by `:`, each optionally followed by `;` and a weight
+/// between 0 and 1
+///
+/// Weights must add up to at most 1; the colors without one share what is
+/// left.
+///
+/// ```
+/// use graphviz_parser::attributes::color::{Color, ColorList};
+/// use std::str::FromStr;
+///
+/// let colors = ColorList::from_str("red;0.3:/svg/green:blue").unwrap();
+/// assert_eq!(colors.0.len(), 3);
+/// assert_eq!(colors.0[0].weight, Some(0.3));
+/// assert_eq!(colors.to_string(), "red;0.3:/svg/green:blue");
+/// assert!(ColorList::from_str("red;0.6:blue;0.6").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorList(pub Vec<WeightedColor>);
+
+impl FromStr for ColorList {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut colors = vec![];
+        let mut total = 0.0;
+        for item in s.trim().split(':') {
+            let (color, weight) = match item.split_once(';') {
+                Some((color, weight)) => {
+                    let weight = f64::from_str(weight.trim())
+                        .ok()
+                        .filter(|weight| (0.0..=1.0).contains(weight))
+                        .ok_or_else(|| anyhow!("Invalid weight {} in color list {}", weight, s))?;
+                    total += weight;
+                    (color, Some(weight))
+                }
+                None => (item, None),
+            };
+            colors.push(WeightedColor {
+                color: Color::from_str(color)?,
+                weight,
+            });
+        }
+        // Allow for rounding errors in weights like 0.1
+        if total > 1.0 + 1e-9 {
+            bail!("The weights of color list {} add up to more than 1", s);
+        }
+        Ok(ColorList(colors))
+    }
+}
+
+impl fmt::Display for ColorList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, weighted) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(":")?;
+            }
+            write!(f, "{}", weighted.color)?;
+            if let Some(weight) = weighted.weight {
+                write!(f, ";{}", weight)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, ColorList, Rgba};
+    use crate::attributes::color_names::{BREWER, BREWER_SCALES, SVG, X11};
+    use std::str::FromStr;
+
+    fn rgba(s: &str, colorscheme: Option<&str>) -> Option<Rgba> {
+        Color::from_str(s).unwrap().to_rgba(colorscheme)
+    }
+
+    #[test]
+    fn color_tables_test() {
+        for table in [X11, SVG] {
+            assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+        for (_, smallest, colors) in BREWER {
+            assert!(*smallest <= colors.len());
+        }
+        for (_, sizes) in BREWER_SCALES {
+            assert!(sizes
+                .iter()
+                .enumerate()
+                .all(|(i, colors)| colors.len() == i + 3));
+        }
+    }
+
+    #[test]
+    fn color_parse_test() {
+        assert_eq!(
+            Color::from_str("#40e0d0").unwrap(),
+            Color::Rgba(Rgba::rgb(64, 224, 208))
+        );
+        assert_eq!(
+            Color::from_str("#ff 00 00 80").unwrap(),
+            Color::Rgba(Rgba {
+                red: 255,
+                green: 0,
+                blue: 0,
+                alpha: 128
+            })
+        );
+        assert_eq!(
+            Color::from_str(".051,.718,.627").unwrap(),
+            Color::Hsv {
+                hue: 0.051,
+                saturation: 0.718,
+                value: 0.627
+            }
+        );
+        assert_eq!(
+            Color::from_str("/accent3/2").unwrap(),
+            Color::Named {
+                scheme: Some("accent3".to_string()),
+                name: "2".to_string()
+            }
+        );
+        assert!(Color::from_str("#ff00").is_err());
+        assert!(Color::from_str("#gg0000").is_err());
+        assert!(Color::from_str("0.5 1.5 0").is_err());
+        assert!(Color::from_str("/x11").is_err());
+        assert!(Color::from_str("").is_err());
+        for s in ["#ff000080", "0.500 1.000 0.250", "//red", "/svg/Green", "5"] {
+            assert_eq!(Color::from_str(s).unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn color_resolve_test() {
+        assert_eq!(rgba("DarkSlateGray", None), Some(Rgba::rgb(47, 79, 79)));
+        assert_eq!(rgba("green", None), Some(Rgba::rgb(0, 255, 0)));
+        assert_eq!(rgba("green", Some("svg")), Some(Rgba::rgb(0, 128, 0)));
+        assert_eq!(rgba("/svg/green", Some("x11")), Some(Rgba::rgb(0, 128, 0)));
+        assert_eq!(rgba("//green", Some("svg")), Some(Rgba::rgb(0, 255, 0)));
+        // Names that aren't in the colorscheme fall back to X11
+        assert_eq!(
+            rgba("turquoise1", Some("svg")),
+            Some(Rgba::rgb(0, 245, 255))
+        );
+        assert_eq!(rgba("/svg/turquoise1", None), None);
+        assert_eq!(rgba("teal", None), None);
+        assert_eq!(rgba("8", Some("Paired12")), Some(Rgba::rgb(255, 127, 0)));
+        assert_eq!(rgba("4", Some("set33")), None);
+        assert_eq!(rgba("/set32/1", None), None);
+        assert_eq!(rgba("/blues9/1", None), Some(Rgba::rgb(247, 251, 255)));
+        assert_eq!(rgba("/blues3/1", None), Some(Rgba::rgb(222, 235, 247)));
+        assert_eq!(rgba("4", Some("RdYlGn11")), Some(Rgba::rgb(253, 174, 97)));
+        assert_eq!(rgba("/blues9/10", None), None);
+        assert_eq!(rgba("/blues10/1", None), None);
+        assert_eq!(rgba("/rdylgn2/1", None), None);
+        assert_eq!(rgba("invis", None), Some(Rgba::TRANSPARENT));
+        assert_eq!(rgba("0 0 0.5", None), Some(Rgba::rgb(128, 128, 128)));
+        assert_eq!(rgba("0.6667 1 1", None), Some(Rgba::rgb(0, 0, 255)));
+        assert_eq!(rgba("1 1 1", None), Some(Rgba::rgb(255, 0, 0)));
+        assert!(Color::from_str("teal").unwrap().could_resolve());
+        assert!(Color::from_str("3").unwrap().could_resolve());
+        assert!(!Color::from_str("reddish").unwrap().could_resolve());
+    }
+
+    #[test]
+    fn color_list_test() {
+        let colors = ColorList::from_str("yellow;0.3:blue").unwrap();
+        assert_eq!(colors.0[0].color, Color::from_str("yellow").unwrap());
+        assert_eq!(colors.0[1].weight, None);
+        assert!(ColorList::from_str("0.1 0.2 0.3;0.1:0.4,0.5,0.6;0.9").is_ok());
+        assert!(ColorList::from_str("red;1.5").is_err());
+        assert!(ColorList::from_str("red;x").is_err());
+        assert!(ColorList::from_str("red::blue").is_err());
+    }
+}
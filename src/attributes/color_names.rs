@@ -0,0 +1,2705 @@
+//! The color names graphviz knows about
+
+/// The X11 color scheme, which is the default one, sorted by name
+pub(super) static X11: &[(&str, [u8; 3])] = &[
+    ("aliceblue", [240, 248, 255]),
+    ("antiquewhite", [250, 235, 215]),
+    ("antiquewhite1", [255, 239, 219]),
+    ("antiquewhite2", [238, 223, 204]),
+    ("antiquewhite3", [205, 192, 176]),
+    ("antiquewhite4", [139, 131, 120]),
+    ("aquamarine", [127, 255, 212]),
+    ("aquamarine1", [127, 255, 212]),
+    ("aquamarine2", [118, 238, 198]),
+    ("aquamarine3", [102, 205, 170]),
+    ("aquamarine4", [69, 139, 116]),
+    ("azure", [240, 255, 255]),
+    ("azure1", [240, 255, 255]),
+    ("azure2", [224, 238, 238]),
+    ("azure3", [193, 205, 205]),
+    ("azure4", [131, 139, 139]),
+    ("beige", [245, 245, 220]),
+    ("bisque", [255, 228, 196]),
+    ("bisque1", [255, 228, 196]),
+    ("bisque2", [238, 213, 183]),
+    ("bisque3", [205, 183, 158]),
+    ("bisque4", [139, 125, 107]),
+    ("black", [0, 0, 0]),
+    ("blanchedalmond", [255, 235, 205]),
+    ("blue", [0, 0, 255]),
+    ("blue1", [0, 0, 255]),
+    ("blue2", [0, 0, 238]),
+    ("blue3", [0, 0, 205]),
+    ("blue4", [0, 0, 139]),
+    ("blueviolet", [138, 43, 226]),
+    ("brown", [165, 42, 42]),
+    ("brown1", [255, 64, 64]),
+    ("brown2", [238, 59, 59]),
+    ("brown3", [205, 51, 51]),
+    ("brown4", [139, 35, 35]),
+    ("burlywood", [222, 184, 135]),
+    ("burlywood1", [255, 211, 155]),
+    ("burlywood2", [238, 197, 145]),
+    ("burlywood3", [205, 170, 125]),
+    ("burlywood4", [139, 115, 85]),
+    ("cadetblue", [95, 158, 160]),
+    ("cadetblue1", [152, 245, 255]),
+    ("cadetblue2", [142, 229, 238]),
+    ("cadetblue3", [122, 197, 205]),
+    ("cadetblue4", [83, 134, 139]),
+    ("chartreuse", [127, 255, 0]),
+    ("chartreuse1", [127, 255, 0]),
+    ("chartreuse2", [118, 238, 0]),
+    ("chartreuse3", [102, 205, 0]),
+    ("chartreuse4", [69, 139, 0]),
+    ("chocolate", [210, 105, 30]),
+    ("chocolate1", [255, 127, 36]),
+    ("chocolate2", [238, 118, 33]),
+    ("chocolate3", [205, 102, 29]),
+    ("chocolate4", [139, 69, 19]),
+    ("coral", [255, 127, 80]),
+    ("coral1", [255, 114, 86]),
+    ("coral2", [238, 106, 80]),
+    ("coral3", [205, 91, 69]),
+    ("coral4", [139, 62, 47]),
+    ("cornflowerblue", [100, 149, 237]),
+    ("cornsilk", [255, 248, 220]),
+    ("cornsilk1", [255, 248, 220]),
+    ("cornsilk2", [238, 232, 205]),
+    ("cornsilk3", [205, 200, 177]),
+    ("cornsilk4", [139, 136, 120]),
+    ("crimson", [220, 20, 60]),
+    ("cyan", [0, 255, 255]),
+    ("cyan1", [0, 255, 255]),
+    ("cyan2", [0, 238, 238]),
+    ("cyan3", [0, 205, 205]),
+    ("cyan4", [0, 139, 139]),
+    ("darkblue", [0, 0, 139]),
+    ("darkcyan", [0, 139, 139]),
+    ("darkgoldenrod", [184, 134, 11]),
+    ("darkgoldenrod1", [255, 185, 15]),
+    ("darkgoldenrod2", [238, 173, 14]),
+    ("darkgoldenrod3", [205, 149, 12]),
+    ("darkgoldenrod4", [139, 101, 8]),
+    ("darkgray", [169, 169, 169]),
+    ("darkgreen", [0, 100, 0]),
+    ("darkgrey", [169, 169, 169]),
+    ("darkkhaki", [189, 183, 107]),
+    ("darkmagenta", [139, 0, 139]),
+    ("darkolivegreen", [85, 107, 47]),
+    ("darkolivegreen1", [202, 255, 112]),
+    ("darkolivegreen2", [188, 238, 104]),
+    ("darkolivegreen3", [162, 205, 90]),
+    ("darkolivegreen4", [110, 139, 61]),
+    ("darkorange", [255, 140, 0]),
+    ("darkorange1", [255, 127, 0]),
+    ("darkorange2", [238, 118, 0]),
+    ("darkorange3", [205, 102, 0]),
+    ("darkorange4", [139, 69, 0]),
+    ("darkorchid", [153, 50, 204]),
+    ("darkorchid1", [191, 62, 255]),
+    ("darkorchid2", [178, 58, 238]),
+    ("darkorchid3", [154, 50, 205]),
+    ("darkorchid4", [104, 34, 139]),
+    ("darkred", [139, 0, 0]),
+    ("darksalmon", [233, 150, 122]),
+    ("darkseagreen", [143, 188, 143]),
+    ("darkseagreen1", [193, 255, 193]),
+    ("darkseagreen2", [180, 238, 180]),
+    ("darkseagreen3", [155, 205, 155]),
+    ("darkseagreen4", [105, 139, 105]),
+    ("darkslateblue", [72, 61, 139]),
+    ("darkslategray", [47, 79, 79]),
+    ("darkslategray1", [151, 255, 255]),
+    ("darkslategray2", [141, 238, 238]),
+    ("darkslategray3", [121, 205, 205]),
+    ("darkslategray4", [82, 139, 139]),
+    ("darkslategrey", [47, 79, 79]),
+    ("darkturquoise", [0, 206, 209]),
+    ("darkviolet", [148, 0, 211]),
+    ("deeppink", [255, 20, 147]),
+    ("deeppink1", [255, 20, 147]),
+    ("deeppink2", [238, 18, 137]),
+    ("deeppink3", [205, 16, 118]),
+    ("deeppink4", [139, 10, 80]),
+    ("deepskyblue", [0, 191, 255]),
+    ("deepskyblue1", [0, 191, 255]),
+    ("deepskyblue2", [0, 178, 238]),
+    ("deepskyblue3", [0, 154, 205]),
+    ("deepskyblue4", [0, 104, 139]),
+    ("dimgray", [105, 105, 105]),
+    ("dimgrey", [105, 105, 105]),
+    ("dodgerblue", [30, 144, 255]),
+    ("dodgerblue1", [30, 144, 255]),
+    ("dodgerblue2", [28, 134, 238]),
+    ("dodgerblue3", [24, 116, 205]),
+    ("dodgerblue4", [16, 78, 139]),
+    ("firebrick", [178, 34, 34]),
+    ("firebrick1", [255, 48, 48]),
+    ("firebrick2", [238, 44, 44]),
+    ("firebrick3", [205, 38, 38]),
+    ("firebrick4", [139, 26, 26]),
+    ("floralwhite", [255, 250, 240]),
+    ("forestgreen", [34, 139, 34]),
+    ("gainsboro", [220, 220, 220]),
+    ("ghostwhite", [248, 248, 255]),
+    ("gold", [255, 215, 0]),
+    ("gold1", [255, 215, 0]),
+    ("gold2", [238, 201, 0]),
+    ("gold3", [205, 173, 0]),
+    ("gold4", [139, 117, 0]),
+    ("goldenrod", [218, 165, 32]),
+    ("goldenrod1", [255, 193, 37]),
+    ("goldenrod2", [238, 180, 34]),
+    ("goldenrod3", [205, 155, 29]),
+    ("goldenrod4", [139, 105, 20]),
+    ("gray", [190, 190, 190]),
+    ("gray0", [0, 0, 0]),
+    ("gray1", [3, 3, 3]),
+    ("gray10", [26, 26, 26]),
+    ("gray100", [255, 255, 255]),
+    ("gray11", [28, 28, 28]),
+    ("gray12", [31, 31, 31]),
+    ("gray13", [33, 33, 33]),
+    ("gray14", [36, 36, 36]),
+    ("gray15", [38, 38, 38]),
+    ("gray16", [41, 41, 41]),
+    ("gray17", [43, 43, 43]),
+    ("gray18", [46, 46, 46]),
+    ("gray19", [48, 48, 48]),
+    ("gray2", [5, 5, 5]),
+    ("gray20", [51, 51, 51]),
+    ("gray21", [54, 54, 54]),
+    ("gray22", [56, 56, 56]),
+    ("gray23", [59, 59, 59]),
+    ("gray24", [61, 61, 61]),
+    ("gray25", [64, 64, 64]),
+    ("gray26", [66, 66, 66]),
+    ("gray27", [69, 69, 69]),
+    ("gray28", [71, 71, 71]),
+    ("gray29", [74, 74, 74]),
+    ("gray3", [8, 8, 8]),
+    ("gray30", [77, 77, 77]),
+    ("gray31", [79, 79, 79]),
+    ("gray32", [82, 82, 82]),
+    ("gray33", [84, 84, 84]),
+    ("gray34", [87, 87, 87]),
+    ("gray35", [89, 89, 89]),
+    ("gray36", [92, 92, 92]),
+    ("gray37", [94, 94, 94]),
+    ("gray38", [97, 97, 97]),
+    ("gray39", [99, 99, 99]),
+    ("gray4", [10, 10, 10]),
+    ("gray40", [102, 102, 102]),
+    ("gray41", [105, 105, 105]),
+    ("gray42", [107, 107, 107]),
+    ("gray43", [110, 110, 110]),
+    ("gray44", [112, 112, 112]),
+    ("gray45", [115, 115, 115]),
+    ("gray46", [117, 117, 117]),
+    ("gray47", [120, 120, 120]),
+    ("gray48", [122, 122, 122]),
+    ("gray49", [125, 125, 125]),
+    ("gray5", [13, 13, 13]),
+    ("gray50", [127, 127, 127]),
+    ("gray51", [130, 130, 130]),
+    ("gray52", [133, 133, 133]),
+    ("gray53", [135, 135, 135]),
+    ("gray54", [138, 138, 138]),
+    ("gray55", [140, 140, 140]),
+    ("gray56", [143, 143, 143]),
+    ("gray57", [145, 145, 145]),
+    ("gray58", [148, 148, 148]),
+    ("gray59", [150, 150, 150]),
+    ("gray6", [15, 15, 15]),
+    ("gray60", [153, 153, 153]),
+    ("gray61", [156, 156, 156]),
+    ("gray62", [158, 158, 158]),
+    ("gray63", [161, 161, 161]),
+    ("gray64", [163, 163, 163]),
+    ("gray65", [166, 166, 166]),
+    ("gray66", [168, 168, 168]),
+    ("gray67", [171, 171, 171]),
+    ("gray68", [173, 173, 173]),
+    ("gray69", [176, 176, 176]),
+    ("gray7", [18, 18, 18]),
+    ("gray70", [179, 179, 179]),
+    ("gray71", [181, 181, 181]),
+    ("gray72", [184, 184, 184]),
+    ("gray73", [186, 186, 186]),
+    ("gray74", [189, 189, 189]),
+    ("gray75", [191, 191, 191]),
+    ("gray76", [194, 194, 194]),
+    ("gray77", [196, 196, 196]),
+    ("gray78", [199, 199, 199]),
+    ("gray79", [201, 201, 201]),
+    ("gray8", [20, 20, 20]),
+    ("gray80", [204, 204, 204]),
+    ("gray81", [207, 207, 207]),
+    ("gray82", [209, 209, 209]),
+    ("gray83", [212, 212, 212]),
+    ("gray84", [214, 214, 214]),
+    ("gray85", [217, 217, 217]),
+    ("gray86", [219, 219, 219]),
+    ("gray87", [222, 222, 222]),
+    ("gray88", [224, 224, 224]),
+    ("gray89", [227, 227, 227]),
+    ("gray9", [23, 23, 23]),
+    ("gray90", [229, 229, 229]),
+    ("gray91", [232, 232, 232]),
+    ("gray92", [235, 235, 235]),
+    ("gray93", [237, 237, 237]),
+    ("gray94", [240, 240, 240]),
+    ("gray95", [242, 242, 242]),
+    ("gray96", [245, 245, 245]),
+    ("gray97", [247, 247, 247]),
+    ("gray98", [250, 250, 250]),
+    ("gray99", [252, 252, 252]),
+    ("green", [0, 255, 0]),
+    ("green1", [0, 255, 0]),
+    ("green2", [0, 238, 0]),
+    ("green3", [0, 205, 0]),
+    ("green4", [0, 139, 0]),
+    ("greenyellow", [173, 255, 47]),
+    ("grey", [190, 190, 190]),
+    ("grey0", [0, 0, 0]),
+    ("grey1", [3, 3, 3]),
+    ("grey10", [26, 26, 26]),
+    ("grey100", [255, 255, 255]),
+    ("grey11", [28, 28, 28]),
+    ("grey12", [31, 31, 31]),
+    ("grey13", [33, 33, 33]),
+    ("grey14", [36, 36, 36]),
+    ("grey15", [38, 38, 38]),
+    ("grey16", [41, 41, 41]),
+    ("grey17", [43, 43, 43]),
+    ("grey18", [46, 46, 46]),
+    ("grey19", [48, 48, 48]),
+    ("grey2", [5, 5, 5]),
+    ("grey20", [51, 51, 51]),
+    ("grey21", [54, 54, 54]),
+    ("grey22", [56, 56, 56]),
+    ("grey23", [59, 59, 59]),
+    ("grey24", [61, 61, 61]),
+    ("grey25", [64, 64, 64]),
+    ("grey26", [66, 66, 66]),
+    ("grey27", [69, 69, 69]),
+    ("grey28", [71, 71, 71]),
+    ("grey29", [74, 74, 74]),
+    ("grey3", [8, 8, 8]),
+    ("grey30", [77, 77, 77]),
+    ("grey31", [79, 79, 79]),
+    ("grey32", [82, 82, 82]),
+    ("grey33", [84, 84, 84]),
+    ("grey34", [87, 87, 87]),
+    ("grey35", [89, 89, 89]),
+    ("grey36", [92, 92, 92]),
+    ("grey37", [94, 94, 94]),
+    ("grey38", [97, 97, 97]),
+    ("grey39", [99, 99, 99]),
+    ("grey4", [10, 10, 10]),
+    ("grey40", [102, 102, 102]),
+    ("grey41", [105, 105, 105]),
+    ("grey42", [107, 107, 107]),
+    ("grey43", [110, 110, 110]),
+    ("grey44", [112, 112, 112]),
+    ("grey45", [115, 115, 115]),
+    ("grey46", [117, 117, 117]),
+    ("grey47", [120, 120, 120]),
+    ("grey48", [122, 122, 122]),
+    ("grey49", [125, 125, 125]),
+    ("grey5", [13, 13, 13]),
+    ("grey50", [127, 127, 127]),
+    ("grey51", [130, 130, 130]),
+    ("grey52", [133, 133, 133]),
+    ("grey53", [135, 135, 135]),
+    ("grey54", [138, 138, 138]),
+    ("grey55", [140, 140, 140]),
+    ("grey56", [143, 143, 143]),
+    ("grey57", [145, 145, 145]),
+    ("grey58", [148, 148, 148]),
+    ("grey59", [150, 150, 150]),
+    ("grey6", [15, 15, 15]),
+    ("grey60", [153, 153, 153]),
+    ("grey61", [156, 156, 156]),
+    ("grey62", [158, 158, 158]),
+    ("grey63", [161, 161, 161]),
+    ("grey64", [163, 163, 163]),
+    ("grey65", [166, 166, 166]),
+    ("grey66", [168, 168, 168]),
+    ("grey67", [171, 171, 171]),
+    ("grey68", [173, 173, 173]),
+    ("grey69", [176, 176, 176]),
+    ("grey7", [18, 18, 18]),
+    ("grey70", [179, 179, 179]),
+    ("grey71", [181, 181, 181]),
+    ("grey72", [184, 184, 184]),
+    ("grey73", [186, 186, 186]),
+    ("grey74", [189, 189, 189]),
+    ("grey75", [191, 191, 191]),
+    ("grey76", [194, 194, 194]),
+    ("grey77", [196, 196, 196]),
+    ("grey78", [199, 199, 199]),
+    ("grey79", [201, 201, 201]),
+    ("grey8", [20, 20, 20]),
+    ("grey80", [204, 204, 204]),
+    ("grey81", [207, 207, 207]),
+    ("grey82", [209, 209, 209]),
+    ("grey83", [212, 212, 212]),
+    ("grey84", [214, 214, 214]),
+    ("grey85", [217, 217, 217]),
+    ("grey86", [219, 219, 219]),
+    ("grey87", [222, 222, 222]),
+    ("grey88", [224, 224, 224]),
+    ("grey89", [227, 227, 227]),
+    ("grey9", [23, 23, 23]),
+    ("grey90", [229, 229, 229]),
+    ("grey91", [232, 232, 232]),
+    ("grey92", [235, 235, 235]),
+    ("grey93", [237, 237, 237]),
+    ("grey94", [240, 240, 240]),
+    ("grey95", [242, 242, 242]),
+    ("grey96", [245, 245, 245]),
+    ("grey97", [247, 247, 247]),
+    ("grey98", [250, 250, 250]),
+    ("grey99", [252, 252, 252]),
+    ("honeydew", [240, 255, 240]),
+    ("honeydew1", [240, 255, 240]),
+    ("honeydew2", [224, 238, 224]),
+    ("honeydew3", [193, 205, 193]),
+    ("honeydew4", [131, 139, 131]),
+    ("hotpink", [255, 105, 180]),
+    ("hotpink1", [255, 110, 180]),
+    ("hotpink2", [238, 106, 167]),
+    ("hotpink3", [205, 96, 144]),
+    ("hotpink4", [139, 58, 98]),
+    ("indianred", [205, 92, 92]),
+    ("indianred1", [255, 106, 106]),
+    ("indianred2", [238, 99, 99]),
+    ("indianred3", [205, 85, 85]),
+    ("indianred4", [139, 58, 58]),
+    ("indigo", [75, 0, 130]),
+    ("ivory", [255, 255, 240]),
+    ("ivory1", [255, 255, 240]),
+    ("ivory2", [238, 238, 224]),
+    ("ivory3", [205, 205, 193]),
+    ("ivory4", [139, 139, 131]),
+    ("khaki", [240, 230, 140]),
+    ("khaki1", [255, 246, 143]),
+    ("khaki2", [238, 230, 133]),
+    ("khaki3", [205, 198, 115]),
+    ("khaki4", [139, 134, 78]),
+    ("lavender", [230, 230, 250]),
+    ("lavenderblush", [255, 240, 245]),
+    ("lavenderblush1", [255, 240, 245]),
+    ("lavenderblush2", [238, 224, 229]),
+    ("lavenderblush3", [205, 193, 197]),
+    ("lavenderblush4", [139, 131, 134]),
+    ("lawngreen", [124, 252, 0]),
+    ("lemonchiffon", [255, 250, 205]),
+    ("lemonchiffon1", [255, 250, 205]),
+    ("lemonchiffon2", [238, 233, 191]),
+    ("lemonchiffon3", [205, 201, 165]),
+    ("lemonchiffon4", [139, 137, 112]),
+    ("lightblue", [173, 216, 230]),
+    ("lightblue1", [191, 239, 255]),
+    ("lightblue2", [178, 223, 238]),
+    ("lightblue3", [154, 192, 205]),
+    ("lightblue4", [104, 131, 139]),
+    ("lightcoral", [240, 128, 128]),
+    ("lightcyan", [224, 255, 255]),
+    ("lightcyan1", [224, 255, 255]),
+    ("lightcyan2", [209, 238, 238]),
+    ("lightcyan3", [180, 205, 205]),
+    ("lightcyan4", [122, 139, 139]),
+    ("lightgoldenrod", [238, 221, 130]),
+    ("lightgoldenrod1", [255, 236, 139]),
+    ("lightgoldenrod2", [238, 220, 130]),
+    ("lightgoldenrod3", [205, 190, 112]),
+    ("lightgoldenrod4", [139, 129, 76]),
+    ("lightgoldenrodyellow", [250, 250, 210]),
+    ("lightgray", [211, 211, 211]),
+    ("lightgreen", [144, 238, 144]),
+    ("lightgrey", [211, 211, 211]),
+    ("lightpink", [255, 182, 193]),
+    ("lightpink1", [255, 174, 185]),
+    ("lightpink2", [238, 162, 173]),
+    ("lightpink3", [205, 140, 149]),
+    ("lightpink4", [139, 95, 101]),
+    ("lightsalmon", [255, 160, 122]),
+    ("lightsalmon1", [255, 160, 122]),
+    ("lightsalmon2", [238, 149, 114]),
+    ("lightsalmon3", [205, 129, 98]),
+    ("lightsalmon4", [139, 87, 66]),
+    ("lightseagreen", [32, 178, 170]),
+    ("lightskyblue", [135, 206, 250]),
+    ("lightskyblue1", [176, 226, 255]),
+    ("lightskyblue2", [164, 211, 238]),
+    ("lightskyblue3", [141, 182, 205]),
+    ("lightskyblue4", [96, 123, 139]),
+    ("lightslateblue", [132, 112, 255]),
+    ("lightslategray", [119, 136, 153]),
+    ("lightslategrey", [119, 136, 153]),
+    ("lightsteelblue", [176, 196, 222]),
+    ("lightsteelblue1", [202, 225, 255]),
+    ("lightsteelblue2", [188, 210, 238]),
+    ("lightsteelblue3", [162, 181, 205]),
+    ("lightsteelblue4", [110, 123, 139]),
+    ("lightyellow", [255, 255, 224]),
+    ("lightyellow1", [255, 255, 224]),
+    ("lightyellow2", [238, 238, 209]),
+    ("lightyellow3", [205, 205, 180]),
+    ("lightyellow4", [139, 139, 122]),
+    ("limegreen", [50, 205, 50]),
+    ("linen", [250, 240, 230]),
+    ("magenta", [255, 0, 255]),
+    ("magenta1", [255, 0, 255]),
+    ("magenta2", [238, 0, 238]),
+    ("magenta3", [205, 0, 205]),
+    ("magenta4", [139, 0, 139]),
+    ("maroon", [176, 48, 96]),
+    ("maroon1", [255, 52, 179]),
+    ("maroon2", [238, 48, 167]),
+    ("maroon3", [205, 41, 144]),
+    ("maroon4", [139, 28, 98]),
+    ("mediumaquamarine", [102, 205, 170]),
+    ("mediumblue", [0, 0, 205]),
+    ("mediumorchid", [186, 85, 211]),
+    ("mediumorchid1", [224, 102, 255]),
+    ("mediumorchid2", [209, 95, 238]),
+    ("mediumorchid3", [180, 82, 205]),
+    ("mediumorchid4", [122, 55, 139]),
+    ("mediumpurple", [147, 112, 219]),
+    ("mediumpurple1", [171, 130, 255]),
+    ("mediumpurple2", [159, 121, 238]),
+    ("mediumpurple3", [137, 104, 205]),
+    ("mediumpurple4", [93, 71, 139]),
+    ("mediumseagreen", [60, 179, 113]),
+    ("mediumslateblue", [123, 104, 238]),
+    ("mediumspringgreen", [0, 250, 154]),
+    ("mediumturquoise", [72, 209, 204]),
+    ("mediumvioletred", [199, 21, 133]),
+    ("midnightblue", [25, 25, 112]),
+    ("mintcream", [245, 255, 250]),
+    ("mistyrose", [255, 228, 225]),
+    ("mistyrose1", [255, 228, 225]),
+    ("mistyrose2", [238, 213, 210]),
+    ("mistyrose3", [205, 183, 181]),
+    ("mistyrose4", [139, 125, 123]),
+    ("moccasin", [255, 228, 181]),
+    ("navajowhite", [255, 222, 173]),
+    ("navajowhite1", [255, 222, 173]),
+    ("navajowhite2", [238, 207, 161]),
+    ("navajowhite3", [205, 179, 139]),
+    ("navajowhite4", [139, 121, 94]),
+    ("navy", [0, 0, 128]),
+    ("navyblue", [0, 0, 128]),
+    ("oldlace", [253, 245, 230]),
+    ("olivedrab", [107, 142, 35]),
+    ("olivedrab1", [192, 255, 62]),
+    ("olivedrab2", [179, 238, 58]),
+    ("olivedrab3", [154, 205, 50]),
+    ("olivedrab4", [105, 139, 34]),
+    ("orange", [255, 165, 0]),
+    ("orange1", [255, 165, 0]),
+    ("orange2", [238, 154, 0]),
+    ("orange3", [205, 133, 0]),
+    ("orange4", [139, 90, 0]),
+    ("orangered", [255, 69, 0]),
+    ("orangered1", [255, 69, 0]),
+    ("orangered2", [238, 64, 0]),
+    ("orangered3", [205, 55, 0]),
+    ("orangered4", [139, 37, 0]),
+    ("orchid", [218, 112, 214]),
+    ("orchid1", [255, 131, 250]),
+    ("orchid2", [238, 122, 233]),
+    ("orchid3", [205, 105, 201]),
+    ("orchid4", [139, 71, 137]),
+    ("palegoldenrod", [238, 232, 170]),
+    ("palegreen", [152, 251, 152]),
+    ("palegreen1", [154, 255, 154]),
+    ("palegreen2", [144, 238, 144]),
+    ("palegreen3", [124, 205, 124]),
+    ("palegreen4", [84, 139, 84]),
+    ("paleturquoise", [175, 238, 238]),
+    ("paleturquoise1", [187, 255, 255]),
+    ("paleturquoise2", [174, 238, 238]),
+    ("paleturquoise3", [150, 205, 205]),
+    ("paleturquoise4", [102, 139, 139]),
+    ("palevioletred", [219, 112, 147]),
+    ("palevioletred1", [255, 130, 171]),
+    ("palevioletred2", [238, 121, 159]),
+    ("palevioletred3", [205, 104, 137]),
+    ("palevioletred4", [139, 71, 93]),
+    ("papayawhip", [255, 239, 213]),
+    ("peachpuff", [255, 218, 185]),
+    ("peachpuff1", [255, 218, 185]),
+    ("peachpuff2", [238, 203, 173]),
+    ("peachpuff3", [205, 175, 149]),
+    ("peachpuff4", [139, 119, 101]),
+    ("peru", [205, 133, 63]),
+    ("pink", [255, 192, 203]),
+    ("pink1", [255, 181, 197]),
+    ("pink2", [238, 169, 184]),
+    ("pink3", [205, 145, 158]),
+    ("pink4", [139, 99, 108]),
+    ("plum", [221, 160, 221]),
+    ("plum1", [255, 187, 255]),
+    ("plum2", [238, 174, 238]),
+    ("plum3", [205, 150, 205]),
+    ("plum4", [139, 102, 139]),
+    ("powderblue", [176, 224, 230]),
+    ("purple", [160, 32, 240]),
+    ("purple1", [155, 48, 255]),
+    ("purple2", [145, 44, 238]),
+    ("purple3", [125, 38, 205]),
+    ("purple4", [85, 26, 139]),
+    ("red", [255, 0, 0]),
+    ("red1", [255, 0, 0]),
+    ("red2", [238, 0, 0]),
+    ("red3", [205, 0, 0]),
+    ("red4", [139, 0, 0]),
+    ("rosybrown", [188, 143, 143]),
+    ("rosybrown1", [255, 193, 193]),
+    ("rosybrown2", [238, 180, 180]),
+    ("rosybrown3", [205, 155, 155]),
+    ("rosybrown4", [139, 105, 105]),
+    ("royalblue", [65, 105, 225]),
+    ("royalblue1", [72, 118, 255]),
+    ("royalblue2", [67, 110, 238]),
+    ("royalblue3", [58, 95, 205]),
+    ("royalblue4", [39, 64, 139]),
+    ("saddlebrown", [139, 69, 19]),
+    ("salmon", [250, 128, 114]),
+    ("salmon1", [255, 140, 105]),
+    ("salmon2", [238, 130, 98]),
+    ("salmon3", [205, 112, 84]),
+    ("salmon4", [139, 76, 57]),
+    ("sandybrown", [244, 164, 96]),
+    ("seagreen", [46, 139, 87]),
+    ("seagreen1", [84, 255, 159]),
+    ("seagreen2", [78, 238, 148]),
+    ("seagreen3", [67, 205, 128]),
+    ("seagreen4", [46, 139, 87]),
+    ("seashell", [255, 245, 238]),
+    ("seashell1", [255, 245, 238]),
+    ("seashell2", [238, 229, 222]),
+    ("seashell3", [205, 197, 191]),
+    ("seashell4", [139, 134, 130]),
+    ("sienna", [160, 82, 45]),
+    ("sienna1", [255, 130, 71]),
+    ("sienna2", [238, 121, 66]),
+    ("sienna3", [205, 104, 57]),
+    ("sienna4", [139, 71, 38]),
+    ("skyblue", [135, 206, 235]),
+    ("skyblue1", [135, 206, 255]),
+    ("skyblue2", [126, 192, 238]),
+    ("skyblue3", [108, 166, 205]),
+    ("skyblue4", [74, 112, 139]),
+    ("slateblue", [106, 90, 205]),
+    ("slateblue1", [131, 111, 255]),
+    ("slateblue2", [122, 103, 238]),
+    ("slateblue3", [105, 89, 205]),
+    ("slateblue4", [71, 60, 139]),
+    ("slategray", [112, 128, 144]),
+    ("slategray1", [198, 226, 255]),
+    ("slategray2", [185, 211, 238]),
+    ("slategray3", [159, 182, 205]),
+    ("slategray4", [108, 123, 139]),
+    ("slategrey", [112, 128, 144]),
+    ("snow", [255, 250, 250]),
+    ("snow1", [255, 250, 250]),
+    ("snow2", [238, 233, 233]),
+    ("snow3", [205, 201, 201]),
+    ("snow4", [139, 137, 137]),
+    ("springgreen", [0, 255, 127]),
+    ("springgreen1", [0, 255, 127]),
+    ("springgreen2", [0, 238, 118]),
+    ("springgreen3", [0, 205, 102]),
+    ("springgreen4", [0, 139, 69]),
+    ("steelblue", [70, 130, 180]),
+    ("steelblue1", [99, 184, 255]),
+    ("steelblue2", [92, 172, 238]),
+    ("steelblue3", [79, 148, 205]),
+    ("steelblue4", [54, 100, 139]),
+    ("tan", [210, 180, 140]),
+    ("tan1", [255, 165, 79]),
+    ("tan2", [238, 154, 73]),
+    ("tan3", [205, 133, 63]),
+    ("tan4", [139, 90, 43]),
+    ("thistle", [216, 191, 216]),
+    ("thistle1", [255, 225, 255]),
+    ("thistle2", [238, 210, 238]),
+    ("thistle3", [205, 181, 205]),
+    ("thistle4", [139, 123, 139]),
+    ("tomato", [255, 99, 71]),
+    ("tomato1", [255, 99, 71]),
+    ("tomato2", [238, 92, 66]),
+    ("tomato3", [205, 79, 57]),
+    ("tomato4", [139, 54, 38]),
+    ("turquoise", [64, 224, 208]),
+    ("turquoise1", [0, 245, 255]),
+    ("turquoise2", [0, 229, 238]),
+    ("turquoise3", [0, 197, 205]),
+    ("turquoise4", [0, 134, 139]),
+    ("violet", [238, 130, 238]),
+    ("violetred", [208, 32, 144]),
+    ("violetred1", [255, 62, 150]),
+    ("violetred2", [238, 58, 140]),
+    ("violetred3", [205, 50, 120]),
+    ("violetred4", [139, 34, 82]),
+    ("wheat", [245, 222, 179]),
+    ("wheat1", [255, 231, 186]),
+    ("wheat2", [238, 216, 174]),
+    ("wheat3", [205, 186, 150]),
+    ("wheat4", [139, 126, 102]),
+    ("white", [255, 255, 255]),
+    ("whitesmoke", [245, 245, 245]),
+    ("yellow", [255, 255, 0]),
+    ("yellow1", [255, 255, 0]),
+    ("yellow2", [238, 238, 0]),
+    ("yellow3", [205, 205, 0]),
+    ("yellow4", [139, 139, 0]),
+    ("yellowgreen", [154, 205, 50]),
+];
+
+/// The SVG color scheme, sorted by name
+pub(super) static SVG: &[(&str, [u8; 3])] = &[
+    ("aliceblue", [240, 248, 255]),
+    ("antiquewhite", [250, 235, 215]),
+    ("aqua", [0, 255, 255]),
+    ("aquamarine", [127, 255, 212]),
+    ("azure", [240, 255, 255]),
+    ("beige", [245, 245, 220]),
+    ("bisque", [255, 228, 196]),
+    ("black", [0, 0, 0]),
+    ("blanchedalmond", [255, 235, 205]),
+    ("blue", [0, 0, 255]),
+    ("blueviolet", [138, 43, 226]),
+    ("brown", [165, 42, 42]),
+    ("burlywood", [222, 184, 135]),
+    ("cadetblue", [95, 158, 160]),
+    ("chartreuse", [127, 255, 0]),
+    ("chocolate", [210, 105, 30]),
+    ("coral", [255, 127, 80]),
+    ("cornflowerblue", [100, 149, 237]),
+    ("cornsilk", [255, 248, 220]),
+    ("crimson", [220, 20, 60]),
+    ("cyan", [0, 255, 255]),
+    ("darkblue", [0, 0, 139]),
+    ("darkcyan", [0, 139, 139]),
+    ("darkgoldenrod", [184, 134, 11]),
+    ("darkgray", [169, 169, 169]),
+    ("darkgreen", [0, 100, 0]),
+    ("darkgrey", [169, 169, 169]),
+    ("darkkhaki", [189, 183, 107]),
+    ("darkmagenta", [139, 0, 139]),
+    ("darkolivegreen", [85, 107, 47]),
+    ("darkorange", [255, 140, 0]),
+    ("darkorchid", [153, 50, 204]),
+    ("darkred", [139, 0, 0]),
+    ("darksalmon", [233, 150, 122]),
+    ("darkseagreen", [143, 188, 143]),
+    ("darkslateblue", [72, 61, 139]),
+    ("darkslategray", [47, 79, 79]),
+    ("darkslategrey", [47, 79, 79]),
+    ("darkturquoise", [0, 206, 209]),
+    ("darkviolet", [148, 0, 211]),
+    ("deeppink", [255, 20, 147]),
+    ("deepskyblue", [0, 191, 255]),
+    ("dimgray", [105, 105, 105]),
+    ("dimgrey", [105, 105, 105]),
+    ("dodgerblue", [30, 144, 255]),
+    ("firebrick", [178, 34, 34]),
+    ("floralwhite", [255, 250, 240]),
+    ("forestgreen", [34, 139, 34]),
+    ("fuchsia", [255, 0, 255]),
+    ("gainsboro", [220, 220, 220]),
+    ("ghostwhite", [248, 248, 255]),
+    ("gold", [255, 215, 0]),
+    ("goldenrod", [218, 165, 32]),
+    ("gray", [128, 128, 128]),
+    ("green", [0, 128, 0]),
+    ("greenyellow", [173, 255, 47]),
+    ("grey", [128, 128, 128]),
+    ("honeydew", [240, 255, 240]),
+    ("hotpink", [255, 105, 180]),
+    ("indianred", [205, 92, 92]),
+    ("indigo", [75, 0, 130]),
+    ("ivory", [255, 255, 240]),
+    ("khaki", [240, 230, 140]),
+    ("lavender", [230, 230, 250]),
+    ("lavenderblush", [255, 240, 245]),
+    ("lawngreen", [124, 252, 0]),
+    ("lemonchiffon", [255, 250, 205]),
+    ("lightblue", [173, 216, 230]),
+    ("lightcoral", [240, 128, 128]),
+    ("lightcyan", [224, 255, 255]),
+    ("lightgoldenrodyellow", [250, 250, 210]),
+    ("lightgray", [211, 211, 211]),
+    ("lightgreen", [144, 238, 144]),
+    ("lightgrey", [211, 211, 211]),
+    ("lightpink", [255, 182, 193]),
+    ("lightsalmon", [255, 160, 122]),
+    ("lightseagreen", [32, 178, 170]),
+    ("lightskyblue", [135, 206, 250]),
+    ("lightslategray", [119, 136, 153]),
+    ("lightslategrey", [119, 136, 153]),
+    ("lightsteelblue", [176, 196, 222]),
+    ("lightyellow", [255, 255, 224]),
+    ("lime", [0, 255, 0]),
+    ("limegreen", [50, 205, 50]),
+    ("linen", [250, 240, 230]),
+    ("magenta", [255, 0, 255]),
+    ("maroon", [128, 0, 0]),
+    ("mediumaquamarine", [102, 205, 170]),
+    ("mediumblue", [0, 0, 205]),
+    ("mediumorchid", [186, 85, 211]),
+    ("mediumpurple", [147, 112, 219]),
+    ("mediumseagreen", [60, 179, 113]),
+    ("mediumslateblue", [123, 104, 238]),
+    ("mediumspringgreen", [0, 250, 154]),
+    ("mediumturquoise", [72, 209, 204]),
+    ("mediumvioletred", [199, 21, 133]),
+    ("midnightblue", [25, 25, 112]),
+    ("mintcream", [245, 255, 250]),
+    ("mistyrose", [255, 228, 225]),
+    ("moccasin", [255, 228, 181]),
+    ("navajowhite", [255, 222, 173]),
+    ("navy", [0, 0, 128]),
+    ("oldlace", [253, 245, 230]),
+    ("olive", [128, 128, 0]),
+    ("olivedrab", [107, 142, 35]),
+    ("orange", [255, 165, 0]),
+    ("orangered", [255, 69, 0]),
+    ("orchid", [218, 112, 214]),
+    ("palegoldenrod", [238, 232, 170]),
+    ("palegreen", [152, 251, 152]),
+    ("paleturquoise", [175, 238, 238]),
+    ("palevioletred", [219, 112, 147]),
+    ("papayawhip", [255, 239, 213]),
+    ("peachpuff", [255, 218, 185]),
+    ("peru", [205, 133, 63]),
+    ("pink", [255, 192, 203]),
+    ("plum", [221, 160, 221]),
+    ("powderblue", [176, 224, 230]),
+    ("purple", [128, 0, 128]),
+    ("red", [255, 0, 0]),
+    ("rosybrown", [188, 143, 143]),
+    ("royalblue", [65, 105, 225]),
+    ("saddlebrown", [139, 69, 19]),
+    ("salmon", [250, 128, 114]),
+    ("sandybrown", [244, 164, 96]),
+    ("seagreen", [46, 139, 87]),
+    ("seashell", [255, 245, 238]),
+    ("sienna", [160, 82, 45]),
+    ("silver", [192, 192, 192]),
+    ("skyblue", [135, 206, 235]),
+    ("slateblue", [106, 90, 205]),
+    ("slategray", [112, 128, 144]),
+    ("slategrey", [112, 128, 144]),
+    ("snow", [255, 250, 250]),
+    ("springgreen", [0, 255, 127]),
+    ("steelblue", [70, 130, 180]),
+    ("tan", [210, 180, 140]),
+    ("teal", [0, 128, 128]),
+    ("thistle", [216, 191, 216]),
+    ("tomato", [255, 99, 71]),
+    ("turquoise", [64, 224, 208]),
+    ("violet", [238, 130, 238]),
+    ("wheat", [245, 222, 179]),
+    ("white", [255, 255, 255]),
+    ("whitesmoke", [245, 245, 245]),
+    ("yellow", [255, 255, 0]),
+    ("yellowgreen", [154, 205, 50]),
+];
+
+/// The qualitative Brewer color schemes, along with the smallest size they
+/// come in
+///
+/// A scheme like `accent5` is made of the first 5 colors of its palette.
+pub(super) static BREWER: &[(&str, usize, &[[u8; 3]])] = &[
+    (
+        "accent",
+        3,
+        &[
+            [127, 201, 127],
+            [190, 174, 212],
+            [253, 192, 134],
+            [255, 255, 153],
+            [56, 108, 176],
+            [240, 2, 127],
+            [191, 91, 23],
+            [102, 102, 102],
+        ],
+    ),
+    (
+        "dark2",
+        3,
+        &[
+            [27, 158, 119],
+            [217, 95, 2],
+            [117, 112, 179],
+            [231, 41, 138],
+            [102, 166, 30],
+            [230, 171, 2],
+            [166, 118, 29],
+            [102, 102, 102],
+        ],
+    ),
+    (
+        "paired",
+        3,
+        &[
+            [166, 206, 227],
+            [31, 120, 180],
+            [178, 223, 138],
+            [51, 160, 44],
+            [251, 154, 153],
+            [227, 26, 28],
+            [253, 191, 111],
+            [255, 127, 0],
+            [202, 178, 214],
+            [106, 61, 154],
+            [255, 255, 153],
+            [177, 89, 40],
+        ],
+    ),
+    (
+        "pastel1",
+        3,
+        &[
+            [251, 180, 174],
+            [179, 205, 227],
+            [204, 235, 197],
+            [222, 203, 228],
+            [254, 217, 166],
+            [255, 255, 204],
+            [229, 216, 189],
+            [253, 218, 236],
+            [242, 242, 242],
+        ],
+    ),
+    (
+        "pastel2",
+        3,
+        &[
+            [179, 226, 205],
+            [253, 205, 172],
+            [203, 213, 232],
+            [244, 202, 228],
+            [230, 245, 201],
+            [255, 242, 174],
+            [241, 226, 204],
+            [204, 204, 204],
+        ],
+    ),
+    (
+        "set1",
+        3,
+        &[
+            [228, 26, 28],
+            [55, 126, 184],
+            [77, 175, 74],
+            [152, 78, 163],
+            [255, 127, 0],
+            [255, 255, 51],
+            [166, 86, 40],
+            [247, 129, 191],
+            [153, 153, 153],
+        ],
+    ),
+    (
+        "set2",
+        3,
+        &[
+            [102, 194, 165],
+            [252, 141, 98],
+            [141, 160, 203],
+            [231, 138, 195],
+            [166, 216, 84],
+            [255, 217, 47],
+            [229, 196, 148],
+            [179, 179, 179],
+        ],
+    ),
+    (
+        "set3",
+        3,
+        &[
+            [141, 211, 199],
+            [255, 255, 179],
+            [190, 186, 218],
+            [251, 128, 114],
+            [128, 177, 211],
+            [253, 180, 98],
+            [179, 222, 105],
+            [252, 205, 229],
+            [217, 217, 217],
+            [188, 128, 189],
+            [204, 235, 197],
+            [255, 237, 111],
+        ],
+    ),
+];
+
+/// The sequential and diverging Brewer color schemes, which have a palette of
+/// their own for every size, starting with 3 colors
+///
+/// A scheme like `blues5` is made of the third palette of `blues`.
+pub(super) static BREWER_SCALES: &[(&str, &[&[[u8; 3]]])] = &[
+    (
+        "blues",
+        &[
+            &[[222, 235, 247], [158, 202, 225], [49, 130, 189]],
+            &[
+                [239, 243, 255],
+                [189, 215, 231],
+                [107, 174, 214],
+                [33, 113, 181],
+            ],
+            &[
+                [239, 243, 255],
+                [189, 215, 231],
+                [107, 174, 214],
+                [49, 130, 189],
+                [8, 81, 156],
+            ],
+            &[
+                [239, 243, 255],
+                [198, 219, 239],
+                [158, 202, 225],
+                [107, 174, 214],
+                [49, 130, 189],
+                [8, 81, 156],
+            ],
+            &[
+                [239, 243, 255],
+                [198, 219, 239],
+                [158, 202, 225],
+                [107, 174, 214],
+                [66, 146, 198],
+                [33, 113, 181],
+                [8, 69, 148],
+            ],
+            &[
+                [247, 251, 255],
+                [222, 235, 247],
+                [198, 219, 239],
+                [158, 202, 225],
+                [107, 174, 214],
+                [66, 146, 198],
+                [33, 113, 181],
+                [8, 69, 148],
+            ],
+            &[
+                [247, 251, 255],
+                [222, 235, 247],
+                [198, 219, 239],
+                [158, 202, 225],
+                [107, 174, 214],
+                [66, 146, 198],
+                [33, 113, 181],
+                [8, 81, 156],
+                [8, 48, 107],
+            ],
+        ],
+    ),
+    (
+        "brbg",
+        &[
+            &[[216, 179, 101], [245, 245, 245], [90, 180, 172]],
+            &[
+                [166, 97, 26],
+                [223, 194, 125],
+                [128, 205, 193],
+                [1, 133, 113],
+            ],
+            &[
+                [166, 97, 26],
+                [223, 194, 125],
+                [245, 245, 245],
+                [128, 205, 193],
+                [1, 133, 113],
+            ],
+            &[
+                [140, 81, 10],
+                [216, 179, 101],
+                [246, 232, 195],
+                [199, 234, 229],
+                [90, 180, 172],
+                [1, 102, 94],
+            ],
+            &[
+                [140, 81, 10],
+                [216, 179, 101],
+                [246, 232, 195],
+                [245, 245, 245],
+                [199, 234, 229],
+                [90, 180, 172],
+                [1, 102, 94],
+            ],
+            &[
+                [140, 81, 10],
+                [191, 129, 45],
+                [223, 194, 125],
+                [246, 232, 195],
+                [199, 234, 229],
+                [128, 205, 193],
+                [53, 151, 143],
+                [1, 102, 94],
+            ],
+            &[
+                [140, 81, 10],
+                [191, 129, 45],
+                [223, 194, 125],
+                [246, 232, 195],
+                [245, 245, 245],
+                [199, 234, 229],
+                [128, 205, 193],
+                [53, 151, 143],
+                [1, 102, 94],
+            ],
+            &[
+                [84, 48, 5],
+                [140, 81, 10],
+                [191, 129, 45],
+                [223, 194, 125],
+                [246, 232, 195],
+                [199, 234, 229],
+                [128, 205, 193],
+                [53, 151, 143],
+                [1, 102, 94],
+                [0, 60, 48],
+            ],
+            &[
+                [84, 48, 5],
+                [140, 81, 10],
+                [191, 129, 45],
+                [223, 194, 125],
+                [246, 232, 195],
+                [245, 245, 245],
+                [199, 234, 229],
+                [128, 205, 193],
+                [53, 151, 143],
+                [1, 102, 94],
+                [0, 60, 48],
+            ],
+        ],
+    ),
+    (
+        "bugn",
+        &[
+            &[[229, 245, 249], [153, 216, 201], [44, 162, 95]],
+            &[
+                [237, 248, 251],
+                [178, 226, 226],
+                [102, 194, 164],
+                [35, 139, 69],
+            ],
+            &[
+                [237, 248, 251],
+                [178, 226, 226],
+                [102, 194, 164],
+                [44, 162, 95],
+                [0, 109, 44],
+            ],
+            &[
+                [237, 248, 251],
+                [204, 236, 230],
+                [153, 216, 201],
+                [102, 194, 164],
+                [44, 162, 95],
+                [0, 109, 44],
+            ],
+            &[
+                [237, 248, 251],
+                [204, 236, 230],
+                [153, 216, 201],
+                [102, 194, 164],
+                [65, 174, 118],
+                [35, 139, 69],
+                [0, 88, 36],
+            ],
+            &[
+                [247, 252, 253],
+                [229, 245, 249],
+                [204, 236, 230],
+                [153, 216, 201],
+                [102, 194, 164],
+                [65, 174, 118],
+                [35, 139, 69],
+                [0, 88, 36],
+            ],
+            &[
+                [247, 252, 253],
+                [229, 245, 249],
+                [204, 236, 230],
+                [153, 216, 201],
+                [102, 194, 164],
+                [65, 174, 118],
+                [35, 139, 69],
+                [0, 109, 44],
+                [0, 68, 27],
+            ],
+        ],
+    ),
+    (
+        "bupu",
+        &[
+            &[[224, 236, 244], [158, 188, 218], [136, 86, 167]],
+            &[
+                [237, 248, 251],
+                [179, 205, 227],
+                [140, 150, 198],
+                [136, 65, 157],
+            ],
+            &[
+                [237, 248, 251],
+                [179, 205, 227],
+                [140, 150, 198],
+                [136, 86, 167],
+                [129, 15, 124],
+            ],
+            &[
+                [237, 248, 251],
+                [191, 211, 230],
+                [158, 188, 218],
+                [140, 150, 198],
+                [136, 86, 167],
+                [129, 15, 124],
+            ],
+            &[
+                [237, 248, 251],
+                [191, 211, 230],
+                [158, 188, 218],
+                [140, 150, 198],
+                [140, 107, 177],
+                [136, 65, 157],
+                [110, 1, 107],
+            ],
+            &[
+                [247, 252, 253],
+                [224, 236, 244],
+                [191, 211, 230],
+                [158, 188, 218],
+                [140, 150, 198],
+                [140, 107, 177],
+                [136, 65, 157],
+                [110, 1, 107],
+            ],
+            &[
+                [247, 252, 253],
+                [224, 236, 244],
+                [191, 211, 230],
+                [158, 188, 218],
+                [140, 150, 198],
+                [140, 107, 177],
+                [136, 65, 157],
+                [129, 15, 124],
+                [77, 0, 75],
+            ],
+        ],
+    ),
+    (
+        "gnbu",
+        &[
+            &[[224, 243, 219], [168, 221, 181], [67, 162, 202]],
+            &[
+                [240, 249, 232],
+                [186, 228, 188],
+                [123, 204, 196],
+                [43, 140, 190],
+            ],
+            &[
+                [240, 249, 232],
+                [186, 228, 188],
+                [123, 204, 196],
+                [67, 162, 202],
+                [8, 104, 172],
+            ],
+            &[
+                [240, 249, 232],
+                [204, 235, 197],
+                [168, 221, 181],
+                [123, 204, 196],
+                [67, 162, 202],
+                [8, 104, 172],
+            ],
+            &[
+                [240, 249, 232],
+                [204, 235, 197],
+                [168, 221, 181],
+                [123, 204, 196],
+                [78, 179, 211],
+                [43, 140, 190],
+                [8, 88, 158],
+            ],
+            &[
+                [247, 252, 240],
+                [224, 243, 219],
+                [204, 235, 197],
+                [168, 221, 181],
+                [123, 204, 196],
+                [78, 179, 211],
+                [43, 140, 190],
+                [8, 88, 158],
+            ],
+            &[
+                [247, 252, 240],
+                [224, 243, 219],
+                [204, 235, 197],
+                [168, 221, 181],
+                [123, 204, 196],
+                [78, 179, 211],
+                [43, 140, 190],
+                [8, 104, 172],
+                [8, 64, 129],
+            ],
+        ],
+    ),
+    (
+        "greens",
+        &[
+            &[[229, 245, 224], [161, 217, 155], [49, 163, 84]],
+            &[
+                [237, 248, 233],
+                [186, 228, 179],
+                [116, 196, 118],
+                [35, 139, 69],
+            ],
+            &[
+                [237, 248, 233],
+                [186, 228, 179],
+                [116, 196, 118],
+                [49, 163, 84],
+                [0, 109, 44],
+            ],
+            &[
+                [237, 248, 233],
+                [199, 233, 192],
+                [161, 217, 155],
+                [116, 196, 118],
+                [49, 163, 84],
+                [0, 109, 44],
+            ],
+            &[
+                [237, 248, 233],
+                [199, 233, 192],
+                [161, 217, 155],
+                [116, 196, 118],
+                [65, 171, 93],
+                [35, 139, 69],
+                [0, 90, 50],
+            ],
+            &[
+                [247, 252, 245],
+                [229, 245, 224],
+                [199, 233, 192],
+                [161, 217, 155],
+                [116, 196, 118],
+                [65, 171, 93],
+                [35, 139, 69],
+                [0, 90, 50],
+            ],
+            &[
+                [247, 252, 245],
+                [229, 245, 224],
+                [199, 233, 192],
+                [161, 217, 155],
+                [116, 196, 118],
+                [65, 171, 93],
+                [35, 139, 69],
+                [0, 109, 44],
+                [0, 68, 27],
+            ],
+        ],
+    ),
+    (
+        "greys",
+        &[
+            &[[240, 240, 240], [189, 189, 189], [99, 99, 99]],
+            &[
+                [247, 247, 247],
+                [204, 204, 204],
+                [150, 150, 150],
+                [82, 82, 82],
+            ],
+            &[
+                [247, 247, 247],
+                [204, 204, 204],
+                [150, 150, 150],
+                [99, 99, 99],
+                [37, 37, 37],
+            ],
+            &[
+                [247, 247, 247],
+                [217, 217, 217],
+                [189, 189, 189],
+                [150, 150, 150],
+                [99, 99, 99],
+                [37, 37, 37],
+            ],
+            &[
+                [247, 247, 247],
+                [217, 217, 217],
+                [189, 189, 189],
+                [150, 150, 150],
+                [115, 115, 115],
+                [82, 82, 82],
+                [37, 37, 37],
+            ],
+            &[
+                [255, 255, 255],
+                [240, 240, 240],
+                [217, 217, 217],
+                [189, 189, 189],
+                [150, 150, 150],
+                [115, 115, 115],
+                [82, 82, 82],
+                [37, 37, 37],
+            ],
+            &[
+                [255, 255, 255],
+                [240, 240, 240],
+                [217, 217, 217],
+                [189, 189, 189],
+                [150, 150, 150],
+                [115, 115, 115],
+                [82, 82, 82],
+                [37, 37, 37],
+                [0, 0, 0],
+            ],
+        ],
+    ),
+    (
+        "oranges",
+        &[
+            &[[254, 230, 206], [253, 174, 107], [230, 85, 13]],
+            &[
+                [254, 237, 222],
+                [253, 190, 133],
+                [253, 141, 60],
+                [217, 71, 1],
+            ],
+            &[
+                [254, 237, 222],
+                [253, 190, 133],
+                [253, 141, 60],
+                [230, 85, 13],
+                [166, 54, 3],
+            ],
+            &[
+                [254, 237, 222],
+                [253, 208, 162],
+                [253, 174, 107],
+                [253, 141, 60],
+                [230, 85, 13],
+                [166, 54, 3],
+            ],
+            &[
+                [254, 237, 222],
+                [253, 208, 162],
+                [253, 174, 107],
+                [253, 141, 60],
+                [241, 105, 19],
+                [217, 72, 1],
+                [140, 45, 4],
+            ],
+            &[
+                [255, 245, 235],
+                [254, 230, 206],
+                [253, 208, 162],
+                [253, 174, 107],
+                [253, 141, 60],
+                [241, 105, 19],
+                [217, 72, 1],
+                [140, 45, 4],
+            ],
+            &[
+                [255, 245, 235],
+                [254, 230, 206],
+                [253, 208, 162],
+                [253, 174, 107],
+                [253, 141, 60],
+                [241, 105, 19],
+                [217, 72, 1],
+                [166, 54, 3],
+                [127, 39, 4],
+            ],
+        ],
+    ),
+    (
+        "orrd",
+        &[
+            &[[254, 232, 200], [253, 187, 132], [227, 74, 51]],
+            &[
+                [254, 240, 217],
+                [253, 204, 138],
+                [252, 141, 89],
+                [215, 48, 31],
+            ],
+            &[
+                [254, 240, 217],
+                [253, 204, 138],
+                [252, 141, 89],
+                [227, 74, 51],
+                [179, 0, 0],
+            ],
+            &[
+                [254, 240, 217],
+                [253, 212, 158],
+                [253, 187, 132],
+                [252, 141, 89],
+                [227, 74, 51],
+                [179, 0, 0],
+            ],
+            &[
+                [254, 240, 217],
+                [253, 212, 158],
+                [253, 187, 132],
+                [252, 141, 89],
+                [239, 101, 72],
+                [215, 48, 31],
+                [153, 0, 0],
+            ],
+            &[
+                [255, 247, 236],
+                [254, 232, 200],
+                [253, 212, 158],
+                [253, 187, 132],
+                [252, 141, 89],
+                [239, 101, 72],
+                [215, 48, 31],
+                [153, 0, 0],
+            ],
+            &[
+                [255, 247, 236],
+                [254, 232, 200],
+                [253, 212, 158],
+                [253, 187, 132],
+                [252, 141, 89],
+                [239, 101, 72],
+                [215, 48, 31],
+                [179, 0, 0],
+                [127, 0, 0],
+            ],
+        ],
+    ),
+    (
+        "piyg",
+        &[
+            &[[233, 163, 201], [247, 247, 247], [161, 215, 106]],
+            &[
+                [208, 28, 139],
+                [241, 182, 218],
+                [184, 225, 134],
+                [77, 172, 38],
+            ],
+            &[
+                [208, 28, 139],
+                [241, 182, 218],
+                [247, 247, 247],
+                [184, 225, 134],
+                [77, 172, 38],
+            ],
+            &[
+                [197, 27, 125],
+                [233, 163, 201],
+                [253, 224, 239],
+                [230, 245, 208],
+                [161, 215, 106],
+                [77, 146, 33],
+            ],
+            &[
+                [197, 27, 125],
+                [233, 163, 201],
+                [253, 224, 239],
+                [247, 247, 247],
+                [230, 245, 208],
+                [161, 215, 106],
+                [77, 146, 33],
+            ],
+            &[
+                [197, 27, 125],
+                [222, 119, 174],
+                [241, 182, 218],
+                [253, 224, 239],
+                [230, 245, 208],
+                [184, 225, 134],
+                [127, 188, 65],
+                [77, 146, 33],
+            ],
+            &[
+                [197, 27, 125],
+                [222, 119, 174],
+                [241, 182, 218],
+                [253, 224, 239],
+                [247, 247, 247],
+                [230, 245, 208],
+                [184, 225, 134],
+                [127, 188, 65],
+                [77, 146, 33],
+            ],
+            &[
+                [142, 1, 82],
+                [197, 27, 125],
+                [222, 119, 174],
+                [241, 182, 218],
+                [253, 224, 239],
+                [230, 245, 208],
+                [184, 225, 134],
+                [127, 188, 65],
+                [77, 146, 33],
+                [39, 100, 25],
+            ],
+            &[
+                [142, 1, 82],
+                [197, 27, 125],
+                [222, 119, 174],
+                [241, 182, 218],
+                [253, 224, 239],
+                [247, 247, 247],
+                [230, 245, 208],
+                [184, 225, 134],
+                [127, 188, 65],
+                [77, 146, 33],
+                [39, 100, 25],
+            ],
+        ],
+    ),
+    (
+        "prgn",
+        &[
+            &[[175, 141, 195], [247, 247, 247], [127, 191, 123]],
+            &[
+                [123, 50, 148],
+                [194, 165, 207],
+                [166, 219, 160],
+                [0, 136, 55],
+            ],
+            &[
+                [123, 50, 148],
+                [194, 165, 207],
+                [247, 247, 247],
+                [166, 219, 160],
+                [0, 136, 55],
+            ],
+            &[
+                [118, 42, 131],
+                [175, 141, 195],
+                [231, 212, 232],
+                [217, 240, 211],
+                [127, 191, 123],
+                [27, 120, 55],
+            ],
+            &[
+                [118, 42, 131],
+                [175, 141, 195],
+                [231, 212, 232],
+                [247, 247, 247],
+                [217, 240, 211],
+                [127, 191, 123],
+                [27, 120, 55],
+            ],
+            &[
+                [118, 42, 131],
+                [153, 112, 171],
+                [194, 165, 207],
+                [231, 212, 232],
+                [217, 240, 211],
+                [166, 219, 160],
+                [90, 174, 97],
+                [27, 120, 55],
+            ],
+            &[
+                [118, 42, 131],
+                [153, 112, 171],
+                [194, 165, 207],
+                [231, 212, 232],
+                [247, 247, 247],
+                [217, 240, 211],
+                [166, 219, 160],
+                [90, 174, 97],
+                [27, 120, 55],
+            ],
+            &[
+                [64, 0, 75],
+                [118, 42, 131],
+                [153, 112, 171],
+                [194, 165, 207],
+                [231, 212, 232],
+                [217, 240, 211],
+                [166, 219, 160],
+                [90, 174, 97],
+                [27, 120, 55],
+                [0, 68, 27],
+            ],
+            &[
+                [64, 0, 75],
+                [118, 42, 131],
+                [153, 112, 171],
+                [194, 165, 207],
+                [231, 212, 232],
+                [247, 247, 247],
+                [217, 240, 211],
+                [166, 219, 160],
+                [90, 174, 97],
+                [27, 120, 55],
+                [0, 68, 27],
+            ],
+        ],
+    ),
+    (
+        "pubu",
+        &[
+            &[[236, 231, 242], [166, 189, 219], [43, 140, 190]],
+            &[
+                [241, 238, 246],
+                [189, 201, 225],
+                [116, 169, 207],
+                [5, 112, 176],
+            ],
+            &[
+                [241, 238, 246],
+                [189, 201, 225],
+                [116, 169, 207],
+                [43, 140, 190],
+                [4, 90, 141],
+            ],
+            &[
+                [241, 238, 246],
+                [208, 209, 230],
+                [166, 189, 219],
+                [116, 169, 207],
+                [43, 140, 190],
+                [4, 90, 141],
+            ],
+            &[
+                [241, 238, 246],
+                [208, 209, 230],
+                [166, 189, 219],
+                [116, 169, 207],
+                [54, 144, 192],
+                [5, 112, 176],
+                [3, 78, 123],
+            ],
+            &[
+                [255, 247, 251],
+                [236, 231, 242],
+                [208, 209, 230],
+                [166, 189, 219],
+                [116, 169, 207],
+                [54, 144, 192],
+                [5, 112, 176],
+                [3, 78, 123],
+            ],
+            &[
+                [255, 247, 251],
+                [236, 231, 242],
+                [208, 209, 230],
+                [166, 189, 219],
+                [116, 169, 207],
+                [54, 144, 192],
+                [5, 112, 176],
+                [4, 90, 141],
+                [2, 56, 88],
+            ],
+        ],
+    ),
+    (
+        "pubugn",
+        &[
+            &[[236, 226, 240], [166, 189, 219], [28, 144, 153]],
+            &[
+                [246, 239, 247],
+                [189, 201, 225],
+                [103, 169, 207],
+                [2, 129, 138],
+            ],
+            &[
+                [246, 239, 247],
+                [189, 201, 225],
+                [103, 169, 207],
+                [28, 144, 153],
+                [1, 108, 89],
+            ],
+            &[
+                [246, 239, 247],
+                [208, 209, 230],
+                [166, 189, 219],
+                [103, 169, 207],
+                [28, 144, 153],
+                [1, 108, 89],
+            ],
+            &[
+                [246, 239, 247],
+                [208, 209, 230],
+                [166, 189, 219],
+                [103, 169, 207],
+                [54, 144, 192],
+                [2, 129, 138],
+                [1, 100, 80],
+            ],
+            &[
+                [255, 247, 251],
+                [236, 226, 240],
+                [208, 209, 230],
+                [166, 189, 219],
+                [103, 169, 207],
+                [54, 144, 192],
+                [2, 129, 138],
+                [1, 100, 80],
+            ],
+            &[
+                [255, 247, 251],
+                [236, 226, 240],
+                [208, 209, 230],
+                [166, 189, 219],
+                [103, 169, 207],
+                [54, 144, 192],
+                [2, 129, 138],
+                [1, 108, 89],
+                [1, 70, 54],
+            ],
+        ],
+    ),
+    (
+        "puor",
+        &[
+            &[[241, 163, 64], [247, 247, 247], [153, 142, 195]],
+            &[[230, 97, 1], [253, 184, 99], [178, 171, 210], [94, 60, 153]],
+            &[
+                [230, 97, 1],
+                [253, 184, 99],
+                [247, 247, 247],
+                [178, 171, 210],
+                [94, 60, 153],
+            ],
+            &[
+                [179, 88, 6],
+                [241, 163, 64],
+                [254, 224, 182],
+                [216, 218, 235],
+                [153, 142, 195],
+                [84, 39, 136],
+            ],
+            &[
+                [179, 88, 6],
+                [241, 163, 64],
+                [254, 224, 182],
+                [247, 247, 247],
+                [216, 218, 235],
+                [153, 142, 195],
+                [84, 39, 136],
+            ],
+            &[
+                [179, 88, 6],
+                [224, 130, 20],
+                [253, 184, 99],
+                [254, 224, 182],
+                [216, 218, 235],
+                [178, 171, 210],
+                [128, 115, 172],
+                [84, 39, 136],
+            ],
+            &[
+                [179, 88, 6],
+                [224, 130, 20],
+                [253, 184, 99],
+                [254, 224, 182],
+                [247, 247, 247],
+                [216, 218, 235],
+                [178, 171, 210],
+                [128, 115, 172],
+                [84, 39, 136],
+            ],
+            &[
+                [127, 59, 8],
+                [179, 88, 6],
+                [224, 130, 20],
+                [253, 184, 99],
+                [254, 224, 182],
+                [216, 218, 235],
+                [178, 171, 210],
+                [128, 115, 172],
+                [84, 39, 136],
+                [45, 0, 75],
+            ],
+            &[
+                [127, 59, 8],
+                [179, 88, 6],
+                [224, 130, 20],
+                [253, 184, 99],
+                [254, 224, 182],
+                [247, 247, 247],
+                [216, 218, 235],
+                [178, 171, 210],
+                [128, 115, 172],
+                [84, 39, 136],
+                [45, 0, 75],
+            ],
+        ],
+    ),
+    (
+        "purd",
+        &[
+            &[[231, 225, 239], [201, 148, 199], [221, 28, 119]],
+            &[
+                [241, 238, 246],
+                [215, 181, 216],
+                [223, 101, 176],
+                [206, 18, 86],
+            ],
+            &[
+                [241, 238, 246],
+                [215, 181, 216],
+                [223, 101, 176],
+                [221, 28, 119],
+                [152, 0, 67],
+            ],
+            &[
+                [241, 238, 246],
+                [212, 185, 218],
+                [201, 148, 199],
+                [223, 101, 176],
+                [221, 28, 119],
+                [152, 0, 67],
+            ],
+            &[
+                [241, 238, 246],
+                [212, 185, 218],
+                [201, 148, 199],
+                [223, 101, 176],
+                [231, 41, 138],
+                [206, 18, 86],
+                [145, 0, 63],
+            ],
+            &[
+                [247, 244, 249],
+                [231, 225, 239],
+                [212, 185, 218],
+                [201, 148, 199],
+                [223, 101, 176],
+                [231, 41, 138],
+                [206, 18, 86],
+                [145, 0, 63],
+            ],
+            &[
+                [247, 244, 249],
+                [231, 225, 239],
+                [212, 185, 218],
+                [201, 148, 199],
+                [223, 101, 176],
+                [231, 41, 138],
+                [206, 18, 86],
+                [152, 0, 67],
+                [103, 0, 31],
+            ],
+        ],
+    ),
+    (
+        "purples",
+        &[
+            &[[239, 237, 245], [188, 189, 220], [117, 107, 177]],
+            &[
+                [242, 240, 247],
+                [203, 201, 226],
+                [158, 154, 200],
+                [106, 81, 163],
+            ],
+            &[
+                [242, 240, 247],
+                [203, 201, 226],
+                [158, 154, 200],
+                [117, 107, 177],
+                [84, 39, 143],
+            ],
+            &[
+                [242, 240, 247],
+                [218, 218, 235],
+                [188, 189, 220],
+                [158, 154, 200],
+                [117, 107, 177],
+                [84, 39, 143],
+            ],
+            &[
+                [242, 240, 247],
+                [218, 218, 235],
+                [188, 189, 220],
+                [158, 154, 200],
+                [128, 125, 186],
+                [106, 81, 163],
+                [74, 20, 134],
+            ],
+            &[
+                [252, 251, 253],
+                [239, 237, 245],
+                [218, 218, 235],
+                [188, 189, 220],
+                [158, 154, 200],
+                [128, 125, 186],
+                [106, 81, 163],
+                [74, 20, 134],
+            ],
+            &[
+                [252, 251, 253],
+                [239, 237, 245],
+                [218, 218, 235],
+                [188, 189, 220],
+                [158, 154, 200],
+                [128, 125, 186],
+                [106, 81, 163],
+                [84, 39, 143],
+                [63, 0, 125],
+            ],
+        ],
+    ),
+    (
+        "rdbu",
+        &[
+            &[[239, 138, 98], [247, 247, 247], [103, 169, 207]],
+            &[
+                [202, 0, 32],
+                [244, 165, 130],
+                [146, 197, 222],
+                [5, 113, 176],
+            ],
+            &[
+                [202, 0, 32],
+                [244, 165, 130],
+                [247, 247, 247],
+                [146, 197, 222],
+                [5, 113, 176],
+            ],
+            &[
+                [178, 24, 43],
+                [239, 138, 98],
+                [253, 219, 199],
+                [209, 229, 240],
+                [103, 169, 207],
+                [33, 102, 172],
+            ],
+            &[
+                [178, 24, 43],
+                [239, 138, 98],
+                [253, 219, 199],
+                [247, 247, 247],
+                [209, 229, 240],
+                [103, 169, 207],
+                [33, 102, 172],
+            ],
+            &[
+                [178, 24, 43],
+                [214, 96, 77],
+                [244, 165, 130],
+                [253, 219, 199],
+                [209, 229, 240],
+                [146, 197, 222],
+                [67, 147, 195],
+                [33, 102, 172],
+            ],
+            &[
+                [178, 24, 43],
+                [214, 96, 77],
+                [244, 165, 130],
+                [253, 219, 199],
+                [247, 247, 247],
+                [209, 229, 240],
+                [146, 197, 222],
+                [67, 147, 195],
+                [33, 102, 172],
+            ],
+            &[
+                [103, 0, 31],
+                [178, 24, 43],
+                [214, 96, 77],
+                [244, 165, 130],
+                [253, 219, 199],
+                [209, 229, 240],
+                [146, 197, 222],
+                [67, 147, 195],
+                [33, 102, 172],
+                [5, 48, 97],
+            ],
+            &[
+                [103, 0, 31],
+                [178, 24, 43],
+                [214, 96, 77],
+                [244, 165, 130],
+                [253, 219, 199],
+                [247, 247, 247],
+                [209, 229, 240],
+                [146, 197, 222],
+                [67, 147, 195],
+                [33, 102, 172],
+                [5, 48, 97],
+            ],
+        ],
+    ),
+    (
+        "rdgy",
+        &[
+            &[[239, 138, 98], [255, 255, 255], [153, 153, 153]],
+            &[[202, 0, 32], [244, 165, 130], [186, 186, 186], [64, 64, 64]],
+            &[
+                [202, 0, 32],
+                [244, 165, 130],
+                [255, 255, 255],
+                [186, 186, 186],
+                [64, 64, 64],
+            ],
+            &[
+                [178, 24, 43],
+                [239, 138, 98],
+                [253, 219, 199],
+                [224, 224, 224],
+                [153, 153, 153],
+                [77, 77, 77],
+            ],
+            &[
+                [178, 24, 43],
+                [239, 138, 98],
+                [253, 219, 199],
+                [255, 255, 255],
+                [224, 224, 224],
+                [153, 153, 153],
+                [77, 77, 77],
+            ],
+            &[
+                [178, 24, 43],
+                [214, 96, 77],
+                [244, 165, 130],
+                [253, 219, 199],
+                [224, 224, 224],
+                [186, 186, 186],
+                [135, 135, 135],
+                [77, 77, 77],
+            ],
+            &[
+                [178, 24, 43],
+                [214, 96, 77],
+                [244, 165, 130],
+                [253, 219, 199],
+                [255, 255, 255],
+                [224, 224, 224],
+                [186, 186, 186],
+                [135, 135, 135],
+                [77, 77, 77],
+            ],
+            &[
+                [103, 0, 31],
+                [178, 24, 43],
+                [214, 96, 77],
+                [244, 165, 130],
+                [253, 219, 199],
+                [224, 224, 224],
+                [186, 186, 186],
+                [135, 135, 135],
+                [77, 77, 77],
+                [26, 26, 26],
+            ],
+            &[
+                [103, 0, 31],
+                [178, 24, 43],
+                [214, 96, 77],
+                [244, 165, 130],
+                [253, 219, 199],
+                [255, 255, 255],
+                [224, 224, 224],
+                [186, 186, 186],
+                [135, 135, 135],
+                [77, 77, 77],
+                [26, 26, 26],
+            ],
+        ],
+    ),
+    (
+        "rdpu",
+        &[
+            &[[253, 224, 221], [250, 159, 181], [197, 27, 138]],
+            &[
+                [254, 235, 226],
+                [251, 180, 185],
+                [247, 104, 161],
+                [174, 1, 126],
+            ],
+            &[
+                [254, 235, 226],
+                [251, 180, 185],
+                [247, 104, 161],
+                [197, 27, 138],
+                [122, 1, 119],
+            ],
+            &[
+                [254, 235, 226],
+                [252, 197, 192],
+                [250, 159, 181],
+                [247, 104, 161],
+                [197, 27, 138],
+                [122, 1, 119],
+            ],
+            &[
+                [254, 235, 226],
+                [252, 197, 192],
+                [250, 159, 181],
+                [247, 104, 161],
+                [221, 52, 151],
+                [174, 1, 126],
+                [122, 1, 119],
+            ],
+            &[
+                [255, 247, 243],
+                [253, 224, 221],
+                [252, 197, 192],
+                [250, 159, 181],
+                [247, 104, 161],
+                [221, 52, 151],
+                [174, 1, 126],
+                [122, 1, 119],
+            ],
+            &[
+                [255, 247, 243],
+                [253, 224, 221],
+                [252, 197, 192],
+                [250, 159, 181],
+                [247, 104, 161],
+                [221, 52, 151],
+                [174, 1, 126],
+                [122, 1, 119],
+                [73, 0, 106],
+            ],
+        ],
+    ),
+    (
+        "rdylbu",
+        &[
+            &[[252, 141, 89], [255, 255, 191], [145, 191, 219]],
+            &[
+                [215, 25, 28],
+                [253, 174, 97],
+                [171, 217, 233],
+                [44, 123, 182],
+            ],
+            &[
+                [215, 25, 28],
+                [253, 174, 97],
+                [255, 255, 191],
+                [171, 217, 233],
+                [44, 123, 182],
+            ],
+            &[
+                [215, 48, 39],
+                [252, 141, 89],
+                [254, 224, 144],
+                [224, 243, 248],
+                [145, 191, 219],
+                [69, 117, 180],
+            ],
+            &[
+                [215, 48, 39],
+                [252, 141, 89],
+                [254, 224, 144],
+                [255, 255, 191],
+                [224, 243, 248],
+                [145, 191, 219],
+                [69, 117, 180],
+            ],
+            &[
+                [215, 48, 39],
+                [244, 109, 67],
+                [253, 174, 97],
+                [254, 224, 144],
+                [224, 243, 248],
+                [171, 217, 233],
+                [116, 173, 209],
+                [69, 117, 180],
+            ],
+            &[
+                [215, 48, 39],
+                [244, 109, 67],
+                [253, 174, 97],
+                [254, 224, 144],
+                [255, 255, 191],
+                [224, 243, 248],
+                [171, 217, 233],
+                [116, 173, 209],
+                [69, 117, 180],
+            ],
+            &[
+                [165, 0, 38],
+                [215, 48, 39],
+                [244, 109, 67],
+                [253, 174, 97],
+                [254, 224, 144],
+                [224, 243, 248],
+                [171, 217, 233],
+                [116, 173, 209],
+                [69, 117, 180],
+                [49, 54, 149],
+            ],
+            &[
+                [165, 0, 38],
+                [215, 48, 39],
+                [244, 109, 67],
+                [253, 174, 97],
+                [254, 224, 144],
+                [255, 255, 191],
+                [224, 243, 248],
+                [171, 217, 233],
+                [116, 173, 209],
+                [69, 117, 180],
+                [49, 54, 149],
+            ],
+        ],
+    ),
+    (
+        "rdylgn",
+        &[
+            &[[252, 141, 89], [255, 255, 191], [145, 207, 96]],
+            &[
+                [215, 25, 28],
+                [253, 174, 97],
+                [166, 217, 106],
+                [26, 150, 65],
+            ],
+            &[
+                [215, 25, 28],
+                [253, 174, 97],
+                [255, 255, 191],
+                [166, 217, 106],
+                [26, 150, 65],
+            ],
+            &[
+                [215, 48, 39],
+                [252, 141, 89],
+                [254, 224, 139],
+                [217, 239, 139],
+                [145, 207, 96],
+                [26, 152, 80],
+            ],
+            &[
+                [215, 48, 39],
+                [252, 141, 89],
+                [254, 224, 139],
+                [255, 255, 191],
+                [217, 239, 139],
+                [145, 207, 96],
+                [26, 152, 80],
+            ],
+            &[
+                [215, 48, 39],
+                [244, 109, 67],
+                [253, 174, 97],
+                [254, 224, 139],
+                [217, 239, 139],
+                [166, 217, 106],
+                [102, 189, 99],
+                [26, 152, 80],
+            ],
+            &[
+                [215, 48, 39],
+                [244, 109, 67],
+                [253, 174, 97],
+                [254, 224, 139],
+                [255, 255, 191],
+                [217, 239, 139],
+                [166, 217, 106],
+                [102, 189, 99],
+                [26, 152, 80],
+            ],
+            &[
+                [165, 0, 38],
+                [215, 48, 39],
+                [244, 109, 67],
+                [253, 174, 97],
+                [254, 224, 139],
+                [217, 239, 139],
+                [166, 217, 106],
+                [102, 189, 99],
+                [26, 152, 80],
+                [0, 104, 55],
+            ],
+            &[
+                [165, 0, 38],
+                [215, 48, 39],
+                [244, 109, 67],
+                [253, 174, 97],
+                [254, 224, 139],
+                [255, 255, 191],
+                [217, 239, 139],
+                [166, 217, 106],
+                [102, 189, 99],
+                [26, 152, 80],
+                [0, 104, 55],
+            ],
+        ],
+    ),
+    (
+        "reds",
+        &[
+            &[[254, 224, 210], [252, 146, 114], [222, 45, 38]],
+            &[
+                [254, 229, 217],
+                [252, 174, 145],
+                [251, 106, 74],
+                [203, 24, 29],
+            ],
+            &[
+                [254, 229, 217],
+                [252, 174, 145],
+                [251, 106, 74],
+                [222, 45, 38],
+                [165, 15, 21],
+            ],
+            &[
+                [254, 229, 217],
+                [252, 187, 161],
+                [252, 146, 114],
+                [251, 106, 74],
+                [222, 45, 38],
+                [165, 15, 21],
+            ],
+            &[
+                [254, 229, 217],
+                [252, 187, 161],
+                [252, 146, 114],
+                [251, 106, 74],
+                [239, 59, 44],
+                [203, 24, 29],
+                [153, 0, 13],
+            ],
+            &[
+                [255, 245, 240],
+                [254, 224, 210],
+                [252, 187, 161],
+                [252, 146, 114],
+                [251, 106, 74],
+                [239, 59, 44],
+                [203, 24, 29],
+                [153, 0, 13],
+            ],
+            &[
+                [255, 245, 240],
+                [254, 224, 210],
+                [252, 187, 161],
+                [252, 146, 114],
+                [251, 106, 74],
+                [239, 59, 44],
+                [203, 24, 29],
+                [165, 15, 21],
+                [103, 0, 13],
+            ],
+        ],
+    ),
+    (
+        "spectral",
+        &[
+            &[[252, 141, 89], [255, 255, 191], [153, 213, 148]],
+            &[
+                [215, 25, 28],
+                [253, 174, 97],
+                [171, 221, 164],
+                [43, 131, 186],
+            ],
+            &[
+                [215, 25, 28],
+                [253, 174, 97],
+                [255, 255, 191],
+                [171, 221, 164],
+                [43, 131, 186],
+            ],
+            &[
+                [213, 62, 79],
+                [252, 141, 89],
+                [254, 224, 139],
+                [230, 245, 152],
+                [153, 213, 148],
+                [50, 136, 189],
+            ],
+            &[
+                [213, 62, 79],
+                [252, 141, 89],
+                [254, 224, 139],
+                [255, 255, 191],
+                [230, 245, 152],
+                [153, 213, 148],
+                [50, 136, 189],
+            ],
+            &[
+                [213, 62, 79],
+                [244, 109, 67],
+                [253, 174, 97],
+                [254, 224, 139],
+                [230, 245, 152],
+                [171, 221, 164],
+                [102, 194, 165],
+                [50, 136, 189],
+            ],
+            &[
+                [213, 62, 79],
+                [244, 109, 67],
+                [253, 174, 97],
+                [254, 224, 139],
+                [255, 255, 191],
+                [230, 245, 152],
+                [171, 221, 164],
+                [102, 194, 165],
+                [50, 136, 189],
+            ],
+            &[
+                [158, 1, 66],
+                [213, 62, 79],
+                [244, 109, 67],
+                [253, 174, 97],
+                [254, 224, 139],
+                [230, 245, 152],
+                [171, 221, 164],
+                [102, 194, 165],
+                [50, 136, 189],
+                [94, 79, 162],
+            ],
+            &[
+                [158, 1, 66],
+                [213, 62, 79],
+                [244, 109, 67],
+                [253, 174, 97],
+                [254, 224, 139],
+                [255, 255, 191],
+                [230, 245, 152],
+                [171, 221, 164],
+                [102, 194, 165],
+                [50, 136, 189],
+                [94, 79, 162],
+            ],
+        ],
+    ),
+    (
+        "ylgn",
+        &[
+            &[[247, 252, 185], [173, 221, 142], [49, 163, 84]],
+            &[
+                [255, 255, 204],
+                [194, 230, 153],
+                [120, 198, 121],
+                [35, 132, 67],
+            ],
+            &[
+                [255, 255, 204],
+                [194, 230, 153],
+                [120, 198, 121],
+                [49, 163, 84],
+                [0, 104, 55],
+            ],
+            &[
+                [255, 255, 204],
+                [217, 240, 163],
+                [173, 221, 142],
+                [120, 198, 121],
+                [49, 163, 84],
+                [0, 104, 55],
+            ],
+            &[
+                [255, 255, 204],
+                [217, 240, 163],
+                [173, 221, 142],
+                [120, 198, 121],
+                [65, 171, 93],
+                [35, 132, 67],
+                [0, 90, 50],
+            ],
+            &[
+                [255, 255, 229],
+                [247, 252, 185],
+                [217, 240, 163],
+                [173, 221, 142],
+                [120, 198, 121],
+                [65, 171, 93],
+                [35, 132, 67],
+                [0, 90, 50],
+            ],
+            &[
+                [255, 255, 229],
+                [247, 252, 185],
+                [217, 240, 163],
+                [173, 221, 142],
+                [120, 198, 121],
+                [65, 171, 93],
+                [35, 132, 67],
+                [0, 104, 55],
+                [0, 69, 41],
+            ],
+        ],
+    ),
+    (
+        "ylgnbu",
+        &[
+            &[[237, 248, 177], [127, 205, 187], [44, 127, 184]],
+            &[
+                [255, 255, 204],
+                [161, 218, 180],
+                [65, 182, 196],
+                [34, 94, 168],
+            ],
+            &[
+                [255, 255, 204],
+                [161, 218, 180],
+                [65, 182, 196],
+                [44, 127, 184],
+                [37, 52, 148],
+            ],
+            &[
+                [255, 255, 204],
+                [199, 233, 180],
+                [127, 205, 187],
+                [65, 182, 196],
+                [44, 127, 184],
+                [37, 52, 148],
+            ],
+            &[
+                [255, 255, 204],
+                [199, 233, 180],
+                [127, 205, 187],
+                [65, 182, 196],
+                [29, 145, 192],
+                [34, 94, 168],
+                [12, 44, 132],
+            ],
+            &[
+                [255, 255, 217],
+                [237, 248, 177],
+                [199, 233, 180],
+                [127, 205, 187],
+                [65, 182, 196],
+                [29, 145, 192],
+                [34, 94, 168],
+                [12, 44, 132],
+            ],
+            &[
+                [255, 255, 217],
+                [237, 248, 177],
+                [199, 233, 180],
+                [127, 205, 187],
+                [65, 182, 196],
+                [29, 145, 192],
+                [34, 94, 168],
+                [37, 52, 148],
+                [8, 29, 88],
+            ],
+        ],
+    ),
+    (
+        "ylorbr",
+        &[
+            &[[255, 247, 188], [254, 196, 79], [217, 95, 14]],
+            &[
+                [255, 255, 212],
+                [254, 217, 142],
+                [254, 153, 41],
+                [204, 76, 2],
+            ],
+            &[
+                [255, 255, 212],
+                [254, 217, 142],
+                [254, 153, 41],
+                [217, 95, 14],
+                [153, 52, 4],
+            ],
+            &[
+                [255, 255, 212],
+                [254, 227, 145],
+                [254, 196, 79],
+                [254, 153, 41],
+                [217, 95, 14],
+                [153, 52, 4],
+            ],
+            &[
+                [255, 255, 212],
+                [254, 227, 145],
+                [254, 196, 79],
+                [254, 153, 41],
+                [236, 112, 20],
+                [204, 76, 2],
+                [140, 45, 4],
+            ],
+            &[
+                [255, 255, 229],
+                [255, 247, 188],
+                [254, 227, 145],
+                [254, 196, 79],
+                [254, 153, 41],
+                [236, 112, 20],
+                [204, 76, 2],
+                [140, 45, 4],
+            ],
+            &[
+                [255, 255, 229],
+                [255, 247, 188],
+                [254, 227, 145],
+                [254, 196, 79],
+                [254, 153, 41],
+                [236, 112, 20],
+                [204, 76, 2],
+                [153, 52, 4],
+                [102, 37, 6],
+            ],
+        ],
+    ),
+    (
+        "ylorrd",
+        &[
+            &[[255, 237, 160], [254, 178, 76], [240, 59, 32]],
+            &[
+                [255, 255, 178],
+                [254, 204, 92],
+                [253, 141, 60],
+                [227, 26, 28],
+            ],
+            &[
+                [255, 255, 178],
+                [254, 204, 92],
+                [253, 141, 60],
+                [240, 59, 32],
+                [189, 0, 38],
+            ],
+            &[
+                [255, 255, 178],
+                [254, 217, 118],
+                [254, 178, 76],
+                [253, 141, 60],
+                [240, 59, 32],
+                [189, 0, 38],
+            ],
+            &[
+                [255, 255, 178],
+                [254, 217, 118],
+                [254, 178, 76],
+                [253, 141, 60],
+                [252, 78, 42],
+                [227, 26, 28],
+                [177, 0, 38],
+            ],
+            &[
+                [255, 255, 204],
+                [255, 237, 160],
+                [254, 217, 118],
+                [254, 178, 76],
+                [253, 141, 60],
+                [252, 78, 42],
+                [227, 26, 28],
+                [177, 0, 38],
+            ],
+            &[
+                [255, 255, 204],
+                [255, 237, 160],
+                [254, 217, 118],
+                [254, 178, 76],
+                [253, 141, 60],
+                [252, 78, 42],
+                [227, 26, 28],
+                [189, 0, 38],
+                [128, 0, 38],
+            ],
+        ],
+    ),
+];
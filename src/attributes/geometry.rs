@@ -0,0 +1,302 @@
+//! Points, rectangles and splines, as found in the output of `dot -Tdot`
+//!
+//! Coordinates are in points, and `y` grows upwards. The formats are the
+//! ones documented for [point](https://graphviz.org/docs/attr-types/point/),
+//! [rect](https://graphviz.org/docs/attr-types/rect/) and
+//! [splineType](https://graphviz.org/docs/attr-types/splineType/):
+//!
+//! ```
+//! use graphviz_parser::attributes::geometry::{Point, PointF, Rect, SplineType};
+//! use std::str::FromStr;
+//!
+//! let pos = Point::from_str("1,2!").unwrap();
+//! assert_eq!((pos.x, pos.y, pos.pinned), (1.0, 2.0, true));
+//!
+//! let bb = Rect::from_str("0,0,100,200").unwrap();
+//! assert_eq!(bb.width(), 100.0);
+//!
+//! let splines = SplineType::from_str("e,10,20 0,0 1,1 2,2 3,3").unwrap();
+//! assert_eq!(splines.0[0].end, Some(PointF { x: 10.0, y: 20.0 }));
+//! assert_eq!(splines.0[0].points.len(), 4);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail};
+
+/// Parses a finite coordinate
+fn coordinate(s: &str, value: &str) -> anyhow::Result<f64> {
+    f64::from_str(s.trim())
+        .ok()
+        .filter(|c| c.is_finite())
+        .ok_or_else(|| anyhow!("Invalid coordinate {} in {}", s, value))
+}
+
+/// Parses `N` coordinates separated by commas
+fn coordinates<const N: usize>(s: &str, value: &str) -> anyhow::Result<[f64; N]> {
+    let parts: Vec<_> = s.split(',').collect();
+    if parts.len() != N {
+        bail!("Expected {} coordinates in {}", N, value);
+    }
+    let mut coordinates = [0.0; N];
+    for (coordinate_value, part) in coordinates.iter_mut().zip(parts) {
+        *coordinate_value = coordinate(part, value)?;
+    }
+    Ok(coordinates)
+}
+
+/// A point without the extras of [`Point`], as used by rectangles and splines
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointF {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Parses `x,y`
+impl FromStr for PointF {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = coordinates(s.trim(), s)?;
+        Ok(PointF { x, y })
+    }
+}
+
+impl fmt::Display for PointF {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// A point like `1,2`, which may have a third coordinate and be suffixed
+/// with `!`
+///
+/// For `pos`, the `!` pins the node to its position. For `size`, it makes
+/// the drawing scale up to the size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+    pub z: Option<f64>,
+    pub pinned: bool,
+}
+
+impl FromStr for Point {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let (trimmed, pinned) = match trimmed.strip_suffix('!') {
+            Some(trimmed) => (trimmed, true),
+            None => (trimmed, false),
+        };
+        let (x, y, z) = match trimmed.matches(',').count() {
+            2 => {
+                let [x, y, z] = coordinates(trimmed, s)?;
+                (x, y, Some(z))
+            }
+            _ => {
+                let [x, y] = coordinates(trimmed, s)?;
+                (x, y, None)
+            }
+        };
+        Ok(Point { x, y, z, pinned })
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)?;
+        if let Some(z) = self.z {
+            write!(f, ",{}", z)?;
+        }
+        if self.pinned {
+            f.write_str("!")?;
+        }
+        Ok(())
+    }
+}
+
+/// A rectangle like `0,0,100,200`, given by its lower left and upper right
+/// corners
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub lower_left: PointF,
+    pub upper_right: PointF,
+}
+
+impl Rect {
+    pub fn width(&self) -> f64 {
+        self.upper_right.x - self.lower_left.x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.upper_right.y - self.lower_left.y
+    }
+}
+
+impl FromStr for Rect {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [llx, lly, urx, ury] = coordinates(s.trim(), s)?;
+        Ok(Rect {
+            lower_left: PointF { x: llx, y: lly },
+            upper_right: PointF { x: urx, y: ury },
+        })
+    }
+}
+
+impl fmt::Display for Rect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.lower_left, self.upper_right)
+    }
+}
+
+/// A piecewise cubic Bézier curve, with the points the arrowheads at its
+/// ends point to
+///
+/// `points` holds the start of the curve followed by the two control points
+/// and the end of each piece, so there are `3n + 1` of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spline {
+    /// The tip of the arrowhead at the start of the curve, written `s,x,y`
+    pub start: Option<PointF>,
+    /// The tip of the arrowhead at the end of the curve, written `e,x,y`
+    pub end: Option<PointF>,
+    pub points: Vec<PointF>,
+}
+
+impl FromStr for Spline {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut spline = Spline {
+            start: None,
+            end: None,
+            points: vec![],
+        };
+        for item in s.split_whitespace() {
+            let marker = match item.split_once(',') {
+                Some(("s", point)) => Some((&mut spline.start, point)),
+                Some(("e", point)) => Some((&mut spline.end, point)),
+                _ => None,
+            };
+            match marker {
+                Some((slot, point)) if spline.points.is_empty() && slot.is_none() => {
+                    *slot = Some(PointF::from_str(point)?)
+                }
+                Some(_) => bail!("Misplaced arrowhead point {} in spline {}", item, s),
+                None => spline.points.push(PointF::from_str(item)?),
+            }
+        }
+        if spline.points.len() < 4 || spline.points.len() % 3 != 1 {
+            bail!(
+                "Expected 3n + 1 control points in spline {}, found {}",
+                s,
+                spline.points.len()
+            );
+        }
+        Ok(spline)
+    }
+}
+
+impl fmt::Display for Spline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut items = vec![];
+        if let Some(end) = self.end {
+            items.push(format!("e,{}", end));
+        }
+        if let Some(start) = self.start {
+            items.push(format!("s,{}", start));
+        }
+        items.extend(self.points.iter().map(PointF::to_string));
+        f.write_str(&items.join(" "))
+    }
+}
+
+/// The splines of an edge, separated by `;`, which is how `pos` is written
+/// for edges
+///
+/// Edges usually have a single spline; `dot` writes more of them for edges
+/// with several colors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplineType(pub Vec<Spline>);
+
+impl FromStr for SplineType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(';')
+            .map(Spline::from_str)
+            .collect::<anyhow::Result<_>>()
+            .map(SplineType)
+    }
+}
+
+impl fmt::Display for SplineType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, spline) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(";")?;
+            }
+            write!(f, "{}", spline)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Point, PointF, Rect, Spline, SplineType};
+    use std::str::FromStr;
+
+    #[test]
+    fn geometry_point_test() {
+        assert_eq!(
+            Point::from_str(" 1.5,-2,3!").unwrap(),
+            Point {
+                x: 1.5,
+                y: -2.0,
+                z: Some(3.0),
+                pinned: true
+            }
+        );
+        assert_eq!(Point::from_str("7.5,10").unwrap().to_string(), "7.5,10");
+        assert!(Point::from_str("1").is_err());
+        assert!(Point::from_str("1,2,3,4").is_err());
+        assert!(Point::from_str("1,x").is_err());
+        assert!(Point::from_str("1,2!!").is_err());
+        assert!(PointF::from_str("1,2!").is_err());
+        assert!(Rect::from_str("0,0,100").is_err());
+        assert_eq!(Rect::from_str("0,0,54,108").unwrap().height(), 108.0);
+    }
+
+    #[test]
+    fn geometry_spline_test() {
+        let spline = Spline::from_str("s,1,2 e,3,4 0,0 1,1 2,2 3,3 4,4 5,5 6,6").unwrap();
+        assert_eq!(spline.start, Some(PointF { x: 1.0, y: 2.0 }));
+        assert_eq!(spline.end, Some(PointF { x: 3.0, y: 4.0 }));
+        assert_eq!(spline.points.len(), 7);
+        assert_eq!(
+            spline.to_string(),
+            "e,3,4 s,1,2 0,0 1,1 2,2 3,3 4,4 5,5 6,6"
+        );
+
+        let splines = SplineType::from_str("0,0 1,1 2,2 3,3;e,9,9 3,3 4,4 5,5 6,6").unwrap();
+        assert_eq!(splines.0.len(), 2);
+        assert_eq!(splines.0[1].end, Some(PointF { x: 9.0, y: 9.0 }));
+        assert_eq!(splines.to_string(), "0,0 1,1 2,2 3,3;e,9,9 3,3 4,4 5,5 6,6");
+
+        for invalid in [
+            "0,0 1,1 2,2",
+            "0,0 1,1 2,2 3,3 4,4",
+            "0,0 e,1,1 1,1 2,2 3,3",
+            "e,1,1 e,2,2 0,0 1,1 2,2 3,3",
+            "",
+        ] {
+            assert!(Spline::from_str(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Just enough of [HTML labels](https://graphviz.org/doc/info/shapes.html#html)
+//! to find the ports they declare
+
+/// Returns the markup inside of the outer angle brackets of an HTML string
+pub(crate) fn markup(label: &str) -> &str {
+    let label = label.trim();
+    let label = label.strip_prefix('<').unwrap_or(label);
+    label.strip_suffix('>').unwrap_or(label).trim()
+}
+
+/// The values of the `PORT` attributes of the elements of `markup`, in order
+pub(crate) fn ports(markup: &str) -> Vec<String> {
+    let mut ports = vec![];
+    let mut rest = markup;
+    while let Some(start) = rest.find('<') {
+        let tag = &rest[start + 1..];
+        let end = tag.find('>').unwrap_or(tag.len());
+        rest = &tag[end..];
+        let tag = &tag[..end];
+        if tag.starts_with(['/', '!']) {
+            continue;
+        }
+        // Skip the element name, then read `name="value"` pairs
+        let mut attributes = tag.trim_start_matches(|c: char| !c.is_whitespace());
+        while let Some((name, value)) = attributes.split_once('=') {
+            let value = value.trim_start();
+            let quote = match value.chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => break,
+            };
+            let (value, remainder) = match value[1..].split_once(quote) {
+                Some(split) => split,
+                None => break,
+            };
+            if name.trim().eq_ignore_ascii_case("port") {
+                ports.push(value.to_string());
+            }
+            attributes = remainder;
+        }
+    }
+    ports
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{markup, ports};
+
+    #[test]
+    fn html_ports_test() {
+        let label = "<<TABLE PORT='t'><TR><TD port=\"a\" BGCOLOR=\"red\">a</TD><td>b</td>\
+                     <!-- PORT=\"c\" --><TD COLSPAN=\"2\" PORT = \"d\">d</TD></TR></TABLE>>";
+        assert_eq!(ports(markup(label)), vec!["t", "a", "d"]);
+        assert_eq!(markup("<plain text>"), "plain text");
+        assert_eq!(markup("< <b>bold</b> >"), "<b>bold</b>");
+    }
+}
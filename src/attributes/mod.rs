@@ -0,0 +1,481 @@
+//!
+//! The attributes module describes the attributes graphviz knows about, as
+//! documented [here](https://graphviz.org/doc/info/attrs.html), and provides
+//! typed access to their values.
+//!
+//! Every attribute has an [`AttributeSpec`] saying which graph components it
+//! applies to, the types of values it takes, its default and the layout
+//! engines that honor it:
+//!
+//! ```
+//! use graphviz_parser::attributes::{self, Components, Engines, ValueType};
+//!
+//! let spec = attributes::lookup("rankdir").unwrap();
+//! assert_eq!(spec.components, Components::GRAPH);
+//! assert_eq!(spec.types, &[ValueType::RankDir]);
+//! assert_eq!(spec.default, Some("TB"));
+//! assert!(spec.engines.contains(Engines::DOT));
+//! assert!(!spec.engines.contains(Engines::NEATO));
+//! ```
+//!
+//! Attributes with a type of their own implement [`Attribute`], which lets
+//! them be read from resolved graphs without looking them up by name:
+//!
+//! ```
+//! use graphviz_parser::attributes::{Label, Shape};
+//! use graphviz_parser::DotGraph;
+//! use std::str::FromStr;
+//!
+//! let graph = DotGraph::from_str("digraph { a [shape = box, label = \"A\"]; b }").unwrap().resolve();
+//! let a = &graph.nodes()[0];
+//! assert_eq!(a.attr::<Shape>().unwrap(), Some(Shape::Box));
+//! assert_eq!(a.attr::<Label>().unwrap(), Some(Label(String::from("A"))));
+//! assert_eq!(graph.nodes()[1].attr::<Shape>().unwrap(), None);
+//! ```
+
+pub mod color;
+mod color_names;
+pub mod geometry;
+pub(crate) mod html;
+pub mod record;
+mod schema;
+pub(crate) mod validate;
+mod values;
+pub mod xdot;
+
+use std::str::FromStr;
+
+use crate::semantic::Attributes;
+use color::{Color, ColorList};
+use geometry::{Point, Rect, SplineType};
+use xdot::Drawing;
+
+pub use schema::ATTRIBUTES;
+pub use values::{
+    Arrow, ArrowShape, ArrowSide, ArrowType, DirType, RankDir, Shape, Style, StyleItem, StyleName,
+};
+
+/// A set of flags, with a constant for each flag
+macro_rules! flags {
+    ($(#[$meta:meta])* $name:ident { $($(#[$flag_meta:meta])* $flag:ident = $bit:expr,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name(u16);
+
+        impl $name {
+            $($(#[$flag_meta])* pub const $flag: Self = Self(1 << $bit);)*
+
+            /// The set containing no flags
+            pub const NONE: Self = Self(0);
+
+            /// The set containing all flags
+            pub const ALL: Self = Self(0 $(| 1 << $bit)*);
+
+            pub const fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            pub const fn difference(self, other: Self) -> Self {
+                Self(self.0 & !other.0)
+            }
+
+            /// Returns true if all flags of `other` are set in `self`
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Returns true if any flag of `other` is set in `self`
+            pub const fn intersects(self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+        }
+
+        impl std::ops::BitOr for $name {
+            type Output = Self;
+
+            fn bitor(self, other: Self) -> Self {
+                self.union(other)
+            }
+        }
+    };
+}
+
+flags! {
+    /// The kinds of graph components an attribute applies to
+    Components {
+        GRAPH = 0,
+        NODE = 1,
+        EDGE = 2,
+        /// Subgraphs that aren't clusters
+        SUBGRAPH = 3,
+        CLUSTER = 4,
+    }
+}
+
+impl Components {
+    /// Builds a set from the letters graphviz documents components with,
+    /// i.e. `G`, `N`, `E`, `S` and `C`
+    pub const fn from_letters(letters: &str) -> Self {
+        let letters = letters.as_bytes();
+        let mut components = Self::NONE;
+        let mut index = 0;
+        while index < letters.len() {
+            components = components.union(match letters[index] {
+                b'G' => Self::GRAPH,
+                b'N' => Self::NODE,
+                b'E' => Self::EDGE,
+                b'S' => Self::SUBGRAPH,
+                b'C' => Self::CLUSTER,
+                _ => Self::NONE,
+            });
+            index += 1;
+        }
+        components
+    }
+}
+
+impl std::fmt::Display for Components {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let letters = [
+            (Self::GRAPH, 'G'),
+            (Self::NODE, 'N'),
+            (Self::EDGE, 'E'),
+            (Self::SUBGRAPH, 'S'),
+            (Self::CLUSTER, 'C'),
+        ];
+        for (component, letter) in letters.iter() {
+            if self.contains(*component) {
+                write!(f, "{}", letter)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+flags! {
+    /// The layout engines that honor an attribute
+    Engines {
+        DOT = 0,
+        NEATO = 1,
+        TWOPI = 2,
+        CIRCO = 3,
+        FDP = 4,
+        SFDP = 5,
+        OSAGE = 6,
+        PATCHWORK = 7,
+    }
+}
+
+/// The types of values graphviz attributes take, named after the ones
+/// documented [here](https://graphviz.org/docs/attr-types/)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueType {
+    /// A double, optionally prefixed with `+`
+    AddDouble,
+    /// A point, optionally prefixed with `+`
+    AddPoint,
+    ArrowType,
+    Bool,
+    ClusterMode,
+    Color,
+    ColorList,
+    DirType,
+    Double,
+    DoubleList,
+    EscString,
+    Int,
+    LayerList,
+    LayerRange,
+    /// An escaped string or an HTML string
+    LblString,
+    OutputMode,
+    PackMode,
+    PageDir,
+    Point,
+    PointList,
+    PortPos,
+    QuadType,
+    RankDir,
+    RankType,
+    Rect,
+    Shape,
+    SmoothType,
+    SplineType,
+    StartType,
+    String,
+    Style,
+    ViewPort,
+    /// [Drawing operations](xdot), as written by `dot -Txdot`
+    XDot,
+}
+
+/// What graphviz documents about an attribute
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttributeSpec {
+    pub name: &'static str,
+    pub components: Components,
+    /// The types of values the attribute takes, most specific first
+    pub types: &'static [ValueType],
+    /// The default value, if it doesn't depend on the component or the layout
+    pub default: Option<&'static str>,
+    pub engines: Engines,
+}
+
+/// Looks up the attribute with the given name, which is case-sensitive
+pub fn lookup(name: &str) -> Option<&'static AttributeSpec> {
+    ATTRIBUTES.iter().find(|spec| spec.name == name)
+}
+
+/// An attribute whose values have a type of their own
+pub trait Attribute: Sized {
+    /// The name of the attribute
+    const NAME: &'static str;
+
+    /// Parses a value of the attribute
+    fn from_value(value: &str) -> anyhow::Result<Self>;
+
+    /// What graphviz documents about the attribute
+    fn spec() -> &'static AttributeSpec {
+        lookup(Self::NAME).expect("Typed attributes are in the schema")
+    }
+}
+
+/// Reads the attribute `A` from a set of attributes
+///
+/// Returns `None` if it isn't set, and an error if its value is invalid.
+pub fn get<A: Attribute>(attributes: &Attributes) -> anyhow::Result<Option<A>> {
+    match attributes.get(A::NAME) {
+        Some(value) => A::from_value(value)
+            .map(Some)
+            .map_err(|e| e.context(format!("Error; invalid value for {}", A::NAME))),
+        None => Ok(None),
+    }
+}
+
+/// Defines attributes whose values are a single value of an existing type
+macro_rules! attributes {
+    ($($(#[$meta:meta])* $name:ident($value:ty) = $attribute:expr;)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct $name(pub $value);
+
+            impl Attribute for $name {
+                const NAME: &'static str = $attribute;
+
+                fn from_value(value: &str) -> anyhow::Result<Self> {
+                    Ok(Self(<$value>::from_str(value.trim())?))
+                }
+            }
+        )*
+    };
+}
+
+attributes! {
+    /// The text of a label, which may contain escape sequences like `\N`,
+    /// or be an HTML string
+    Label(String) = "label";
+    XLabel(String) = "xlabel";
+    Tooltip(String) = "tooltip";
+    FontName(String) = "fontname";
+    FontSize(f64) = "fontsize";
+    PenWidth(f64) = "penwidth";
+    /// The width of a node, in inches
+    Width(f64) = "width";
+    /// The height of a node, in inches
+    Height(f64) = "height";
+    Weight(f64) = "weight";
+    MinLen(i64) = "minlen";
+    Peripheries(i64) = "peripheries";
+    Group(String) = "group";
+    /// The `color` attribute, which is a list to draw parallel edges with
+    /// several colors
+    Colors(ColorList) = "color";
+    FillColor(ColorList) = "fillcolor";
+    BgColor(ColorList) = "bgcolor";
+    FontColor(Color) = "fontcolor";
+    PenColor(Color) = "pencolor";
+    LabelFontColor(Color) = "labelfontcolor";
+    /// The scheme color names without one of their own are looked up in
+    ColorScheme(String) = "colorscheme";
+    /// The bounding box of a graph or cluster, as computed by a layout
+    Bb(Rect) = "bb";
+    /// The position of a label, as computed by a layout
+    Lp(Point) = "lp";
+    HeadLp(Point) = "head_lp";
+    TailLp(Point) = "tail_lp";
+    XLp(Point) = "xlp";
+    ArrowHead(ArrowType) = "arrowhead";
+    ArrowTail(ArrowType) = "arrowtail";
+    /// How to draw a graph, cluster, node or edge, as computed by a layout
+    Draw(Drawing) = "_draw_";
+    /// How to draw the label of a graph, cluster, node or edge
+    LDraw(Drawing) = "_ldraw_";
+    HDraw(Drawing) = "_hdraw_";
+    TDraw(Drawing) = "_tdraw_";
+    HlDraw(Drawing) = "_hldraw_";
+    TlDraw(Drawing) = "_tldraw_";
+}
+
+/// The position of a node, or the splines of an edge
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pos {
+    Point(Point),
+    Splines(SplineType),
+}
+
+impl Attribute for Pos {
+    const NAME: &'static str = "pos";
+
+    fn from_value(value: &str) -> anyhow::Result<Self> {
+        // A single point is never a valid spline, which takes at least four
+        match Point::from_str(value) {
+            Ok(point) => Ok(Pos::Point(point)),
+            Err(_) => Ok(Pos::Splines(SplineType::from_str(value)?)),
+        }
+    }
+}
+
+/// The maximum size of a drawing, in inches
+///
+/// A single number sets both the width and the height.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Size(pub Point);
+
+impl Attribute for Size {
+    const NAME: &'static str = "size";
+
+    fn from_value(value: &str) -> anyhow::Result<Self> {
+        let (number, pinned) = match value.trim().strip_suffix('!') {
+            Some(number) => (number, true),
+            None => (value.trim(), false),
+        };
+        match f64::from_str(number) {
+            Ok(size) if size.is_finite() => Ok(Size(Point {
+                x: size,
+                y: size,
+                z: None,
+                pinned,
+            })),
+            _ => Ok(Size(Point::from_str(value)?)),
+        }
+    }
+}
+
+impl Attribute for Shape {
+    const NAME: &'static str = "shape";
+
+    fn from_value(value: &str) -> anyhow::Result<Self> {
+        value.parse()
+    }
+}
+
+impl Attribute for RankDir {
+    const NAME: &'static str = "rankdir";
+
+    fn from_value(value: &str) -> anyhow::Result<Self> {
+        value.parse()
+    }
+}
+
+impl Attribute for Style {
+    const NAME: &'static str = "style";
+
+    fn from_value(value: &str) -> anyhow::Result<Self> {
+        value.parse()
+    }
+}
+
+impl Attribute for DirType {
+    const NAME: &'static str = "dir";
+
+    fn from_value(value: &str) -> anyhow::Result<Self> {
+        value.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        get, lookup, Attribute, Bb, Components, Engines, FontSize, Lp, Pos, Shape, Size, ATTRIBUTES,
+    };
+    use crate::semantic::Attributes;
+    use crate::DotGraph;
+    use std::str::FromStr;
+
+    #[test]
+    fn attributes_schema_test() {
+        for (index, spec) in ATTRIBUTES.iter().enumerate() {
+            assert!(!spec.types.is_empty(), "{}", spec.name);
+            assert_ne!(spec.components, Components::NONE, "{}", spec.name);
+            assert_ne!(spec.engines, Engines::NONE, "{}", spec.name);
+            assert!(
+                ATTRIBUTES[..index]
+                    .iter()
+                    .all(|other| other.name != spec.name),
+                "{} is listed twice",
+                spec.name
+            );
+        }
+        let color = lookup("color").unwrap();
+        assert_eq!(color.components, Components::from_letters("ENC"));
+        assert_eq!(color.components.to_string(), "NEC");
+        assert_eq!(
+            lookup("len").unwrap().engines,
+            Engines::NEATO | Engines::FDP
+        );
+        assert!(lookup("colour").is_none());
+        assert!(lookup("k").is_none());
+        assert!(lookup("K").is_some());
+        assert_eq!(Shape::spec().default, Some("ellipse"));
+    }
+
+    #[test]
+    fn attributes_get_test() {
+        let mut attributes = Attributes::new();
+        assert_eq!(get::<FontSize>(&attributes).unwrap(), None);
+        attributes.insert("fontsize".into(), " 10.5".into());
+        attributes.insert("shape".into(), "boxx".into());
+        assert_eq!(get::<FontSize>(&attributes).unwrap(), Some(FontSize(10.5)));
+        let error = get::<Shape>(&attributes).unwrap_err();
+        assert_eq!(
+            format!("{:#}", error),
+            "Error; invalid value for shape: Unknown shape boxx"
+        );
+    }
+
+    #[test]
+    fn attributes_geometry_test() {
+        // As written by `dot -Tdot`
+        let graph = DotGraph::from_str(
+            "digraph { graph [bb=\"0,0,54,108\", size=\"7.5,10!\"];
+                a [pos=\"27,90\"]; b [pos=\"27,18!\"];
+                a -> b [pos=\"e,27,36.104 27,71.697 27,63.983 27,54.712 27,46.112\", lp=\"30,54\"] }",
+        )
+        .unwrap()
+        .resolve();
+        let bb = graph.attr::<Bb>().unwrap().unwrap();
+        assert_eq!(bb.0.height(), 108.0);
+        assert!(graph.attr::<Size>().unwrap().unwrap().0.pinned);
+        match graph.nodes()[1].attr::<Pos>().unwrap().unwrap() {
+            Pos::Point(point) => assert_eq!((point.x, point.y, point.pinned), (27.0, 18.0, true)),
+            pos => panic!("Expected a point, found {:?}", pos),
+        }
+        let edge = &graph.edges()[0];
+        match edge.attr::<Pos>().unwrap().unwrap() {
+            Pos::Splines(splines) => {
+                assert_eq!(splines.0[0].end.unwrap().y, 36.104);
+                assert_eq!(splines.0[0].points.len(), 4);
+            }
+            pos => panic!("Expected splines, found {:?}", pos),
+        }
+        assert_eq!(edge.attr::<Lp>().unwrap().unwrap().0.x, 30.0);
+
+        let mut attributes = Attributes::new();
+        attributes.insert("size".into(), "7".into());
+        attributes.insert("pos".into(), "1,2 3".into());
+        let size = get::<Size>(&attributes).unwrap().unwrap().0;
+        assert_eq!((size.x, size.y, size.pinned), (7.0, 7.0, false));
+        assert!(get::<Pos>(&attributes).is_err());
+    }
+}
@@ -0,0 +1,344 @@
+//! Labels of `record` and `Mrecord` nodes, as documented
+//! [here](https://graphviz.org/doc/info/shapes.html#record)
+//!
+//! A record label is a list of fields separated by `|`. A field is either
+//! some text, optionally preceded by a `<port>` name, or a nested list of
+//! fields between `{` and `}`, which is laid out in the other direction:
+//!
+//! ```
+//! use graphviz_parser::attributes::record::{Field, RecordLabel};
+//! use std::str::FromStr;
+//!
+//! let label = RecordLabel::from_str("<f0> left|{<f1> top|bottom}|right\\|").unwrap();
+//! assert_eq!(label.0.len(), 3);
+//! assert_eq!(
+//!     label.0[2],
+//!     Field::Text { port: None, text: String::from("right|") }
+//! );
+//! assert_eq!(label.ports(), vec!["f0", "f1"]);
+//! assert!(label.field("f1").is_some());
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail};
+
+use crate::ast_nodes::ID;
+use crate::parse::ParseOptions;
+
+/// A field of a record label
+#[derive(Debug, Clone, PartialEq)]
+pub enum Field {
+    /// Text, with the escapes of `{`, `}`, `|`, `<`, `>` and spaces removed
+    ///
+    /// Other escape sequences, like `\l` or `\N`, are kept as they are.
+    Text { port: Option<ID>, text: String },
+    /// Fields laid out in the other direction than the ones around them
+    Record(Vec<Field>),
+}
+
+/// The fields of a record label
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordLabel(pub Vec<Field>);
+
+impl RecordLabel {
+    /// The names of all ports, in the order they appear in
+    pub fn ports(&self) -> Vec<&str> {
+        let mut ports = vec![];
+        self.for_each(|field| {
+            if let Field::Text {
+                port: Some(port), ..
+            } = field
+            {
+                ports.push(port.as_str());
+            }
+        });
+        ports
+    }
+
+    /// The field with the port `port`, if any
+    pub fn field(&self, port: &str) -> Option<&Field> {
+        let mut found = None;
+        self.for_each(|field| {
+            if let Field::Text { port: Some(id), .. } = field {
+                if found.is_none() && id == port {
+                    found = Some(field);
+                }
+            }
+        });
+        found
+    }
+
+    /// Calls `f` on every field, nested ones included, in order
+    fn for_each<'a>(&'a self, mut f: impl FnMut(&'a Field)) {
+        fn walk<'a>(fields: &'a [Field], f: &mut impl FnMut(&'a Field)) {
+            for field in fields {
+                f(field);
+                if let Field::Record(fields) = field {
+                    walk(fields, f);
+                }
+            }
+        }
+        walk(&self.0, &mut f)
+    }
+}
+
+/// The field being parsed
+enum Pending {
+    Text {
+        port: Option<String>,
+        text: String,
+        /// The length of `text` without trailing unescaped whitespace
+        len: usize,
+    },
+    Record(Vec<Field>),
+}
+
+impl Pending {
+    fn new() -> Self {
+        Pending::Text {
+            port: None,
+            text: String::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns true if nothing but whitespace was parsed
+    fn is_empty(&self) -> bool {
+        matches!(
+            self,
+            Pending::Text {
+                port: None,
+                len: 0,
+                ..
+            }
+        )
+    }
+
+    /// Adds a character to the text, which is only whitespace if escaped
+    fn push(&mut self, c: char, escaped: bool, label: &str) -> anyhow::Result<()> {
+        match self {
+            Pending::Text { text, len, .. } => {
+                if escaped || !c.is_whitespace() {
+                    text.push(c);
+                    *len = text.len();
+                } else if !text.is_empty() {
+                    text.push(c);
+                }
+                Ok(())
+            }
+            Pending::Record(_) if !escaped && c.is_whitespace() => Ok(()),
+            Pending::Record(_) => bail!("Text after `}}` in record label {}", label),
+        }
+    }
+
+    fn finish(self) -> Field {
+        match self {
+            Pending::Text {
+                port,
+                mut text,
+                len,
+            } => {
+                text.truncate(len);
+                Field::Text {
+                    port: port.map(|port| port.trim().to_string()),
+                    text,
+                }
+            }
+            Pending::Record(fields) => Field::Record(fields),
+        }
+    }
+}
+
+impl FromStr for RecordLabel {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let max_depth = ParseOptions::default().max_depth;
+        let unbalanced = || anyhow!("Unbalanced braces in record label {}", s);
+        // The fields of each `{` that is still open, and of the label itself
+        let mut levels: Vec<Vec<Field>> = vec![vec![]];
+        let mut pending = Pending::new();
+        // The port name being parsed, between `<` and `>`
+        let mut port: Option<String> = None;
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            let (c, escaped) = match c {
+                '\\' => match chars.next() {
+                    Some(next) if "{}|<> ".contains(next) => (next, true),
+                    Some(next) => {
+                        pending.push('\\', true, s)?;
+                        (next, true)
+                    }
+                    None => ('\\', true),
+                },
+                c => (c, false),
+            };
+            if port.is_some() {
+                match c {
+                    '>' if !escaped => match &mut pending {
+                        Pending::Text {
+                            port: field_port, ..
+                        } if field_port.is_none() => *field_port = port.take(),
+                        _ => bail!("Misplaced port in record label {}", s),
+                    },
+                    '<' | '{' | '}' | '|' if !escaped => {
+                        bail!("Unterminated port in record label {}", s)
+                    }
+                    c => port.as_mut().expect("A port is being parsed").push(c),
+                }
+                continue;
+            }
+            match c {
+                '<' if !escaped => port = Some(String::new()),
+                '>' if !escaped => bail!("Unexpected `>` in record label {}", s),
+                '{' if !escaped => {
+                    if !pending.is_empty() {
+                        bail!(
+                            "Unexpected `{{` in the middle of a field of record label {}",
+                            s
+                        );
+                    }
+                    if levels.len() > max_depth {
+                        bail!("Record label is nested more than {} levels deep", max_depth);
+                    }
+                    levels.push(vec![]);
+                }
+                '}' if !escaped => {
+                    let mut fields = levels
+                        .pop()
+                        .filter(|_| !levels.is_empty())
+                        .ok_or_else(unbalanced)?;
+                    fields.push(std::mem::replace(&mut pending, Pending::new()).finish());
+                    pending = Pending::Record(fields);
+                }
+                '|' if !escaped => {
+                    let field = std::mem::replace(&mut pending, Pending::new()).finish();
+                    levels
+                        .last_mut()
+                        .expect("The label is always open")
+                        .push(field);
+                }
+                c => pending.push(c, escaped, s)?,
+            }
+        }
+        if port.is_some() {
+            bail!("Unterminated port in record label {}", s);
+        }
+        if levels.len() > 1 {
+            return Err(unbalanced());
+        }
+        let mut fields = levels.pop().expect("The label is always open");
+        fields.push(pending.finish());
+        Ok(RecordLabel(fields))
+    }
+}
+
+/// Writes text with the characters that are special in record labels escaped
+fn write_escaped(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    for c in text.chars() {
+        if "{}|<>".contains(c) {
+            f.write_str("\\")?;
+        }
+        write!(f, "{}", c)?;
+    }
+    Ok(())
+}
+
+fn write_fields(f: &mut fmt::Formatter<'_>, fields: &[Field]) -> fmt::Result {
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 {
+            f.write_str("|")?;
+        }
+        match field {
+            Field::Text { port, text } => {
+                if let Some(port) = port {
+                    f.write_str("<")?;
+                    write_escaped(f, port)?;
+                    f.write_str(">")?;
+                    if !text.is_empty() {
+                        f.write_str(" ")?;
+                    }
+                }
+                write_escaped(f, text)?;
+            }
+            Field::Record(fields) => {
+                f.write_str("{")?;
+                write_fields(f, fields)?;
+                f.write_str("}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl fmt::Display for RecordLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_fields(f, &self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Field, RecordLabel};
+    use std::str::FromStr;
+
+    fn text(port: Option<&str>, text: &str) -> Field {
+        Field::Text {
+            port: port.map(String::from),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn record_parse_test() {
+        assert_eq!(
+            RecordLabel::from_str("<f0> 0xf7fc4380| <f1> | <f2> |-1").unwrap(),
+            RecordLabel(vec![
+                text(Some("f0"), "0xf7fc4380"),
+                text(Some("f1"), ""),
+                text(Some("f2"), ""),
+                text(None, "-1"),
+            ])
+        );
+        assert_eq!(
+            RecordLabel::from_str("hello\\nworld |{ b |{c|<here> d|e}| f}| g | h").unwrap(),
+            RecordLabel(vec![
+                text(None, "hello\\nworld"),
+                Field::Record(vec![
+                    text(None, "b"),
+                    Field::Record(vec![
+                        text(None, "c"),
+                        text(Some("here"), "d"),
+                        text(None, "e"),
+                    ]),
+                    text(None, "f"),
+                ]),
+                text(None, "g"),
+                text(None, "h"),
+            ])
+        );
+        assert_eq!(
+            RecordLabel::from_str("\\{a\\}\\ \\<b\\>").unwrap(),
+            RecordLabel(vec![text(None, "{a} <b>")])
+        );
+        assert_eq!(
+            RecordLabel::from_str("").unwrap(),
+            RecordLabel(vec![text(None, "")])
+        );
+        for label in ["{a|b", "a}|b", "<f0 a", "a>", "<a><b>", "{a} b", "a {b}"] {
+            assert!(RecordLabel::from_str(label).is_err(), "{}", label);
+        }
+        assert!(RecordLabel::from_str(&"{".repeat(100_000)).is_err());
+    }
+
+    #[test]
+    fn record_display_test() {
+        for label in ["<f0> a|{b|<f1>}|c\\|d", "{{a}}", "<x>"] {
+            let parsed = RecordLabel::from_str(label).unwrap();
+            assert_eq!(parsed.to_string(), label);
+            assert_eq!(RecordLabel::from_str(&parsed.to_string()).unwrap(), parsed);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use super::ValueType::*;
+use super::{AttributeSpec, Components, Engines, ValueType};
+
+const ALL: Engines = Engines::ALL;
+const DOT: Engines = Engines::DOT;
+const NEATO: Engines = Engines::NEATO;
+const TWOPI: Engines = Engines::TWOPI;
+const CIRCO: Engines = Engines::CIRCO;
+const FDP: Engines = Engines::FDP;
+const SFDP: Engines = Engines::SFDP;
+const PATCHWORK: Engines = Engines::PATCHWORK;
+const NOT_DOT: Engines = Engines::ALL.difference(Engines::DOT);
+const PRISM: Engines = Engines::NEATO.union(Engines::FDP).union(Engines::SFDP);
+
+const fn spec(
+    name: &'static str,
+    components: &str,
+    types: &'static [ValueType],
+    default: Option<&'static str>,
+    engines: Engines,
+) -> AttributeSpec {
+    AttributeSpec {
+        name,
+        components: Components::from_letters(components),
+        types,
+        default,
+        engines,
+    }
+}
+
+/// Every attribute graphviz documents, in the order it documents them in
+///
+/// Defaults that depend on the component or the layout engine are left out.
+pub static ATTRIBUTES: &[AttributeSpec] = &[
+    spec("_background", "G", &[XDot], None, ALL),
+    // Written by `dot -Txdot`, as documented with the xdot format
+    spec("_draw_", "GNEC", &[XDot], None, ALL),
+    spec("_hdraw_", "E", &[XDot], None, ALL),
+    spec("_hldraw_", "E", &[XDot], None, ALL),
+    spec("_ldraw_", "GNEC", &[XDot], None, ALL),
+    spec("_tdraw_", "E", &[XDot], None, ALL),
+    spec("_tldraw_", "E", &[XDot], None, ALL),
+    spec("area", "NC", &[Double], Some("1.0"), PATCHWORK),
+    spec("arrowhead", "E", &[ArrowType], Some("normal"), ALL),
+    spec("arrowsize", "E", &[Double], Some("1.0"), ALL),
+    spec("arrowtail", "E", &[ArrowType], Some("normal"), ALL),
+    spec("bb", "GC", &[Rect], None, ALL),
+    spec("beautify", "G", &[Bool], Some("false"), SFDP),
+    spec("bgcolor", "GC", &[Color, ColorList], None, ALL),
+    spec("center", "G", &[Bool], Some("false"), ALL),
+    spec("charset", "G", &[String], Some("UTF-8"), ALL),
+    spec("class", "GNEC", &[String], Some(""), ALL),
+    spec("cluster", "CS", &[Bool], Some("false"), ALL),
+    spec("clusterrank", "G", &[ClusterMode], Some("local"), DOT),
+    spec("color", "ENC", &[Color, ColorList], Some("black"), ALL),
+    spec("colorscheme", "GNEC", &[String], Some(""), ALL),
+    spec("comment", "GNE", &[String], Some(""), ALL),
+    spec("compound", "G", &[Bool], Some("false"), DOT),
+    spec("concentrate", "G", &[Bool], Some("false"), ALL),
+    spec("constraint", "E", &[Bool], Some("true"), DOT),
+    spec("Damping", "G", &[Double], Some("0.99"), NEATO),
+    spec("decorate", "E", &[Bool], Some("false"), ALL),
+    spec("defaultdist", "G", &[Double], None, NEATO),
+    spec("dim", "G", &[Int], Some("2"), PRISM),
+    spec("dimen", "G", &[Int], Some("2"), PRISM),
+    spec("dir", "E", &[DirType], None, ALL),
+    spec(
+        "diredgeconstraints",
+        "G",
+        &[String, Bool],
+        Some("false"),
+        NEATO,
+    ),
+    spec("distortion", "N", &[Double], Some("0.0"), ALL),
+    spec("dpi", "G", &[Double], Some("96.0"), ALL),
+    spec("edgehref", "E", &[EscString], Some(""), ALL),
+    spec("edgetarget", "E", &[EscString], None, ALL),
+    spec("edgetooltip", "E", &[EscString], Some(""), ALL),
+    spec("edgeURL", "E", &[EscString], Some(""), ALL),
+    spec("epsilon", "G", &[Double], None, NEATO),
+    spec("esep", "G", &[AddDouble, AddPoint], Some("+3"), NOT_DOT),
+    spec("fillcolor", "NEC", &[Color, ColorList], None, ALL),
+    spec("fixedsize", "N", &[Bool, String], Some("false"), ALL),
+    spec("fontcolor", "ENGC", &[Color], Some("black"), ALL),
+    spec("fontname", "ENGC", &[String], Some("Times-Roman"), ALL),
+    spec("fontnames", "G", &[String], Some(""), ALL),
+    spec("fontpath", "G", &[String], None, ALL),
+    spec("fontsize", "ENGC", &[Double], Some("14.0"), ALL),
+    spec("forcelabels", "G", &[Bool], Some("true"), ALL),
+    spec("gradientangle", "NCG", &[Int], Some(""), ALL),
+    spec("group", "N", &[String], Some(""), DOT),
+    spec("head_lp", "E", &[Point], None, ALL),
+    spec("headclip", "E", &[Bool], Some("true"), ALL),
+    spec("headhref", "E", &[EscString], Some(""), ALL),
+    spec("headlabel", "E", &[LblString], Some(""), ALL),
+    spec("headport", "E", &[PortPos], Some("center"), ALL),
+    spec("headtarget", "E", &[EscString], None, ALL),
+    spec("headtooltip", "E", &[EscString], Some(""), ALL),
+    spec("headURL", "E", &[EscString], Some(""), ALL),
+    spec("height", "N", &[Double], Some("0.5"), ALL),
+    spec("href", "GCNE", &[EscString], Some(""), ALL),
+    spec("id", "GCNE", &[EscString], Some(""), ALL),
+    spec("image", "N", &[String], Some(""), ALL),
+    spec("imagepath", "G", &[String], Some(""), ALL),
+    spec("imagepos", "N", &[String], Some("mc"), ALL),
+    spec("imagescale", "N", &[Bool, String], Some("false"), ALL),
+    spec("inputscale", "G", &[Double], None, NEATO.union(FDP)),
+    spec("K", "GC", &[Double], Some("0.3"), FDP.union(SFDP)),
+    spec("label", "ENGC", &[LblString], None, ALL),
+    spec("label_scheme", "G", &[Int], Some("0"), SFDP),
+    spec("labelangle", "E", &[Double], Some("-25.0"), ALL),
+    spec("labeldistance", "E", &[Double], Some("1.0"), ALL),
+    spec("labelfloat", "E", &[Bool], Some("false"), ALL),
+    spec("labelfontcolor", "E", &[Color], Some("black"), ALL),
+    spec("labelfontname", "E", &[String], Some("Times-Roman"), ALL),
+    spec("labelfontsize", "E", &[Double], Some("14.0"), ALL),
+    spec("labelhref", "E", &[EscString], Some(""), ALL),
+    spec("labeljust", "GC", &[String], Some("c"), ALL),
+    spec("labelloc", "NGC", &[String], None, ALL),
+    spec("labeltarget", "E", &[EscString], None, ALL),
+    spec("labeltooltip", "E", &[EscString], Some(""), ALL),
+    spec("labelURL", "E", &[EscString], Some(""), ALL),
+    spec("landscape", "G", &[Bool], Some("false"), ALL),
+    spec("layer", "ENC", &[LayerRange], Some(""), ALL),
+    spec("layerlistsep", "G", &[String], Some(","), ALL),
+    spec("layers", "G", &[LayerList], Some(""), ALL),
+    spec("layerselect", "G", &[LayerRange], Some(""), ALL),
+    spec("layersep", "G", &[String], Some(":\t "), ALL),
+    spec("layout", "G", &[String], Some(""), ALL),
+    spec("len", "E", &[Double], None, NEATO.union(FDP)),
+    spec("levels", "G", &[Int], None, SFDP),
+    spec("levelsgap", "G", &[Double], Some("0.0"), NEATO),
+    spec("lhead", "E", &[String], Some(""), DOT),
+    spec("lheight", "GC", &[Double], None, ALL),
+    spec("linelength", "G", &[Int], Some("128"), ALL),
+    spec("lp", "EGC", &[Point], None, ALL),
+    spec("ltail", "E", &[String], Some(""), DOT),
+    spec("lwidth", "GC", &[Double], None, ALL),
+    spec("margin", "NCG", &[Double, Point], None, ALL),
+    spec("maxiter", "G", &[Int], None, NEATO.union(FDP)),
+    spec("mclimit", "G", &[Double], Some("1.0"), DOT),
+    spec("mindist", "G", &[Double], Some("1.0"), CIRCO),
+    spec("minlen", "E", &[Int], Some("1"), DOT),
+    spec("mode", "G", &[String], Some("major"), NEATO),
+    spec("model", "G", &[String], Some("shortpath"), NEATO),
+    spec("newrank", "G", &[Bool], Some("false"), DOT),
+    spec("nodesep", "G", &[Double], Some("0.25"), ALL),
+    spec("nojustify", "GCNE", &[Bool], Some("false"), ALL),
+    spec("normalize", "G", &[Double, Bool], Some("false"), NOT_DOT),
+    spec("notranslate", "G", &[Bool], Some("false"), NEATO),
+    spec("nslimit", "G", &[Double], None, DOT),
+    spec("nslimit1", "G", &[Double], None, DOT),
+    spec("oneblock", "G", &[Bool], Some("false"), CIRCO),
+    spec("ordering", "GN", &[String], Some(""), DOT),
+    spec("orientation", "NG", &[Double, String], None, ALL),
+    spec("outputorder", "G", &[OutputMode], Some("breadthfirst"), ALL),
+    spec("overlap", "G", &[String, Bool], Some("true"), NOT_DOT),
+    spec("overlap_scaling", "G", &[Double], Some("-4"), PRISM),
+    spec("overlap_shrink", "G", &[Bool], Some("true"), PRISM),
+    spec("pack", "G", &[Bool, Int], Some("false"), ALL),
+    spec("packmode", "G", &[PackMode], Some("node"), ALL),
+    spec("pad", "G", &[Double, Point], Some("0.0555"), ALL),
+    spec("page", "G", &[Double, Point], None, ALL),
+    spec("pagedir", "G", &[PageDir], Some("BL"), ALL),
+    spec("pencolor", "C", &[Color], Some("black"), ALL),
+    spec("penwidth", "CNE", &[Double], Some("1.0"), ALL),
+    spec("peripheries", "NC", &[Int], None, ALL),
+    spec("pin", "N", &[Bool], Some("false"), NEATO.union(FDP)),
+    spec("pos", "EN", &[Point, SplineType], None, ALL),
+    spec("quadtree", "G", &[QuadType, Bool], Some("normal"), SFDP),
+    spec("quantum", "G", &[Double], Some("0.0"), ALL),
+    spec("rank", "S", &[RankType], None, DOT),
+    spec("rankdir", "G", &[RankDir], Some("TB"), DOT),
+    spec(
+        "ranksep",
+        "G",
+        &[Double, DoubleList],
+        None,
+        DOT.union(TWOPI),
+    ),
+    spec("ratio", "G", &[Double, String], None, ALL),
+    spec("rects", "N", &[Rect], None, ALL),
+    spec("regular", "N", &[Bool], Some("false"), ALL),
+    spec("remincross", "G", &[Bool], Some("true"), DOT),
+    spec("repulsiveforce", "G", &[Double], Some("1.0"), SFDP),
+    spec("resolution", "G", &[Double], Some("96.0"), ALL),
+    spec("root", "GN", &[String, Bool], None, CIRCO.union(TWOPI)),
+    spec("rotate", "G", &[Int], Some("0"), ALL),
+    spec("rotation", "G", &[Double], Some("0"), SFDP),
+    spec("samehead", "E", &[String], Some(""), DOT),
+    spec("sametail", "E", &[String], Some(""), DOT),
+    spec("samplepoints", "N", &[Int], Some("8"), ALL),
+    spec("scale", "G", &[Double, Point], None, NOT_DOT),
+    spec("searchsize", "G", &[Int], Some("30"), DOT),
+    spec("sep", "G", &[AddDouble, AddPoint], Some("+4"), NOT_DOT),
+    spec("shape", "N", &[Shape], Some("ellipse"), ALL),
+    spec("shapefile", "N", &[String], Some(""), ALL),
+    spec("showboxes", "ENG", &[Int], Some("0"), DOT),
+    spec("sides", "N", &[Int], Some("4"), ALL),
+    spec("size", "G", &[Double, Point], None, ALL),
+    spec("skew", "N", &[Double], Some("0.0"), ALL),
+    spec("smoothing", "G", &[SmoothType], Some("none"), SFDP),
+    spec("sortv", "GCN", &[Int], Some("0"), ALL),
+    spec("splines", "G", &[Bool, String], None, ALL),
+    spec("start", "G", &[StartType], Some(""), NEATO.union(FDP)),
+    spec("style", "ENCG", &[Style], Some(""), ALL),
+    spec("stylesheet", "G", &[String], Some(""), ALL),
+    spec("tail_lp", "E", &[Point], None, ALL),
+    spec("tailclip", "E", &[Bool], Some("true"), ALL),
+    spec("tailhref", "E", &[EscString], Some(""), ALL),
+    spec("taillabel", "E", &[LblString], Some(""), ALL),
+    spec("tailport", "E", &[PortPos], Some("center"), ALL),
+    spec("tailtarget", "E", &[EscString], None, ALL),
+    spec("tailtooltip", "E", &[EscString], Some(""), ALL),
+    spec("tailURL", "E", &[EscString], Some(""), ALL),
+    spec("target", "ENGC", &[EscString, String], None, ALL),
+    spec("TBbalance", "G", &[String], Some(""), DOT),
+    spec("tooltip", "NECG", &[EscString], Some(""), ALL),
+    spec("truecolor", "G", &[Bool], None, ALL),
+    spec("URL", "ENGC", &[EscString], None, ALL),
+    spec("vertices", "N", &[PointList], None, ALL),
+    spec("viewport", "G", &[ViewPort], Some(""), ALL),
+    spec("voro_margin", "G", &[Double], Some("0.05"), NOT_DOT),
+    spec("weight", "E", &[Int, Double], Some("1"), ALL),
+    spec("width", "N", &[Double], Some("0.75"), ALL),
+    spec("xdotversion", "G", &[String], None, ALL),
+    spec("xlabel", "EN", &[LblString], Some(""), ALL),
+    spec("xlp", "NE", &[Point], None, ALL),
+];
@@ -0,0 +1,211 @@
+use std::str::FromStr;
+
+use super::color::{Color, ColorList};
+use super::geometry::{Point, Rect, SplineType};
+use super::xdot::Drawing;
+use super::{ArrowType, DirType, RankDir, Shape, Style, ValueType};
+use crate::ast_nodes::Port;
+
+/// Parses a finite double
+fn double(value: &str) -> Option<f64> {
+    f64::from_str(value.trim())
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+/// Parses a boolean the way graphviz does
+///
+/// Besides `true`, `false`, `yes` and `no`, graphviz reads integers as
+/// booleans too, with 0 being false.
+pub(crate) fn boolean(value: &str) -> Option<bool> {
+    let value = value.trim();
+    if one_of(value, &["true", "yes"]) {
+        Some(true)
+    } else if one_of(value, &["false", "no"]) {
+        Some(false)
+    } else {
+        i64::from_str(value).ok().map(|value| value != 0)
+    }
+}
+
+fn point(value: &str) -> bool {
+    Point::from_str(value).is_ok()
+}
+
+fn one_of(value: &str, keywords: &[&str]) -> bool {
+    keywords
+        .iter()
+        .any(|keyword| keyword.eq_ignore_ascii_case(value.trim()))
+}
+
+/// Checks the `array(_flags)?(%d)?` form of a pack mode, where the flags
+/// are any of `c`, `i`, `u`, `t`, `b`, `l` and `r`
+fn pack_array(value: &str) -> bool {
+    let rest = match value.get(..5) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("array") => &value[5..],
+        _ => return false,
+    };
+    let rest = match rest.strip_prefix('_') {
+        Some(flags) => {
+            let count = flags
+                .chars()
+                .take_while(|c| "ciutblr".contains(c.to_ascii_lowercase()))
+                .count();
+            if count == 0 {
+                return false;
+            }
+            &flags[count..]
+        }
+        None => rest,
+    };
+    rest.chars().all(|c| c.is_ascii_digit())
+}
+
+impl ValueType {
+    /// Checks whether `value` is a valid value of this type
+    ///
+    /// Types without a grammar of their own, like strings, accept any value.
+    pub fn accepts(&self, value: &str) -> bool {
+        let trimmed = value.trim();
+        match self {
+            ValueType::AddDouble => double(trimmed.strip_prefix('+').unwrap_or(trimmed)).is_some(),
+            ValueType::AddPoint => point(trimmed.strip_prefix('+').unwrap_or(trimmed)),
+            ValueType::Bool => boolean(trimmed).is_some(),
+            ValueType::ClusterMode => one_of(trimmed, &["local", "global", "none"]),
+            ValueType::Color => Color::from_str(trimmed).is_ok_and(|c| c.could_resolve()),
+            ValueType::ColorList => ColorList::from_str(trimmed)
+                .is_ok_and(|list| list.0.iter().all(|c| c.color.could_resolve())),
+            ValueType::DirType => DirType::from_str(trimmed).is_ok(),
+            ValueType::Double => double(trimmed).is_some(),
+            ValueType::DoubleList => trimmed.split(':').all(|d| double(d).is_some()),
+            ValueType::Int => i64::from_str(trimmed).is_ok(),
+            ValueType::OutputMode => one_of(trimmed, &["breadthfirst", "nodesfirst", "edgesfirst"]),
+            ValueType::PackMode => {
+                one_of(trimmed, &["node", "clust", "graph"]) || pack_array(trimmed)
+            }
+            ValueType::PageDir => {
+                one_of(trimmed, &["BL", "BR", "TL", "TR", "RB", "RT", "LB", "LT"])
+            }
+            ValueType::Point => point(trimmed),
+            ValueType::PointList => trimmed.split_whitespace().all(point),
+            ValueType::PortPos => Port::from_str(trimmed).is_ok(),
+            ValueType::QuadType => one_of(trimmed, &["normal", "fast", "none"]),
+            ValueType::RankDir => RankDir::from_str(trimmed).is_ok(),
+            ValueType::RankType => one_of(trimmed, &["same", "min", "source", "max", "sink"]),
+            ValueType::Rect => Rect::from_str(trimmed).is_ok(),
+            ValueType::Shape => Shape::from_str(trimmed).is_ok(),
+            ValueType::SplineType => SplineType::from_str(trimmed).is_ok(),
+            ValueType::SmoothType => one_of(
+                trimmed,
+                &[
+                    "none",
+                    "avg_dist",
+                    "graph_dist",
+                    "power_dist",
+                    "rng",
+                    "spring",
+                    "triangle",
+                ],
+            ),
+            ValueType::XDot => Drawing::from_str(trimmed).is_ok(),
+            ValueType::ArrowType => ArrowType::from_str(trimmed).is_ok(),
+            ValueType::Style => Style::from_str(trimmed).is_ok(),
+            ValueType::EscString
+            | ValueType::LayerList
+            | ValueType::LayerRange
+            | ValueType::LblString
+            | ValueType::StartType
+            | ValueType::String
+            | ValueType::ViewPort => true,
+        }
+    }
+
+    /// Describes the values of this type, as in "expected a double"
+    pub fn description(&self) -> &'static str {
+        match self {
+            ValueType::AddDouble => "a double, optionally prefixed with +",
+            ValueType::AddPoint => "a point, optionally prefixed with +",
+            ValueType::ArrowType => "an arrow type",
+            ValueType::Bool => "a boolean",
+            ValueType::ClusterMode => "one of local, global or none",
+            ValueType::Color => "a color",
+            ValueType::ColorList => "a list of colors",
+            ValueType::DirType => "one of forward, back, both or none",
+            ValueType::Double => "a double",
+            ValueType::DoubleList => "a list of doubles separated by :",
+            ValueType::EscString => "a string",
+            ValueType::Int => "an integer",
+            ValueType::LayerList => "a list of layers",
+            ValueType::LayerRange => "a range of layers",
+            ValueType::LblString => "a label",
+            ValueType::OutputMode => "one of breadthfirst, nodesfirst or edgesfirst",
+            ValueType::PackMode => "one of node, clust, graph or array(_flags)?(%d)?",
+            ValueType::PageDir => "a page direction like BL",
+            ValueType::Point => "a point like 1,2",
+            ValueType::PointList => "a list of points",
+            ValueType::PortPos => "a port",
+            ValueType::QuadType => "one of normal, fast or none",
+            ValueType::RankDir => "one of TB, LR, BT or RL",
+            ValueType::RankType => "one of same, min, source, max or sink",
+            ValueType::Rect => "a rectangle like 0,0,10,20",
+            ValueType::Shape => "a node shape",
+            ValueType::SmoothType => "a smoothing type",
+            ValueType::SplineType => "a spline",
+            ValueType::StartType => "a start type",
+            ValueType::String => "a string",
+            ValueType::Style => "a style",
+            ValueType::ViewPort => "a viewport",
+            ValueType::XDot => "xdot drawing operations",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueType;
+
+    #[test]
+    fn validate_value_types_test() {
+        assert!(ValueType::Double.accepts(" 1.5"));
+        assert!(ValueType::Double.accepts("-.5"));
+        assert!(!ValueType::Double.accepts("1.2.3"));
+        assert!(!ValueType::Double.accepts("inf"));
+        assert!(ValueType::Int.accepts("3"));
+        assert!(!ValueType::Int.accepts("3.5"));
+        assert!(ValueType::Bool.accepts("Yes"));
+        assert!(!ValueType::Bool.accepts("maybe"));
+        assert!(ValueType::Bool.accepts("0"));
+        assert!(!ValueType::Bool.accepts("1.5"));
+        assert!(!ValueType::Bool.accepts("truex"));
+        assert!(!ValueType::Bool.accepts(""));
+        assert_eq!(super::boolean(" YES "), Some(true));
+        assert_eq!(super::boolean("-2"), Some(true));
+        assert_eq!(super::boolean("0"), Some(false));
+        assert_eq!(super::boolean("off"), None);
+        assert!(ValueType::PackMode.accepts("clust"));
+        assert!(ValueType::PackMode.accepts("array"));
+        assert!(ValueType::PackMode.accepts("array_ct4"));
+        assert!(ValueType::PackMode.accepts("array12"));
+        assert!(!ValueType::PackMode.accepts("arrayzzz"));
+        assert!(!ValueType::PackMode.accepts("array_"));
+        assert!(!ValueType::PackMode.accepts("array_x"));
+        assert!(!ValueType::PackMode.accepts("array_c4x"));
+        assert!(!ValueType::PackMode.accepts("nodes"));
+        assert!(ValueType::Point.accepts("1,2!"));
+        assert!(!ValueType::Point.accepts("1"));
+        assert!(ValueType::AddPoint.accepts("+1,2"));
+        assert!(ValueType::Rect.accepts("0,0,100,200"));
+        assert!(ValueType::SplineType.accepts("e,1,2 0,0 1,1 2,2 3,3"));
+        assert!(!ValueType::SplineType.accepts("0,0 1,1"));
+        assert!(ValueType::PortPos.accepts("f0:ne"));
+        assert!(!ValueType::PortPos.accepts("f0:up"));
+        assert!(!ValueType::Shape.accepts("boxx"));
+        assert!(ValueType::Color.accepts("/svg/teal"));
+        assert!(ValueType::Color.accepts("/blues9/3"));
+        assert!(!ValueType::Color.accepts("/blues9/10"));
+        assert!(!ValueType::Color.accepts("reddish"));
+        assert!(ValueType::ColorList.accepts("red:0.5 0.5 0.5;0.25"));
+        assert!(!ValueType::ColorList.accepts("red;2"));
+        assert!(ValueType::String.accepts("anything"));
+    }
+}
@@ -0,0 +1,557 @@
+use super::Components;
+
+/// Defines an enum of keywords, which is parsed from and printed as them
+macro_rules! keywords {
+    ($(#[$meta:meta])* $name:ident, $kind:expr, { $($(#[$variant_meta:meta])* $variant:ident = $keyword:expr,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+        }
+
+        impl $name {
+            /// All values, in the order graphviz documents them in
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)*];
+
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $keyword,)*
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = anyhow::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::ALL
+                    .iter()
+                    .find(|value| value.as_str().eq_ignore_ascii_case(s.trim()))
+                    .copied()
+                    .ok_or_else(|| anyhow::anyhow!("Unknown {} {}", $kind, s))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+keywords! {
+    /// The shape of a node, as listed [here](https://graphviz.org/doc/info/shapes.html)
+    ///
+    /// Shape names are case-independent.
+    #[derive(Default)]
+    Shape, "shape", {
+        Box = "box",
+        Polygon = "polygon",
+        #[default]
+        Ellipse = "ellipse",
+        Oval = "oval",
+        Circle = "circle",
+        Point = "point",
+        Egg = "egg",
+        Triangle = "triangle",
+        Plaintext = "plaintext",
+        Plain = "plain",
+        Diamond = "diamond",
+        Trapezium = "trapezium",
+        Parallelogram = "parallelogram",
+        House = "house",
+        Pentagon = "pentagon",
+        Hexagon = "hexagon",
+        Septagon = "septagon",
+        Octagon = "octagon",
+        DoubleCircle = "doublecircle",
+        DoubleOctagon = "doubleoctagon",
+        TripleOctagon = "tripleoctagon",
+        InvTriangle = "invtriangle",
+        InvTrapezium = "invtrapezium",
+        InvHouse = "invhouse",
+        MDiamond = "Mdiamond",
+        MSquare = "Msquare",
+        MCircle = "Mcircle",
+        Rect = "rect",
+        Rectangle = "rectangle",
+        Square = "square",
+        Star = "star",
+        None = "none",
+        Underline = "underline",
+        Cylinder = "cylinder",
+        Note = "note",
+        Tab = "tab",
+        Folder = "folder",
+        Box3d = "box3d",
+        Component = "component",
+        Promoter = "promoter",
+        Cds = "cds",
+        Terminator = "terminator",
+        Utr = "utr",
+        PrimerSite = "primersite",
+        RestrictionSite = "restrictionsite",
+        FivePOverhang = "fivepoverhang",
+        ThreePOverhang = "threepoverhang",
+        NOverhang = "noverhang",
+        Assembly = "assembly",
+        Signature = "signature",
+        Insulator = "insulator",
+        RiboSite = "ribosite",
+        RnaStab = "rnastab",
+        ProteaseSite = "proteasesite",
+        ProteinStab = "proteinstab",
+        RPromoter = "rpromoter",
+        RArrow = "rarrow",
+        LArrow = "larrow",
+        LPromoter = "lpromoter",
+        /// A record, whose label describes its fields
+        Record = "record",
+        /// A record with rounded corners
+        MRecord = "Mrecord",
+    }
+}
+
+impl Shape {
+    /// Returns true for the shapes whose labels describe record fields
+    pub fn is_record(&self) -> bool {
+        matches!(self, Self::Record | Self::MRecord)
+    }
+}
+
+keywords! {
+    /// The direction `dot` lays out ranks in
+    #[derive(Default)]
+    RankDir, "rankdir", {
+        #[default]
+        TopToBottom = "TB",
+        LeftToRight = "LR",
+        BottomToTop = "BT",
+        RightToLeft = "RL",
+    }
+}
+
+keywords! {
+    /// Which ends of an edge get arrowheads
+    ///
+    /// This defaults to [`Forward`](DirType::Forward) in directed graphs, and
+    /// to [`None`](DirType::None) in undirected ones.
+    DirType, "dir", {
+        Forward = "forward",
+        Back = "back",
+        Both = "both",
+        None = "none",
+    }
+}
+
+keywords! {
+    /// A primitive arrow shape, as listed [here](https://graphviz.org/doc/info/arrows.html)
+    ArrowShape, "arrow shape", {
+        Box = "box",
+        Crow = "crow",
+        Curve = "curve",
+        ICurve = "icurve",
+        Diamond = "diamond",
+        Dot = "dot",
+        Inv = "inv",
+        None = "none",
+        Normal = "normal",
+        Tee = "tee",
+        Vee = "vee",
+    }
+}
+
+/// The side of an arrow shape that is drawn, when only one of them is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArrowSide {
+    Left,
+    Right,
+}
+
+/// A primitive arrow shape with its modifiers, like `olbox`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Arrow {
+    pub shape: ArrowShape,
+    /// Whether the shape is drawn open instead of filled, written `o`
+    pub open: bool,
+    /// Which half of the shape is drawn, written `l` or `r`
+    pub side: Option<ArrowSide>,
+}
+
+impl Arrow {
+    /// Checks that the modifiers make sense for the shape
+    fn check(&self) -> anyhow::Result<()> {
+        use ArrowShape::*;
+        if self.open && matches!(self.shape, Crow | Curve | ICurve | None | Tee | Vee) {
+            anyhow::bail!("`o` doesn't apply to {} arrows", self.shape);
+        }
+        if self.side.is_some() && matches!(self.shape, Dot | None) {
+            anyhow::bail!("`l` and `r` don't apply to {} arrows", self.shape);
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Arrow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.open {
+            f.write_str("o")?;
+        }
+        match self.side {
+            Some(ArrowSide::Left) => f.write_str("l")?,
+            Some(ArrowSide::Right) => f.write_str("r")?,
+            Option::None => {}
+        }
+        f.write_str(self.shape.as_str())
+    }
+}
+
+/// The deprecated arrow names graphviz still accepts, and what they stand for
+const ARROW_SYNONYMS: &[(&str, ArrowShape, bool, Option<ArrowSide>)] = &[
+    ("invempty", ArrowShape::Inv, true, Option::None),
+    ("ediamond", ArrowShape::Diamond, true, Option::None),
+    ("halfopen", ArrowShape::Vee, false, Some(ArrowSide::Left)),
+    ("empty", ArrowShape::Normal, true, Option::None),
+    ("open", ArrowShape::Vee, false, Option::None),
+];
+
+/// The shape of an arrowhead, made of up to 4 primitive shapes
+///
+/// The first shape is the one closest to the node.
+///
+/// ```
+/// use graphviz_parser::attributes::{Arrow, ArrowShape, ArrowSide, ArrowType};
+/// use std::str::FromStr;
+///
+/// let arrow = ArrowType::from_str("lteeoldiamond").unwrap();
+/// assert_eq!(arrow.0[1], Arrow { shape: ArrowShape::Diamond, open: true, side: Some(ArrowSide::Left) });
+/// assert_eq!(arrow.to_string(), "lteeoldiamond");
+/// assert!(ArrowType::from_str("otee").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArrowType(pub Vec<Arrow>);
+
+impl ArrowType {
+    /// The most shapes an arrow type can be made of
+    pub const MAX_SHAPES: usize = 4;
+}
+
+impl std::str::FromStr for ArrowType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rest = s.trim().to_ascii_lowercase();
+        let mut arrows = vec![];
+        while !rest.is_empty() {
+            if arrows.len() == Self::MAX_SHAPES {
+                anyhow::bail!("Arrow type {} has more than {} shapes", s, Self::MAX_SHAPES);
+            }
+            if let Some((name, shape, open, side)) = ARROW_SYNONYMS
+                .iter()
+                .find(|(name, ..)| rest.starts_with(name))
+            {
+                arrows.push(Arrow {
+                    shape: *shape,
+                    open: *open,
+                    side: *side,
+                });
+                rest.drain(..name.len());
+                continue;
+            }
+            let open = rest.starts_with('o');
+            if open {
+                rest.remove(0);
+            }
+            let side = match rest.chars().next() {
+                Some('l') => Some(ArrowSide::Left),
+                Some('r') => Some(ArrowSide::Right),
+                _ => Option::None,
+            };
+            if side.is_some() {
+                rest.remove(0);
+            }
+            let shape = *ArrowShape::ALL
+                .iter()
+                .find(|shape| rest.starts_with(shape.as_str()))
+                .ok_or_else(|| anyhow::anyhow!("Unknown arrow type {}", s))?;
+            rest.drain(..shape.as_str().len());
+            let arrow = Arrow { shape, open, side };
+            arrow
+                .check()
+                .map_err(|e| e.context(format!("Invalid arrow type {}", s)))?;
+            arrows.push(arrow);
+        }
+        if arrows.is_empty() {
+            anyhow::bail!("Empty arrow type");
+        }
+        Ok(ArrowType(arrows))
+    }
+}
+
+impl std::fmt::Display for ArrowType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for arrow in &self.0 {
+            write!(f, "{}", arrow)?;
+        }
+        Ok(())
+    }
+}
+
+keywords! {
+    /// A style, as listed [here](https://graphviz.org/docs/attr-types/style/)
+    StyleName, "style", {
+        Dashed = "dashed",
+        Dotted = "dotted",
+        Solid = "solid",
+        Invis = "invis",
+        Bold = "bold",
+        /// Edges that get thinner towards their head
+        Tapered = "tapered",
+        Filled = "filled",
+        /// Rectangular nodes and clusters filled with vertical stripes of
+        /// the colors of their `fillcolor`
+        Striped = "striped",
+        /// Elliptical nodes filled with wedges of the colors of their
+        /// `fillcolor`
+        Wedged = "wedged",
+        Diagonals = "diagonals",
+        Rounded = "rounded",
+        Radial = "radial",
+    }
+}
+
+impl StyleName {
+    /// The components the style applies to
+    pub fn components(&self) -> Components {
+        use StyleName::*;
+        match self {
+            Dashed | Dotted | Solid | Invis | Bold => {
+                Components::NODE | Components::EDGE | Components::CLUSTER
+            }
+            Tapered => Components::EDGE,
+            Filled | Striped | Rounded => Components::NODE | Components::CLUSTER,
+            Wedged | Diagonals => Components::NODE,
+            Radial => Components::GRAPH | Components::NODE | Components::CLUSTER,
+        }
+    }
+}
+
+/// An item of a [`Style`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StyleItem {
+    Named(StyleName),
+    /// `setlinewidth(width)`, which is deprecated in favor of `penwidth`
+    SetLineWidth(f64),
+}
+
+impl std::fmt::Display for StyleItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StyleItem::Named(name) => f.write_str(name.as_str()),
+            StyleItem::SetLineWidth(width) => write!(f, "setlinewidth({})", width),
+        }
+    }
+}
+
+/// The styles of a component, separated by commas
+///
+/// At most one of `dashed`, `dotted` and `solid` can be used.
+///
+/// ```
+/// use graphviz_parser::attributes::{Style, StyleItem, StyleName};
+/// use std::str::FromStr;
+///
+/// let style = Style::from_str("dashed,bold,setlinewidth(2)").unwrap();
+/// assert_eq!(
+///     style.0,
+///     vec![
+///         StyleItem::Named(StyleName::Dashed),
+///         StyleItem::Named(StyleName::Bold),
+///         StyleItem::SetLineWidth(2.0),
+///     ]
+/// );
+/// assert_eq!(style.to_string(), "dashed,bold,setlinewidth(2)");
+/// assert!(Style::from_str("dashed,dotted").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style(pub Vec<StyleItem>);
+
+impl Style {
+    /// The named styles, without `setlinewidth`
+    pub fn names(&self) -> impl Iterator<Item = StyleName> + '_ {
+        self.0.iter().filter_map(|item| match item {
+            StyleItem::Named(name) => Some(*name),
+            StyleItem::SetLineWidth(_) => Option::None,
+        })
+    }
+}
+
+/// Splits a style into its items, at the commas and whitespace that aren't
+/// between parentheses
+fn style_items(s: &str) -> anyhow::Result<Vec<&str>> {
+    let mut items = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| anyhow::anyhow!("Unbalanced parentheses in style {}", s))?
+            }
+            c if depth == 0 && (c == ',' || c.is_whitespace()) => {
+                items.push(&s[start..index]);
+                start = index + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        anyhow::bail!("Unbalanced parentheses in style {}", s);
+    }
+    items.push(&s[start..]);
+    Ok(items.into_iter().filter(|item| !item.is_empty()).collect())
+}
+
+impl std::str::FromStr for Style {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut items = vec![];
+        for item in style_items(s)? {
+            let item = match item.split_once('(') {
+                Some((name, arguments)) if name.eq_ignore_ascii_case("setlinewidth") => {
+                    let width = arguments
+                        .strip_suffix(')')
+                        .and_then(|width| f64::from_str(width.trim()).ok())
+                        .filter(|width| width.is_finite() && *width >= 0.0)
+                        .ok_or_else(|| anyhow::anyhow!("Invalid line width in style {}", s))?;
+                    StyleItem::SetLineWidth(width)
+                }
+                Some((name, _)) => anyhow::bail!("Style {} doesn't take arguments", name),
+                Option::None => StyleItem::Named(StyleName::from_str(item)?),
+            };
+            items.push(item);
+        }
+        let style = Style(items);
+        let line_styles: Vec<_> = style
+            .names()
+            .filter(|name| {
+                matches!(
+                    name,
+                    StyleName::Dashed | StyleName::Dotted | StyleName::Solid
+                )
+            })
+            .collect();
+        if line_styles.len() > 1 {
+            anyhow::bail!(
+                "Style {} combines {} and {}",
+                s,
+                line_styles[0],
+                line_styles[1]
+            );
+        }
+        Ok(style)
+    }
+}
+
+impl std::fmt::Display for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, item) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Arrow, ArrowShape, ArrowSide, ArrowType, RankDir, Shape, Style, StyleItem};
+    use std::str::FromStr;
+
+    #[test]
+    fn values_keywords_test() {
+        assert_eq!(Shape::from_str("MRecord").unwrap(), Shape::MRecord);
+        assert_eq!(Shape::from_str("box3d").unwrap().to_string(), "box3d");
+        assert!(Shape::from_str("boxx").is_err());
+        assert!(Shape::Record.is_record());
+        assert_eq!(RankDir::from_str("lr").unwrap(), RankDir::LeftToRight);
+        for shape in Shape::ALL {
+            assert_eq!(&Shape::from_str(shape.as_str()).unwrap(), shape);
+        }
+    }
+
+    #[test]
+    fn values_arrow_type_test() {
+        let arrow = ArrowType::from_str("oboxrcrowdotNone").unwrap();
+        assert_eq!(
+            arrow.0,
+            vec![
+                Arrow {
+                    shape: ArrowShape::Box,
+                    open: true,
+                    side: None
+                },
+                Arrow {
+                    shape: ArrowShape::Crow,
+                    open: false,
+                    side: Some(ArrowSide::Right)
+                },
+                Arrow {
+                    shape: ArrowShape::Dot,
+                    open: false,
+                    side: None
+                },
+                Arrow {
+                    shape: ArrowShape::None,
+                    open: false,
+                    side: None
+                },
+            ]
+        );
+        assert_eq!(arrow.to_string(), "oboxrcrowdotnone");
+        assert_eq!(ArrowType::from_str("invempty").unwrap().to_string(), "oinv");
+        assert_eq!(ArrowType::from_str("halfopen").unwrap().to_string(), "lvee");
+        assert_eq!(ArrowType::from_str("open").unwrap().to_string(), "vee");
+        assert_eq!(
+            ArrowType::from_str("ediamond").unwrap().to_string(),
+            "odiamond"
+        );
+        for invalid in [
+            "",
+            "boxx",
+            "ocrow",
+            "ldot",
+            "rnone",
+            "dotdotdotdotdot",
+            "lo",
+            "onormalo",
+        ] {
+            assert!(ArrowType::from_str(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn values_style_test() {
+        let style = Style::from_str(" filled, rounded  setlinewidth( 1.5 ),").unwrap();
+        assert_eq!(style.0.len(), 3);
+        assert_eq!(style.0[2], StyleItem::SetLineWidth(1.5));
+        assert_eq!(style.to_string(), "filled,rounded,setlinewidth(1.5)");
+        assert_eq!(Style::from_str("").unwrap(), Style(vec![]));
+        for invalid in [
+            "dashy",
+            "bold(2)",
+            "setlinewidth(x)",
+            "setlinewidth(2",
+            "solid,dotted",
+            "filled)",
+        ] {
+            assert!(Style::from_str(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
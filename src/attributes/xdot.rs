@@ -0,0 +1,413 @@
+//! Drawing operations, as written to attributes like `_draw_` by `dot -Txdot`
+//!
+//! The operations are described [here](https://graphviz.org/docs/outputs/canon/#xdot).
+//! Together they are enough to draw a laid out graph:
+//!
+//! ```
+//! use graphviz_parser::attributes::geometry::PointF;
+//! use graphviz_parser::attributes::xdot::{Drawing, Op};
+//! use std::str::FromStr;
+//!
+//! let drawing = Drawing::from_str("c 7 -#000000 e 27 18 27 18").unwrap();
+//! assert_eq!(
+//!     drawing.0,
+//!     vec![
+//!         Op::Color { fill: false, color: String::from("#000000") },
+//!         Op::Ellipse {
+//!             filled: false,
+//!             center: PointF { x: 27.0, y: 18.0 },
+//!             width: 27.0,
+//!             height: 18.0,
+//!         },
+//!     ]
+//! );
+//! assert_eq!(drawing.to_string(), "c 7 -#000000 e 27 18 27 18");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail};
+
+use super::geometry::PointF;
+
+/// How text is aligned to its position
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextAlign {
+    Left,
+    Center,
+    Right,
+}
+
+/// A drawing operation
+#[derive(Debug, Clone, PartialEq)]
+pub enum Op {
+    /// An ellipse, where `width` and `height` are half of its axes
+    Ellipse {
+        filled: bool,
+        center: PointF,
+        width: f64,
+        height: f64,
+    },
+    Polygon {
+        filled: bool,
+        points: Vec<PointF>,
+    },
+    Polyline {
+        points: Vec<PointF>,
+    },
+    /// A piecewise cubic Bézier curve, like the ones of
+    /// [splines](super::geometry::Spline)
+    Bezier {
+        filled: bool,
+        points: Vec<PointF>,
+    },
+    /// Text `width` wide, whose baseline is at `position`
+    Text {
+        position: PointF,
+        align: TextAlign,
+        width: f64,
+        text: String,
+    },
+    /// Sets the fill or the pen color of the operations that follow
+    ///
+    /// The color is a [color](super::color::Color), or a linear `[...]` or
+    /// radial `(...)` gradient.
+    Color {
+        fill: bool,
+        color: String,
+    },
+    /// Sets the font of the text that follows
+    Font {
+        size: f64,
+        name: String,
+    },
+    /// Sets the bold, italic, underline, superscript, subscript,
+    /// strike-through and overline flags of the text that follows, in that
+    /// order from the lowest bit
+    FontFlags(u32),
+    /// Sets the style of the operations that follow, like `dashed` or
+    /// `setlinewidth(2)`
+    Style(String),
+    /// An image, drawn in the rectangle with its lower left corner at
+    /// `position`
+    Image {
+        position: PointF,
+        width: f64,
+        height: f64,
+        name: String,
+    },
+}
+
+/// The operations of a drawing, in the order they are drawn in
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drawing(pub Vec<Op>);
+
+/// Reads the items of an operation string
+struct Cursor<'a> {
+    rest: &'a str,
+    source: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    /// The next whitespace separated word, if any
+    fn word(&mut self) -> Option<&'a str> {
+        self.rest = self.rest.trim_start();
+        if self.rest.is_empty() {
+            return None;
+        }
+        let end = self
+            .rest
+            .find(char::is_whitespace)
+            .unwrap_or(self.rest.len());
+        let (word, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(word)
+    }
+
+    fn expect_word(&mut self, what: &str) -> anyhow::Result<&'a str> {
+        self.word()
+            .ok_or_else(|| anyhow!("Expected {} at the end of xdot {}", what, self.source))
+    }
+
+    fn number(&mut self) -> anyhow::Result<f64> {
+        let word = self.expect_word("a number")?;
+        f64::from_str(word)
+            .ok()
+            .filter(|number| number.is_finite())
+            .ok_or_else(|| anyhow!("Expected a number, found {} in xdot {}", word, self.source))
+    }
+
+    fn count(&mut self) -> anyhow::Result<usize> {
+        let word = self.expect_word("a count")?;
+        usize::from_str(word)
+            .map_err(|_| anyhow!("Expected a count, found {} in xdot {}", word, self.source))
+    }
+
+    fn point(&mut self) -> anyhow::Result<PointF> {
+        Ok(PointF {
+            x: self.number()?,
+            y: self.number()?,
+        })
+    }
+
+    /// Reads a count followed by that many points
+    fn points(&mut self) -> anyhow::Result<Vec<PointF>> {
+        (0..self.count()?).map(|_| self.point()).collect()
+    }
+
+    /// Reads a string written as `n -bytes`, where `n` is the length of
+    /// `bytes` in bytes
+    fn string(&mut self) -> anyhow::Result<String> {
+        let length = self.count()?;
+        let rest = self.rest.trim_start();
+        let rest = rest
+            .strip_prefix('-')
+            .ok_or_else(|| anyhow!("Expected a string starting with - in xdot {}", self.source))?;
+        match (rest.get(..length), rest.get(length..)) {
+            (Some(string), Some(rest)) => {
+                self.rest = rest;
+                Ok(string.to_string())
+            }
+            _ => bail!(
+                "Expected a string of {} bytes in xdot {}",
+                length,
+                self.source
+            ),
+        }
+    }
+}
+
+impl FromStr for Drawing {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cursor = Cursor { rest: s, source: s };
+        let mut ops = vec![];
+        while let Some(op) = cursor.word() {
+            let op = match op {
+                "E" | "e" => Op::Ellipse {
+                    filled: op == "E",
+                    center: cursor.point()?,
+                    width: cursor.number()?,
+                    height: cursor.number()?,
+                },
+                "P" | "p" => Op::Polygon {
+                    filled: op == "P",
+                    points: cursor.points()?,
+                },
+                "L" => Op::Polyline {
+                    points: cursor.points()?,
+                },
+                "B" | "b" => Op::Bezier {
+                    filled: op == "b",
+                    points: cursor.points()?,
+                },
+                "T" => Op::Text {
+                    position: cursor.point()?,
+                    align: match cursor.number()? {
+                        align if align < 0.0 => TextAlign::Left,
+                        align if align > 0.0 => TextAlign::Right,
+                        _ => TextAlign::Center,
+                    },
+                    width: cursor.number()?,
+                    text: cursor.string()?,
+                },
+                "C" | "c" => Op::Color {
+                    fill: op == "C",
+                    color: cursor.string()?,
+                },
+                "F" => Op::Font {
+                    size: cursor.number()?,
+                    name: cursor.string()?,
+                },
+                "t" => {
+                    let word = cursor.expect_word("font flags")?;
+                    Op::FontFlags(u32::from_str(word).map_err(|_| {
+                        anyhow!("Expected font flags, found {} in xdot {}", word, s)
+                    })?)
+                }
+                "S" => Op::Style(cursor.string()?),
+                "I" => Op::Image {
+                    position: cursor.point()?,
+                    width: cursor.number()?,
+                    height: cursor.number()?,
+                    name: cursor.string()?,
+                },
+                op => bail!("Unknown operation {} in xdot {}", op, s),
+            };
+            ops.push(op);
+        }
+        Ok(Drawing(ops))
+    }
+}
+
+fn write_points(f: &mut fmt::Formatter<'_>, points: &[PointF]) -> fmt::Result {
+    write!(f, "{}", points.len())?;
+    for point in points {
+        write!(f, " {} {}", point.x, point.y)?;
+    }
+    Ok(())
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, string: &str) -> fmt::Result {
+    write!(f, "{} -{}", string.len(), string)
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Op::Ellipse {
+                filled,
+                center,
+                width,
+                height,
+            } => write!(
+                f,
+                "{} {} {} {} {}",
+                if *filled { "E" } else { "e" },
+                center.x,
+                center.y,
+                width,
+                height
+            ),
+            Op::Polygon { filled, points } => {
+                f.write_str(if *filled { "P " } else { "p " })?;
+                write_points(f, points)
+            }
+            Op::Polyline { points } => {
+                f.write_str("L ")?;
+                write_points(f, points)
+            }
+            Op::Bezier { filled, points } => {
+                f.write_str(if *filled { "b " } else { "B " })?;
+                write_points(f, points)
+            }
+            Op::Text {
+                position,
+                align,
+                width,
+                text,
+            } => {
+                let align = match align {
+                    TextAlign::Left => -1,
+                    TextAlign::Center => 0,
+                    TextAlign::Right => 1,
+                };
+                write!(f, "T {} {} {} {} ", position.x, position.y, align, width)?;
+                write_string(f, text)
+            }
+            Op::Color { fill, color } => {
+                f.write_str(if *fill { "C " } else { "c " })?;
+                write_string(f, color)
+            }
+            Op::Font { size, name } => {
+                write!(f, "F {} ", size)?;
+                write_string(f, name)
+            }
+            Op::FontFlags(flags) => write!(f, "t {}", flags),
+            Op::Style(style) => {
+                f.write_str("S ")?;
+                write_string(f, style)
+            }
+            Op::Image {
+                position,
+                width,
+                height,
+                name,
+            } => {
+                write!(f, "I {} {} {} {} ", position.x, position.y, width, height)?;
+                write_string(f, name)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Drawing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, op) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", op)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Drawing, Op, TextAlign};
+    use crate::attributes::geometry::PointF;
+    use std::str::FromStr;
+
+    #[test]
+    fn xdot_parse_test() {
+        let drawing = Drawing::from_str(
+            "S 6 -dashed F 14 11 -Times-Roman t 3 c 7 -#ff0000 C 7 -#00ff00 \
+             P 3 0 0 10 0 5 8.5 L 2 0 0 1 1 b 4 0 0 1 1 2 2 3 3 \
+             T 27 14.3 0 23.33 5 -a b c I 1 2 30 40 7 -img.png",
+        )
+        .unwrap();
+        assert_eq!(drawing.0.len(), 10);
+        assert_eq!(drawing.0[0], Op::Style(String::from("dashed")));
+        assert_eq!(
+            drawing.0[1],
+            Op::Font {
+                size: 14.0,
+                name: String::from("Times-Roman")
+            }
+        );
+        assert_eq!(drawing.0[2], Op::FontFlags(3));
+        assert_eq!(
+            drawing.0[5],
+            Op::Polygon {
+                filled: true,
+                points: vec![
+                    PointF { x: 0.0, y: 0.0 },
+                    PointF { x: 10.0, y: 0.0 },
+                    PointF { x: 5.0, y: 8.5 },
+                ]
+            }
+        );
+        assert!(matches!(&drawing.0[7], Op::Bezier { filled: true, points } if points.len() == 4));
+        assert_eq!(
+            drawing.0[8],
+            Op::Text {
+                position: PointF { x: 27.0, y: 14.3 },
+                align: TextAlign::Center,
+                width: 23.33,
+                text: String::from("a b c")
+            }
+        );
+        assert_eq!(Drawing::from_str(&drawing.to_string()).unwrap(), drawing);
+    }
+
+    #[test]
+    fn xdot_strings_test() {
+        // Lengths are in bytes, and strings may contain anything
+        let drawing = Drawing::from_str("T 0 0 -1 10 5 -é -1 S 4 -bold").unwrap();
+        assert_eq!(
+            drawing.0[0],
+            Op::Text {
+                position: PointF { x: 0.0, y: 0.0 },
+                align: TextAlign::Left,
+                width: 10.0,
+                text: String::from("é -1")
+            }
+        );
+        assert_eq!(drawing.0[1], Op::Style(String::from("bold")));
+        assert_eq!(Drawing::from_str("").unwrap(), Drawing(vec![]));
+        for invalid in [
+            "X 1",
+            "e 1 2 3",
+            "S 10 -short",
+            "S 1 x",
+            "p 2 0 0",
+            "T 0 0 0 1 1 -é",
+            "t -1",
+        ] {
+            assert!(Drawing::from_str(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Problems found while parsing a graph, along with where in the input they are
+
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::ast_nodes::{ErrorStatement, Graph};
+use crate::visit::Visit;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Warning => f.write_str("warning"),
+            Self::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// A problem with a part of the input
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// The byte range of the input the problem is about
+    pub span: Range<usize>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn error(span: Range<usize>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            span,
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(span: Range<usize>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            span,
+            message: message.into(),
+        }
+    }
+
+    /// The line and column the diagnostic starts at in `source`, both starting at 1
+    ///
+    /// Columns are counted in characters.
+    pub fn line_col(&self, source: &str) -> (usize, usize) {
+        let before = &source[..self.span.start.min(source.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        (line, before[line_start..].chars().count() + 1)
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.severity, self.message)
+    }
+}
+
+/// A deviation from the spec that was rejected, which is reported as is
+/// rather than as a malformed statement
+#[derive(Debug)]
+pub(crate) struct Deviation(pub String);
+
+impl std::fmt::Display for Deviation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Deviation {}
+
+/// The warnings recorded while parsing
+///
+/// This is a persistent list, so that cloning the lexer to backtrack stays
+/// cheap no matter how many warnings there are.
+#[derive(Clone, Default)]
+pub(crate) struct Warnings(Option<Rc<WarningLink>>);
+
+struct WarningLink {
+    warning: Diagnostic,
+    previous: Warnings,
+}
+
+impl Warnings {
+    pub(crate) fn push(&mut self, warning: Diagnostic) {
+        let previous = std::mem::take(self);
+        self.0 = Some(Rc::new(WarningLink { warning, previous }));
+    }
+
+    /// The warnings in the order they were recorded
+    pub(crate) fn to_vec(&self) -> Vec<Diagnostic> {
+        let mut warnings = vec![];
+        let mut link = &self.0;
+        while let Some(current) = link {
+            warnings.push(current.warning.clone());
+            link = &current.previous.0;
+        }
+        warnings.reverse();
+        warnings
+    }
+}
+
+/// Long lists are unlinked one warning at a time, so that dropping them can't
+/// overflow the stack
+impl Drop for Warnings {
+    fn drop(&mut self) {
+        let mut link = self.0.take();
+        while let Some(current) = link {
+            link = match Rc::try_unwrap(current) {
+                Ok(mut current) => current.previous.0.take(),
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+/// Collects a diagnostic for every statement that couldn't be parsed
+#[derive(Default)]
+struct ErrorCollector(Vec<Diagnostic>);
+
+impl<'ast, T> Visit<'ast, T> for ErrorCollector {
+    fn visit_error(&mut self, error: &'ast ErrorStatement) {
+        self.0
+            .push(Diagnostic::error(error.span.clone(), error.message.clone()));
+    }
+}
+
+pub(crate) fn collect<T>(graph: &Graph<T>) -> Vec<Diagnostic> {
+    let mut collector = ErrorCollector::default();
+    collector.visit_graph(graph);
+    collector.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Diagnostic, Severity};
+    use crate::{DotGraph, ParseOptions};
+
+    #[test]
+    fn diagnostic_line_col_test() {
+        let source = "digraph {\n  a\n  é -> ]\n}";
+        let start = source.find(']').unwrap();
+        let diagnostic = Diagnostic::error(start..start + 1, "oops");
+        assert_eq!(diagnostic.line_col(source), (3, 8));
+        assert_eq!(Diagnostic::warning(0..1, "").line_col(source), (1, 1));
+        assert_eq!(diagnostic.to_string(), "error: oops");
+    }
+
+    #[test]
+    fn diagnostic_nested_errors_test() {
+        let source = "digraph {\n a -> ]\n subgraph s { b; c -> -> d }\n e\n}";
+        let options = ParseOptions {
+            recover: true,
+            ..ParseOptions::default()
+        };
+        let (graph, diagnostics) = DotGraph::parse_with_options(source, options).unwrap();
+        assert_eq!(graph.statements().len(), 3);
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics.iter().all(|d| d.severity == Severity::Error));
+        assert_eq!(&source[diagnostics[0].span.clone()], "a -> ]");
+        assert_eq!(&source[diagnostics[1].span.clone()], "c -> -> d");
+        assert_eq!(diagnostics[1].line_col(source), (3, 18));
+
+        let resolved = graph.resolve();
+        let ids: Vec<_> = resolved.nodes().iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "e"]);
+    }
+}
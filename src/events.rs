@@ -0,0 +1,796 @@
+//!
+//! The events module provides a streaming parser, which reports the
+//! statements of a graph one at a time instead of building the whole AST.
+//!
+//! Input is read in blocks, and only the statement currently being parsed
+//! is kept in memory, which makes it suitable for very large generated files.
+//! Subgraphs are reported as a [`SubgraphStart`](Event::SubgraphStart) event,
+//! the events of their statements, and a [`SubgraphEnd`](Event::SubgraphEnd)
+//! event.
+//!
+//! ```
+//! use graphviz_parser::events::{Event, Events};
+//!
+//! let input = "digraph G {\n a [shape = box]\n subgraph s { b }\n a -> b\n}\n";
+//! let mut nodes = 0;
+//! let mut edges = 0;
+//! for event in Events::new(input.as_bytes()) {
+//!     match event.unwrap() {
+//!         Event::NodeStmt(_) => nodes += 1,
+//!         Event::EdgeStmt(_) => edges += 1,
+//!         _ => {}
+//!     }
+//! }
+//! assert_eq!((nodes, edges), (2, 1));
+//! ```
+
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+use logos::Logos;
+
+use crate::ast_nodes::COMMA_SEPARATOR;
+use crate::ast_nodes::{
+    Assignment, AttributeStatement, Directed, Edge, GraphDirection, Node, Statement, Undirected, ID,
+};
+use crate::diagnostic::Warnings;
+use crate::lex::{unquote_string, Peekable, PeekableLexer, Token};
+use crate::parse::{Constructable, ParseOptions};
+
+/// How many bytes [`Events::new`] looks ahead to tell subgraph statements
+/// apart from subgraphs used as edge endpoints
+pub const DEFAULT_LOOKAHEAD: usize = 1 << 16;
+
+/// How many bytes are read from the reader at a time
+const BLOCK_SIZE: usize = 1 << 12;
+
+/// A single step of parsing a graph
+#[derive(Debug)]
+pub enum Event {
+    GraphStart {
+        id: ID,
+        is_strict: bool,
+        is_directed: bool,
+    },
+    NodeStmt(Node),
+    EdgeStmt(EdgeStatement),
+    AttrStmt(AttributeStatement),
+    Assignment(Assignment),
+    SubgraphStart {
+        id: Option<ID>,
+    },
+    SubgraphEnd,
+    GraphEnd,
+}
+
+/// An edge statement, along with the kind of graph it appeared in
+#[derive(Debug)]
+pub enum EdgeStatement {
+    Undirected(Box<Edge<Undirected>>),
+    Directed(Box<Edge<Directed>>),
+}
+
+/// A graph or subgraph the parser is currently inside of
+struct Block {
+    /// Whether the block is known not to be the endpoint of an edge
+    checked: bool,
+}
+
+/// An iterator over the [events](Event) of the graphs read from a reader
+///
+/// Several graphs may follow each other in the input. After an error, the
+/// iterator ends. The input is parsed with the default [`ParseOptions`],
+/// unless others are given to [`Events::with_options`]. Accepted deviations
+/// from the spec aren't warned about. Since the input is dropped as
+/// it is parsed, the spans of [assignments](Assignment::span) and
+/// [ports](crate::ast_nodes::Port::span) are relative to the start of their
+/// statement.
+pub struct Events<R: BufRead> {
+    reader: R,
+    /// Input read but not yet parsed, starting at `offset`
+    buffer: String,
+    /// The start of a character split across two blocks of input
+    partial: Vec<u8>,
+    offset: usize,
+    eof: bool,
+    blocks: Vec<Block>,
+    is_directed: bool,
+    max_lookahead: usize,
+    options: ParseOptions,
+    pending: VecDeque<Event>,
+    done: bool,
+}
+
+impl<R: BufRead> Events<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_lookahead(reader, DEFAULT_LOOKAHEAD)
+    }
+
+    /// Creates an event reader that looks at most `max_lookahead` bytes ahead
+    ///
+    /// A subgraph at the start of a statement can either be a statement of its
+    /// own, or the start of an edge statement like `{a b} -> c`. Subgraphs that
+    /// fit into the lookahead are checked before their events are reported.
+    /// Larger subgraphs are assumed to be statements of their own, and using
+    /// one as an edge endpoint is an error. Input that can't be lexed is only
+    /// taken to be an unterminated comment within the lookahead as well.
+    pub fn with_lookahead(reader: R, max_lookahead: usize) -> Self {
+        Self {
+            reader,
+            buffer: String::new(),
+            partial: vec![],
+            offset: 0,
+            eof: false,
+            blocks: vec![],
+            is_directed: false,
+            max_lookahead,
+            options: ParseOptions::default(),
+            pending: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Creates an event reader that parses with the given options
+    ///
+    /// Since there are no events for malformed statements,
+    /// [`recover`](ParseOptions::recover) is ignored.
+    ///
+    /// ```
+    /// use graphviz_parser::events::Events;
+    /// use graphviz_parser::{Conformance, ParseOptions};
+    ///
+    /// let options = ParseOptions {
+    ///     conformance: Conformance::Strict,
+    ///     ..ParseOptions::default()
+    /// };
+    /// let input = "digraph { a -- b }";
+    /// assert!(Events::new(input.as_bytes()).all(|event| event.is_ok()));
+    /// assert!(Events::with_options(input.as_bytes(), options).any(|event| event.is_err()));
+    /// ```
+    pub fn with_options(reader: R, options: ParseOptions) -> Self {
+        Self {
+            options: ParseOptions {
+                recover: false,
+                ..options
+            },
+            ..Self::new(reader)
+        }
+    }
+
+    /// Reads more input, dropping what has already been parsed
+    ///
+    /// Input is read in blocks of at most [`BLOCK_SIZE`] bytes, whatever the
+    /// length of its lines. At least as much is read as is currently buffered,
+    /// so that rescanning a long statement doesn't take quadratic time.
+    fn fill(&mut self) -> anyhow::Result<()> {
+        self.buffer.drain(..self.offset);
+        self.offset = 0;
+        let wanted = self.buffer.len().max(1);
+        let mut read = 0;
+        while read < wanted {
+            let block = self.reader.fill_buf()?;
+            if block.is_empty() {
+                if !self.partial.is_empty() {
+                    return Err(anyhow::anyhow!(
+                        "Error; input ended in the middle of a character"
+                    ));
+                }
+                self.eof = true;
+                break;
+            }
+            let n = block.len().min(BLOCK_SIZE);
+            self.partial.extend_from_slice(&block[..n]);
+            self.reader.consume(n);
+            read += n;
+            let valid = match std::str::from_utf8(&self.partial) {
+                Ok(_) => self.partial.len(),
+                Err(error) if error.error_len().is_none() => error.valid_up_to(),
+                Err(error) => return Err(error.into()),
+            };
+            let text = std::str::from_utf8(&self.partial[..valid]).expect("Checked above");
+            self.buffer.push_str(text);
+            self.partial.drain(..valid);
+        }
+        Ok(())
+    }
+
+    /// Makes progress, returning false once the input has ended cleanly
+    fn step(&mut self) -> anyhow::Result<bool> {
+        loop {
+            let scanned = if self.blocks.is_empty() {
+                self.scan_header()?
+            } else {
+                self.scan_statement()?
+            };
+            match scanned {
+                Some(progressed) => return Ok(progressed),
+                None => self.fill()?,
+            }
+        }
+    }
+
+    /// Parses `[strict] (graph | digraph) [ID] {`
+    ///
+    /// Returns `None` if more input is needed.
+    fn scan_header(&mut self) -> anyhow::Result<Option<bool>> {
+        let text = &self.buffer[self.offset..];
+        let mut lexer = Token::lexer(text);
+        let mut tokens = vec![];
+        loop {
+            match lexer.next() {
+                Some(Token::NewLine) | Some(Token::SemiColon) => {}
+                Some(Token::OpenParen) => break,
+                Some(token) => tokens.push(token),
+                None if !self.eof => return Ok(None),
+                None if tokens.is_empty() => return Ok(Some(false)),
+                None => return Err(anyhow::anyhow!("Error; input ended in a graph header")),
+            }
+        }
+        let mut tokens = tokens.into_iter().peekable();
+        let is_strict = tokens.next_if_eq(&Token::Strict).is_some();
+        let is_directed = match tokens.next() {
+            Some(Token::Digraph) => true,
+            Some(Token::Graph) => false,
+            _ if !self.eof => return Ok(None),
+            _ => return Err(anyhow::anyhow!("Error; invalid start token")),
+        };
+        let id = match tokens.next() {
+            Some(Token::ID(id)) => unquote_string(id),
+            None => String::new(),
+            Some(_) if !self.eof => return Ok(None),
+            Some(_) => return Err(anyhow::anyhow!("Error; expected either graph name or {{")),
+        };
+        if tokens.next().is_some() {
+            return Err(anyhow::anyhow!("Error; expected either graph name or {{"));
+        }
+
+        self.offset += lexer.span().end;
+        self.is_directed = is_directed;
+        self.blocks.push(Block { checked: true });
+        self.pending.push_back(Event::GraphStart {
+            id,
+            is_strict,
+            is_directed,
+        });
+        Ok(Some(true))
+    }
+
+    /// Parses the next statement, or the end of a block
+    ///
+    /// Returns `None` if more input is needed.
+    fn scan_statement(&mut self) -> anyhow::Result<Option<bool>> {
+        let text = &self.buffer[self.offset..];
+        let mut lexer = Token::lexer(text);
+        let first = loop {
+            match lexer.next() {
+                Some(Token::NewLine) | Some(Token::SemiColon) => {}
+                // Accepted deviations aren't warned about, see [`Events`]
+                Some(Token::Comma) => self.options.deviation(
+                    &mut Warnings::default(),
+                    lexer.span(),
+                    String::from(COMMA_SEPARATOR),
+                )?,
+                token => break token,
+            }
+        };
+        let start = lexer.span().start;
+        match first {
+            None if !self.eof => Ok(None),
+            None => Err(anyhow::anyhow!(
+                "Error; input ended before the end of the graph"
+            )),
+            Some(Token::CloseParen) => {
+                let end = lexer.span().end;
+                let block = self.blocks.last().expect("Inside of a block");
+                if !block.checked {
+                    match lexer.next() {
+                        None if !self.eof => return Ok(None),
+                        Some(Token::DirectedEdge) | Some(Token::UndirectedEdge) => {
+                            return Err(anyhow::anyhow!(
+                                "Error; subgraph used as an edge endpoint is larger than the lookahead"
+                            ))
+                        }
+                        _ => {}
+                    }
+                }
+                self.offset += end;
+                self.blocks.pop();
+                self.pending.push_back(if self.blocks.is_empty() {
+                    Event::GraphEnd
+                } else {
+                    Event::SubgraphEnd
+                });
+                Ok(Some(true))
+            }
+            Some(Token::Subgraph) | Some(Token::OpenParen) => {
+                let mut id = None;
+                let mut token = first;
+                if token == Some(Token::Subgraph) {
+                    token = lexer.next();
+                    if let Some(Token::ID(id_)) = token {
+                        id = Some(unquote_string(id_));
+                        token = lexer.next();
+                    }
+                }
+                match token {
+                    Some(Token::OpenParen) => {}
+                    None if !self.eof => return Ok(None),
+                    // Let the statement parser report what is wrong
+                    _ => return self.scan_chunk(start),
+                }
+                let header_end = lexer.span().end;
+                let checked = match self.is_edge_endpoint(&mut lexer, start) {
+                    None => return Ok(None),
+                    Some(Some(true)) => return self.scan_chunk(start),
+                    Some(Some(false)) => true,
+                    Some(None) => false,
+                };
+                if self.blocks.len() >= self.options.max_depth {
+                    return Err(nesting_error(self.options.max_depth));
+                }
+                self.offset += header_end;
+                self.blocks.push(Block { checked });
+                self.pending.push_back(Event::SubgraphStart { id });
+                Ok(Some(true))
+            }
+            Some(_) => self.scan_chunk(start),
+        }
+    }
+
+    /// Looks for the end of the subgraph `lexer` is inside of, and checks
+    /// whether an edge operator follows it
+    ///
+    /// Returns `None` if more input is needed, and `Some(None)` if the end
+    /// of the subgraph isn't within the lookahead.
+    fn is_edge_endpoint<'a>(
+        &self,
+        lexer: &mut logos::Lexer<'a, Token<'a>>,
+        start: usize,
+    ) -> Option<Option<bool>> {
+        let mut depth = 1;
+        while depth > 0 {
+            if lexer.span().end - start > self.max_lookahead {
+                return Some(None);
+            }
+            match lexer.next() {
+                Some(Token::OpenParen) => depth += 1,
+                Some(Token::CloseParen) => depth -= 1,
+                // Possibly a string continuing on the next line
+                Some(Token::Quotation) if !self.eof => return None,
+                // Possibly a comment continuing on the next line, as long as
+                // the buffer is small enough for that to be plausible
+                Some(Token::Error) if !self.eof && lexer.source().len() < self.max_lookahead => {
+                    return None
+                }
+                Some(_) => {}
+                None if !self.eof => return None,
+                None => return Some(None),
+            }
+        }
+        match lexer.next() {
+            None if !self.eof => None,
+            Some(Token::DirectedEdge) | Some(Token::UndirectedEdge) => Some(Some(true)),
+            _ => Some(Some(false)),
+        }
+    }
+
+    /// Parses the statements up to the next `;`, newline or `}` outside of
+    /// braces and brackets
+    ///
+    /// Returns `None` if more input is needed.
+    fn scan_chunk(&mut self, start: usize) -> anyhow::Result<Option<bool>> {
+        let text = &self.buffer[self.offset..];
+        let mut lexer = Token::lexer(&text[start..]);
+        let mut depth = 0usize;
+        let end = loop {
+            match lexer.next() {
+                Some(Token::NewLine) | Some(Token::SemiColon) if depth == 0 => {
+                    break start + lexer.span().start
+                }
+                Some(Token::CloseParen) if depth == 0 => break start + lexer.span().start,
+                Some(Token::CloseParen) | Some(Token::CloseBracket) => {
+                    depth = depth.saturating_sub(1)
+                }
+                Some(Token::OpenParen) | Some(Token::OpenBracket) => depth += 1,
+                Some(Token::Quotation) if !self.eof => return Ok(None),
+                Some(Token::Error) if !self.eof && text.len() < self.max_lookahead => {
+                    return Ok(None)
+                }
+                Some(_) => {}
+                None if !self.eof => return Ok(None),
+                None => break text.len(),
+            }
+        };
+
+        let chunk = &text[start..end];
+        if self.is_directed {
+            parse_chunk::<Directed>(
+                chunk,
+                self.blocks.len(),
+                self.options,
+                EdgeStatement::Directed,
+                &mut self.pending,
+            )?;
+        } else {
+            parse_chunk::<Undirected>(
+                chunk,
+                self.blocks.len(),
+                self.options,
+                EdgeStatement::Undirected,
+                &mut self.pending,
+            )?;
+        }
+        self.offset += end;
+        Ok(Some(true))
+    }
+}
+
+fn nesting_error(max_depth: usize) -> anyhow::Error {
+    anyhow::anyhow!(
+        "Error; blocks are nested more than {} levels deep",
+        max_depth
+    )
+}
+
+/// Parses the statements of `chunk`, which is inside of `depth` blocks
+fn parse_chunk<T: GraphDirection>(
+    chunk: &str,
+    depth: usize,
+    options: ParseOptions,
+    wrap: fn(Box<Edge<T>>) -> EdgeStatement,
+    events: &mut VecDeque<Event>,
+) -> anyhow::Result<()> {
+    let lexer = PeekableLexer::with_options(chunk, options);
+    if depth + lexer.nesting_depth() > options.max_depth {
+        return Err(nesting_error(options.max_depth));
+    }
+    let (statements, mut rest) = Vec::<Statement<T>>::from_lexer(lexer)?;
+    rest.clear_filler();
+    if rest.peek().is_some() {
+        return Err(anyhow::anyhow!("Invalid statement: {}", chunk.trim()));
+    }
+    for statement in statements {
+        push_events(statement, wrap, events);
+    }
+    Ok(())
+}
+
+fn push_events<T>(
+    statement: Statement<T>,
+    wrap: fn(Box<Edge<T>>) -> EdgeStatement,
+    events: &mut VecDeque<Event>,
+) {
+    match statement {
+        Statement::Node(node) => events.push_back(Event::NodeStmt(*node)),
+        Statement::Edge(edge) => events.push_back(Event::EdgeStmt(wrap(edge))),
+        Statement::Attribute(attribute) => events.push_back(Event::AttrStmt(*attribute)),
+        Statement::Assignment(assignment) => events.push_back(Event::Assignment(*assignment)),
+        Statement::Subgraph(mut subgraph) => {
+            events.push_back(Event::SubgraphStart {
+                id: subgraph.id.take(),
+            });
+            for statement in std::mem::take(&mut subgraph.statements) {
+                push_events(statement, wrap, events);
+            }
+            events.push_back(Event::SubgraphEnd);
+        }
+        // Only produced when recovering from errors, which this parser doesn't
+        Statement::Error(_) => {}
+    }
+}
+
+impl<R: BufRead> Iterator for Events<R> {
+    type Item = anyhow::Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some(Ok(event));
+            }
+            if self.done {
+                return None;
+            }
+            match self.step() {
+                Ok(true) => {}
+                Ok(false) => self.done = true,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EdgeStatement, Event, Events, DEFAULT_LOOKAHEAD};
+    use crate::ast_nodes::EdgeLHS;
+    use crate::ast_nodes::COMMA_SEPARATOR;
+    use crate::DotGraph;
+    use crate::{Conformance, ParseOptions};
+    use std::str::FromStr;
+
+    /// A compact description of the events, to compare them in tests
+    fn describe(events: Events<&[u8]>) -> Vec<String> {
+        events
+            .map(|event| match event.unwrap() {
+                Event::GraphStart { id, .. } => format!("graph {}", id),
+                Event::NodeStmt(node) => format!("node {}", node.id),
+                Event::EdgeStmt(EdgeStatement::Directed(edge)) => match &edge.lhs {
+                    EdgeLHS::Node(node) => format!("edge {}", node.id),
+                    EdgeLHS::Subgraph(_) => "edge {}".to_string(),
+                },
+                Event::EdgeStmt(EdgeStatement::Undirected(_)) => "edge".to_string(),
+                Event::AttrStmt(_) => "attr".to_string(),
+                Event::Assignment(assignment) => format!("{} = {}", assignment.lhs, assignment.rhs),
+                Event::SubgraphStart { id } => format!("subgraph {}", id.unwrap_or_default()),
+                Event::SubgraphEnd => "end subgraph".to_string(),
+                Event::GraphEnd => "end graph".to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn events_sanity_test() {
+        let input = "strict digraph G {\n  rankdir = LR; node [shape = box]\n  a -> b -> c\n  subgraph cluster_x {\n    d e\n    { f }\n  }\n  {a b} -> {c}\n}\n";
+        assert_eq!(
+            describe(Events::new(input.as_bytes())),
+            vec![
+                "graph G",
+                "rankdir = LR",
+                "attr",
+                "edge a",
+                "subgraph cluster_x",
+                "node d",
+                "node e",
+                "subgraph ",
+                "node f",
+                "end subgraph",
+                "end subgraph",
+                "edge {}",
+                "end graph",
+            ]
+        );
+    }
+
+    #[test]
+    fn events_graph_start_test() {
+        let mut events = Events::new("graph { a -- b }".as_bytes());
+        match events.next().unwrap().unwrap() {
+            Event::GraphStart {
+                id,
+                is_strict,
+                is_directed,
+            } => {
+                assert_eq!(id, "");
+                assert!(!is_strict);
+                assert!(!is_directed);
+            }
+            other => panic!("Unexpected event {:?}", other),
+        }
+        assert!(matches!(
+            events.next().unwrap().unwrap(),
+            Event::EdgeStmt(EdgeStatement::Undirected(_))
+        ));
+        assert!(matches!(events.next().unwrap().unwrap(), Event::GraphEnd));
+        assert!(events.next().is_none());
+    }
+
+    #[test]
+    fn events_statements_spanning_lines_test() {
+        let input = "digraph {\n a -> {\n b\n c\n } /* a\n comment } */\n d [label = \"a } \n b\",\n shape = box] }\ndigraph H { x }";
+        assert_eq!(
+            describe(Events::new(input.as_bytes())),
+            vec![
+                "graph ",
+                "edge a",
+                "node d",
+                "end graph",
+                "graph H",
+                "node x",
+                "end graph"
+            ]
+        );
+    }
+
+    #[test]
+    fn events_lookahead_limit_test() {
+        let input = "digraph { subgraph s { a b c d e f g } x }";
+        assert_eq!(
+            describe(Events::with_lookahead(input.as_bytes(), 4)),
+            vec![
+                "graph ",
+                "subgraph s",
+                "node a",
+                "node b",
+                "node c",
+                "node d",
+                "node e",
+                "node f",
+                "node g",
+                "end subgraph",
+                "node x",
+                "end graph"
+            ]
+        );
+
+        let input = "digraph { subgraph s { a b c d e f g } -> x }";
+        let events: Vec<_> = Events::new(input.as_bytes()).collect();
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|event| event.is_ok()));
+
+        let events: Vec<_> = Events::with_lookahead(input.as_bytes(), 4).collect();
+        assert!(events.last().unwrap().is_err());
+    }
+
+    #[test]
+    fn events_errors_test() {
+        let events: Vec<_> = Events::new("digraph { a -> }".as_bytes()).collect();
+        assert!(events.last().unwrap().is_err());
+        let events: Vec<_> = Events::new("digraph { a -> b".as_bytes()).collect();
+        assert!(events.last().unwrap().is_err());
+        let events: Vec<_> = Events::new("network { a }".as_bytes()).collect();
+        assert_eq!(events.len(), 1);
+        assert!(events[0].is_err());
+        assert_eq!(Events::new("\n// nothing\n".as_bytes()).count(), 0);
+    }
+
+    #[test]
+    fn events_nesting_limit_test() {
+        let deep = format!("digraph {{ {}a{} }}", "{".repeat(10000), "}".repeat(10000));
+        let events: Vec<_> = Events::new(deep.as_bytes()).collect();
+        assert!(events.last().unwrap().is_err());
+        let deep = format!(
+            "digraph {{ x -> {}a{} }}",
+            "{".repeat(10000),
+            "}".repeat(10000)
+        );
+        let events: Vec<_> = Events::new(deep.as_bytes()).collect();
+        assert!(events.last().unwrap().is_err());
+
+        let options = ParseOptions {
+            max_depth: 2,
+            ..ParseOptions::default()
+        };
+        for (input, ok) in [
+            ("digraph { { a } }", true),
+            ("digraph { { { a } } }", false),
+            ("digraph { x -> { { a } } }", false),
+        ] {
+            let events: Vec<_> = Events::with_options(input.as_bytes(), options).collect();
+            assert_eq!(events.iter().all(|event| event.is_ok()), ok, "{}", input);
+        }
+    }
+
+    #[test]
+    fn events_invalid_token_test() {
+        // Lexing errors don't make the whole rest of the input get buffered
+        let mut input = String::from("digraph {\n a $ b\n");
+        for i in 0..100_000 {
+            input.push_str(&format!(" n{}\n", i));
+        }
+        input.push('}');
+        let mut reader = std::io::Cursor::new(input.as_bytes());
+        let events: Vec<_> = Events::new(&mut reader).collect();
+        assert!(events.last().unwrap().is_err());
+        assert!((reader.position() as usize) < 4 * DEFAULT_LOOKAHEAD);
+
+        // Which is as far as the lookahead of the reader goes
+        let mut reader = std::io::Cursor::new(input.as_bytes());
+        let events: Vec<_> = Events::with_lookahead(&mut reader, 64).collect();
+        assert!(events.last().unwrap().is_err());
+        assert!((reader.position() as usize) < 4 * super::BLOCK_SIZE);
+
+        // While a comment may still span lines
+        let input = "digraph {\n a /* b\n c */ d\n}";
+        assert!(Events::new(input.as_bytes()).all(|event| event.is_ok()));
+    }
+
+    #[test]
+    fn events_separators_test() {
+        let input = "digraph { a\n, b; c, d }";
+        assert_eq!(
+            describe(Events::new(input.as_bytes())),
+            vec![
+                "graph ",
+                "node a",
+                "node b",
+                "node c",
+                "node d",
+                "end graph"
+            ]
+        );
+        let options = ParseOptions {
+            conformance: Conformance::Strict,
+            ..ParseOptions::default()
+        };
+        for input in ["digraph { a\n, b }", "digraph { a, b }"] {
+            assert!(DotGraph::parse_with_options(input, options).is_err());
+            let error = Events::with_options(input.as_bytes(), options)
+                .find_map(|event| event.err())
+                .unwrap();
+            assert_eq!(error.to_string(), COMMA_SEPARATOR, "{}", input);
+        }
+    }
+
+    #[test]
+    fn events_long_lines_test() {
+        // Input on a single line is read a block at a time
+        let mut input = String::from("digraph { ");
+        for i in 0..100_000 {
+            input.push_str(&format!("n{}; ", i));
+        }
+        input.push('}');
+        let mut reader = std::io::Cursor::new(input.as_bytes());
+        let mut events = Events::new(&mut reader);
+        assert!(matches!(events.next(), Some(Ok(Event::GraphStart { .. }))));
+        assert!(matches!(events.next(), Some(Ok(Event::NodeStmt(_)))));
+        drop(events);
+        assert!((reader.position() as usize) <= super::BLOCK_SIZE);
+
+        // Characters split across blocks are put back together
+        let input = format!(
+            "digraph {{ {} \"é\" }}",
+            "a ".repeat(super::BLOCK_SIZE / 2 - 5)
+        );
+        let events: Vec<_> = Events::new(input.as_bytes())
+            .collect::<anyhow::Result<_>>()
+            .unwrap();
+        assert!(matches!(&events[events.len() - 2], Event::NodeStmt(node) if node.id == "é"));
+        let events: Vec<_> = Events::new(&b"digraph { \xff }"[..]).collect();
+        assert!(events.last().unwrap().is_err());
+    }
+
+    #[test]
+    fn events_samples_match_ast_test() {
+        for file in [
+            "basic1",
+            "basic2",
+            "basic3",
+            "basic4",
+            "datastruct",
+            "identifiers",
+        ] {
+            let input = std::fs::read_to_string(format!("samples/{}.dot", file)).unwrap();
+            let graph = DotGraph::from_str(&input).unwrap();
+            let events: Vec<_> = Events::new(input.as_bytes())
+                .collect::<anyhow::Result<_>>()
+                .unwrap();
+            let mut depth = 0;
+            let mut statements = 0;
+            for event in events {
+                match event {
+                    Event::SubgraphStart { .. } => {
+                        if depth == 0 {
+                            statements += 1;
+                        }
+                        depth += 1;
+                    }
+                    Event::SubgraphEnd => depth -= 1,
+                    Event::GraphStart { .. } | Event::GraphEnd => {}
+                    _ if depth == 0 => statements += 1,
+                    _ => {}
+                }
+            }
+            assert_eq!(statements, graph.statements().len(), "{}", file);
+        }
+    }
+
+    #[test]
+    fn events_constant_memory_test() {
+        let mut input = String::from("digraph {\n");
+        for i in 0..10000 {
+            input.push_str(&format!("  n{} -> n{} [weight = {}]\n", i, i + 1, i));
+        }
+        input.push_str("}\n");
+        let mut events = Events::new(input.as_bytes());
+        let mut count = 0;
+        while let Some(event) = events.next() {
+            event.unwrap();
+            count += 1;
+            assert!(events.buffer.len() < super::BLOCK_SIZE + 256);
+        }
+        assert_eq!(count, 10002);
+    }
+}
@@ -0,0 +1,365 @@
+//! Owned transformation of the AST
+//!
+//! Where [`VisitMut`](crate::visit_mut::VisitMut) edits a tree in place,
+//! [`Fold`] takes every node by value and returns its replacement, which makes
+//! it possible to change the shape of the tree rather than only its contents.
+//! In particular, [`Fold::fold_statement`] returns a list of statements: an
+//! empty list deletes the statement, and several statements can be spliced in
+//! where there used to be one.
+//!
+//! All methods default to rebuilding the node from its folded children via the
+//! free function of the same name in this module, so passes only need to
+//! override the nodes they change, and can be chained by folding the output of
+//! one pass with the next.
+//!
+//! For example, the following removes all `node [...]`, `edge [...]` and
+//! `graph [...]` statements from a graph:
+//!
+//! ```
+//! use graphviz_parser::DotGraph;
+//! use graphviz_parser::ast_nodes::{Directed, Statement};
+//! use graphviz_parser::fold::{self, Fold};
+//! use std::str::FromStr;
+//!
+//! struct DropAttributes;
+//!
+//! impl Fold<Directed> for DropAttributes {
+//!     fn fold_statement(&mut self, statement: Statement<Directed>) -> Vec<Statement<Directed>> {
+//!         match statement {
+//!             Statement::Attribute(_) => vec![],
+//!             statement => fold::fold_statement(self, statement),
+//!         }
+//!     }
+//! }
+//!
+//! let graph_str = "digraph G { node [shape = box]\n a -> b }";
+//! if let DotGraph::Directed(graph) = DotGraph::from_str(graph_str).unwrap() {
+//!     let graph = DropAttributes.fold_graph(*graph);
+//!     assert_eq!(graph.statements.len(), 1);
+//! }
+//! ```
+
+use crate::ast_nodes::{
+    Assignment, AttributeList, AttributeStatement, Edge, EdgeLHS, EdgeRHS, ErrorStatement, Graph,
+    Node, Port, Statement, Subgraph,
+};
+
+/// A transformation from an owned AST into a new one, parameterized by the
+/// direction `T` of the graph
+pub trait Fold<T> {
+    fn fold_graph(&mut self, graph: Graph<T>) -> Graph<T> {
+        fold_graph(self, graph)
+    }
+
+    fn fold_statements(&mut self, statements: Vec<Statement<T>>) -> Vec<Statement<T>> {
+        fold_statements(self, statements)
+    }
+
+    /// Folds a single statement into any number of statements that take its place
+    fn fold_statement(&mut self, statement: Statement<T>) -> Vec<Statement<T>> {
+        fold_statement(self, statement)
+    }
+
+    fn fold_node(&mut self, node: Node) -> Node {
+        fold_node(self, node)
+    }
+
+    fn fold_port(&mut self, port: Port) -> Port {
+        port
+    }
+
+    fn fold_edge(&mut self, edge: Edge<T>) -> Edge<T> {
+        fold_edge(self, edge)
+    }
+
+    fn fold_edge_lhs(&mut self, lhs: EdgeLHS<T>) -> EdgeLHS<T> {
+        fold_edge_lhs(self, lhs)
+    }
+
+    fn fold_edge_rhs(&mut self, rhs: EdgeRHS<T>) -> EdgeRHS<T> {
+        fold_edge_rhs(self, rhs)
+    }
+
+    fn fold_subgraph(&mut self, subgraph: Subgraph<T>) -> Subgraph<T> {
+        fold_subgraph(self, subgraph)
+    }
+
+    fn fold_attribute_statement(&mut self, attribute: AttributeStatement) -> AttributeStatement {
+        fold_attribute_statement(self, attribute)
+    }
+
+    fn fold_attribute_list(&mut self, attribute_list: AttributeList) -> AttributeList {
+        fold_attribute_list(self, attribute_list)
+    }
+
+    fn fold_assignment(&mut self, assignment: Assignment) -> Assignment {
+        assignment
+    }
+
+    fn fold_error(&mut self, error: ErrorStatement) -> ErrorStatement {
+        error
+    }
+}
+
+pub fn fold_graph<T, F>(f: &mut F, mut graph: Graph<T>) -> Graph<T>
+where
+    F: Fold<T> + ?Sized,
+{
+    let statements = std::mem::take(&mut graph.statements);
+    Graph::new(
+        std::mem::take(&mut graph.id),
+        graph.is_strict,
+        f.fold_statements(statements),
+    )
+}
+
+pub fn fold_statements<T, F>(f: &mut F, statements: Vec<Statement<T>>) -> Vec<Statement<T>>
+where
+    F: Fold<T> + ?Sized,
+{
+    statements
+        .into_iter()
+        .flat_map(|statement| f.fold_statement(statement))
+        .collect()
+}
+
+pub fn fold_statement<T, F>(f: &mut F, statement: Statement<T>) -> Vec<Statement<T>>
+where
+    F: Fold<T> + ?Sized,
+{
+    let folded = match statement {
+        Statement::Node(node) => Statement::Node(Box::new(f.fold_node(*node))),
+        Statement::Edge(edge) => Statement::Edge(Box::new(f.fold_edge(*edge))),
+        Statement::Attribute(attribute) => {
+            Statement::Attribute(Box::new(f.fold_attribute_statement(*attribute)))
+        }
+        Statement::Assignment(assignment) => {
+            Statement::Assignment(Box::new(f.fold_assignment(*assignment)))
+        }
+        Statement::Subgraph(subgraph) => Statement::Subgraph(Box::new(f.fold_subgraph(*subgraph))),
+        Statement::Error(error) => Statement::Error(Box::new(f.fold_error(*error))),
+    };
+    vec![folded]
+}
+
+pub fn fold_node<T, F>(f: &mut F, node: Node) -> Node
+where
+    F: Fold<T> + ?Sized,
+{
+    Node {
+        id: node.id,
+        port: node.port.map(|port| f.fold_port(port)),
+        attribute_list: node
+            .attribute_list
+            .map(|attribute_list| f.fold_attribute_list(attribute_list)),
+    }
+}
+
+/// Folds an edge chain in a loop, in the same order as [`visit::visit_edge`](crate::visit::visit_edge)
+///
+/// Every edge of the chain is handed to `fold_edge_rhs` together with the rest
+/// of the chain, which is then folded here. Returning an endpoint rather than
+/// an edge from `fold_edge_rhs` ends the chain there.
+pub fn fold_edge<T, F>(f: &mut F, edge: Edge<T>) -> Edge<T>
+where
+    F: Fold<T> + ?Sized,
+{
+    let Edge {
+        lhs,
+        rhs,
+        attr_list,
+        ..
+    } = edge;
+    let lhs = f.fold_edge_lhs(lhs);
+    let mut links = vec![];
+    let mut rhs = f.fold_edge_rhs(*rhs);
+    while let EdgeRHS::Edge(mut link) = rhs {
+        let rest = std::mem::replace(&mut *link.rhs, EdgeRHS::Node(Node::empty()));
+        links.push(link);
+        rhs = f.fold_edge_rhs(rest);
+    }
+    let attr_list = f.fold_attribute_list(attr_list);
+    for link in &mut links {
+        link.attr_list = f.fold_attribute_list(std::mem::take(&mut link.attr_list));
+    }
+    while let Some(mut link) = links.pop() {
+        *link.rhs = rhs;
+        rhs = EdgeRHS::Edge(link);
+    }
+    Edge::new(lhs, rhs, attr_list)
+}
+
+pub fn fold_edge_lhs<T, F>(f: &mut F, lhs: EdgeLHS<T>) -> EdgeLHS<T>
+where
+    F: Fold<T> + ?Sized,
+{
+    match lhs {
+        EdgeLHS::Node(node) => EdgeLHS::Node(f.fold_node(node)),
+        EdgeLHS::Subgraph(subgraph) => EdgeLHS::Subgraph(f.fold_subgraph(subgraph)),
+    }
+}
+
+/// Folds the endpoint directly following an edge operator
+///
+/// For an [`EdgeRHS::Edge`] that is the first endpoint of the rest of the
+/// chain, which is folded by [`fold_edge`] itself.
+pub fn fold_edge_rhs<T, F>(f: &mut F, rhs: EdgeRHS<T>) -> EdgeRHS<T>
+where
+    F: Fold<T> + ?Sized,
+{
+    match rhs {
+        EdgeRHS::Edge(mut edge) => {
+            edge.lhs = f.fold_edge_lhs(edge.lhs);
+            EdgeRHS::Edge(edge)
+        }
+        EdgeRHS::Node(node) => EdgeRHS::Node(f.fold_node(node)),
+        EdgeRHS::Subgraph(subgraph) => EdgeRHS::Subgraph(f.fold_subgraph(subgraph)),
+    }
+}
+
+pub fn fold_subgraph<T, F>(f: &mut F, mut subgraph: Subgraph<T>) -> Subgraph<T>
+where
+    F: Fold<T> + ?Sized,
+{
+    let statements = std::mem::take(&mut subgraph.statements);
+    Subgraph {
+        id: subgraph.id.take(),
+        statements: f.fold_statements(statements),
+    }
+}
+
+pub fn fold_attribute_statement<T, F>(
+    f: &mut F,
+    attribute: AttributeStatement,
+) -> AttributeStatement
+where
+    F: Fold<T> + ?Sized,
+{
+    match attribute {
+        AttributeStatement::Graph(attribute_list) => {
+            AttributeStatement::Graph(f.fold_attribute_list(attribute_list))
+        }
+        AttributeStatement::Node(attribute_list) => {
+            AttributeStatement::Node(f.fold_attribute_list(attribute_list))
+        }
+        AttributeStatement::Edge(attribute_list) => {
+            AttributeStatement::Edge(f.fold_attribute_list(attribute_list))
+        }
+    }
+}
+
+pub fn fold_attribute_list<T, F>(f: &mut F, attribute_list: AttributeList) -> AttributeList
+where
+    F: Fold<T> + ?Sized,
+{
+    attribute_list
+        .into_iter()
+        .map(|group| {
+            group
+                .into_iter()
+                .map(|assignment| f.fold_assignment(assignment))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fold;
+    use crate::ast_nodes::{Directed, EdgeLHS, EdgeRHS, Graph, Node, Statement};
+    use crate::lex::PeekableLexer;
+    use crate::parse::Constructable;
+
+    fn parse(s: &str) -> Graph<Directed> {
+        Graph::<Directed>::from_lexer(PeekableLexer::from(s))
+            .unwrap()
+            .0
+    }
+
+    struct InlineAnonymous;
+
+    impl Fold<Directed> for InlineAnonymous {
+        fn fold_statement(&mut self, statement: Statement<Directed>) -> Vec<Statement<Directed>> {
+            match statement {
+                Statement::Subgraph(mut subgraph) if subgraph.id.is_none() => {
+                    self.fold_statements(std::mem::take(&mut subgraph.statements))
+                }
+                statement => super::fold_statement(self, statement),
+            }
+        }
+    }
+
+    struct Rename;
+
+    impl Fold<Directed> for Rename {
+        fn fold_node(&mut self, node: Node) -> Node {
+            let node = super::fold_node(self, node);
+            Node {
+                id: node.id.to_uppercase(),
+                ..node
+            }
+        }
+    }
+
+    /// Cuts every edge chain after its first edge
+    struct FirstEdge;
+
+    impl Fold<Directed> for FirstEdge {
+        fn fold_edge_rhs(&mut self, rhs: EdgeRHS<Directed>) -> EdgeRHS<Directed> {
+            match rhs {
+                EdgeRHS::Edge(edge) => edge.lhs.into(),
+                rhs => super::fold_edge_rhs(self, rhs),
+            }
+        }
+    }
+
+    #[test]
+    fn fold_edge_chain_test() {
+        let g = FirstEdge.fold_graph(parse("digraph G { a -> b -> c -> d [color = red] }"));
+        if let Statement::Edge(edge) = &g.statements[0] {
+            assert!(matches!(&*edge.rhs, EdgeRHS::Node(n) if n.id == "b"));
+            assert_eq!(edge.attr_list[0][0].lhs, "color");
+        } else {
+            unreachable!()
+        }
+
+        let g = Rename.fold_graph(parse("digraph G { a -> b -> c }"));
+        if let Statement::Edge(edge) = &g.statements[0] {
+            let next = match &*edge.rhs {
+                EdgeRHS::Edge(next) => next,
+                _ => unreachable!(),
+            };
+            assert!(matches!(&next.lhs, EdgeLHS::Node(n) if n.id == "B"));
+            assert!(matches!(&*next.rhs, EdgeRHS::Node(n) if n.id == "C"));
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn fold_inline_anonymous_subgraphs_test() {
+        let g = parse("digraph G { a\n { b { c } }\n subgraph s { d } }");
+        let g = InlineAnonymous.fold_graph(g);
+        assert_eq!(g.id, "G");
+        assert_eq!(g.statements.len(), 4);
+        assert!(matches!(&g.statements[2], Statement::Node(n) if n.id == "c"));
+        assert!(matches!(&g.statements[3], Statement::Subgraph(s) if s.statements.len() == 1));
+    }
+
+    #[test]
+    fn fold_rename_nodes_test() {
+        let g = Rename.fold_graph(parse("digraph G { a -> b }"));
+        if let Statement::Edge(edge) = &g.statements[0] {
+            assert!(matches!(&edge.lhs, EdgeLHS::Node(n) if n.id == "A"));
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn fold_composed_passes_test() {
+        let g = parse("digraph G { { a } }");
+        let g = Rename.fold_graph(InlineAnonymous.fold_graph(g));
+        assert!(matches!(&g.statements[0], Statement::Node(n) if n.id == "A"));
+    }
+}
@@ -6,22 +6,25 @@ use logos::Logos;
 /// the graphviz language spec here: https://graphviz.org/doc/info/lang.html
 #[derive(Logos, Debug, PartialEq, Clone)]
 pub(crate) enum Token<'a> {
-    #[regex(r##"([a-zA-Z0-9_]+|-?(\.[0-9]+|[0-9]+(\.[0-9]*)?))"##)]
+    #[regex(r##"([a-zA-Z0-9_\u{80}-\u{10FFFF}]+|-?(\.[0-9]+|[0-9]+(\.[0-9]*)?))"##)]
     #[regex(r##""([^"]|\\")*""##)]
+    // HTML strings keep their angle brackets, which tells them apart from other IDs
+    #[token("<", html_string)]
     ID(&'a str),
 
-    #[token("strict")]
+    // Keywords are case-independent
+    #[token("strict", ignore(ascii_case))]
     Strict,
 
-    #[token("subgraph")]
+    #[token("subgraph", ignore(ascii_case))]
     Subgraph,
-    #[token("graph")]
+    #[token("graph", ignore(ascii_case))]
     Graph,
-    #[token("digraph")]
+    #[token("digraph", ignore(ascii_case))]
     Digraph,
-    #[token("node")]
+    #[token("node", ignore(ascii_case))]
     Node,
-    #[token("edge")]
+    #[token("edge", ignore(ascii_case))]
     Edge,
     #[token("->")]
     DirectedEdge,
@@ -65,6 +68,34 @@ pub(crate) enum Token<'a> {
 }
 use logos::Span;
 
+/// Extends a `<` to the end of the HTML string it starts, i.e. up to the
+/// matching `>`
+fn html_string<'a>(lex: &mut logos::Lexer<'a, Token<'a>>) -> Option<&'a str> {
+    let mut depth = 1usize;
+    for (index, ch) in lex.remainder().char_indices() {
+        match ch {
+            '<' => depth += 1,
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    lex.bump(index + 1);
+                    return Some(lex.slice());
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns true if `token` is the slice of an ID lexed by [`html_string`]
+pub(crate) fn is_html_string(token: &str) -> bool {
+    token.starts_with('<')
+}
+
+use crate::diagnostic::Warnings;
+use crate::parse::ParseOptions;
+
 // Used by other parts of code to convert "s\\tstring" to s\tstring
 pub(crate) fn unquote_string(str: &str) -> String {
     let mut iterator = str.chars();
@@ -90,7 +121,7 @@ pub(crate) fn unquote_string(str: &str) -> String {
         // Don't process the first or the last characters
         match iterator.next() {
             Some(mut prev_char) => {
-                while let Some(cur_char) = iterator.next() {
+                for cur_char in iterator {
                     process(prev_char);
                     prev_char = cur_char;
                 }
@@ -124,6 +155,8 @@ pub(crate) struct PeekableLexer<'a> {
     peeked_token: Option<Token<'a>>,
     curr_span: Span,
     curr_slice: &'a str,
+    pub(crate) options: ParseOptions,
+    pub(crate) warnings: Warnings,
 }
 
 impl<'a> std::fmt::Debug for PeekableLexer<'a> {
@@ -132,7 +165,7 @@ impl<'a> std::fmt::Debug for PeekableLexer<'a> {
         while v.next().is_some() {
             write!(f, "{} ", v.slice())?;
         }
-        write!(f, "\n")
+        writeln!(f)
     }
 }
 
@@ -173,7 +206,7 @@ impl<'a> Peekable<'a> for PeekableLexer<'a> {
     }
 
     fn slice(&self) -> &'a str {
-        self.curr_slice.as_ref()
+        self.curr_slice
     }
 }
 
@@ -184,6 +217,13 @@ impl<'a> PeekableLexer<'a> {
         Self::from_lexer(inner_lexer)
     }
 
+    /// Creates a new lexer from a raw string, for parsing with the given options
+    pub fn with_options(ref_str: &'a str, options: ParseOptions) -> Self {
+        let mut lexer = Self::from(ref_str);
+        lexer.options = options;
+        lexer
+    }
+
     /// Constructs a new instance of the PeekableLexer
     /// from an existing underlying lexer
     fn from_lexer(inner_lexer: logos::Lexer<'a, Token<'a>>) -> Self {
@@ -194,6 +234,8 @@ impl<'a> PeekableLexer<'a> {
             peeked_token: None,
             curr_span,
             curr_slice,
+            options: ParseOptions::default(),
+            warnings: Warnings::default(),
         }
     }
 
@@ -204,6 +246,88 @@ impl<'a> PeekableLexer<'a> {
         }
     }
 
+    /// Accepts a deviation from the language spec with a warning, or rejects
+    /// it when the spec is to be followed strictly
+    pub(crate) fn deviation(&mut self, span: Span, message: String) -> anyhow::Result<()> {
+        self.options.deviation(&mut self.warnings, span, message)
+    }
+
+    /// The whole input this lexer was created from
+    pub(crate) fn source(&self) -> &'a str {
+        self.inner_lexer.source()
+    }
+
+    /// Skips over a malformed statement, up to the next `;` or newline
+    /// outside of braces and brackets, or the `}` closing the current block,
+    /// and returns the span of what was skipped
+    ///
+    /// At least one token is skipped, so that parsing makes progress.
+    pub(crate) fn skip_statement(&mut self) -> Span {
+        self.next();
+        let start = self.span().start;
+        let mut end = self.span().end;
+        let mut braces = 0usize;
+        let mut brackets = 0usize;
+        loop {
+            match self.peek() {
+                None => break,
+                Some(Token::NewLine) | Some(Token::SemiColon) if braces == 0 && brackets == 0 => {
+                    break
+                }
+                Some(Token::CloseParen) if braces == 0 => break,
+                Some(Token::OpenParen) => braces += 1,
+                Some(Token::CloseParen) => braces -= 1,
+                Some(Token::OpenBracket) => brackets += 1,
+                Some(Token::CloseBracket) => brackets = brackets.saturating_sub(1),
+                Some(_) => {}
+            }
+            self.next();
+            end = self.span().end;
+        }
+        start..end
+    }
+
+    /// How deeply blocks are nested from here to the end of the current block
+    pub(crate) fn nesting_depth(&self) -> usize {
+        let mut lexer = self.clone();
+        let mut depth = 0usize;
+        let mut max_depth = 0;
+        for token in lexer.by_ref() {
+            match token {
+                Token::OpenParen => {
+                    depth += 1;
+                    max_depth = max_depth.max(depth);
+                }
+                Token::CloseParen if depth == 0 => break,
+                Token::CloseParen => depth -= 1,
+                _ => {}
+            }
+        }
+        max_depth
+    }
+
+    /// Skips past the `}` closing the block the lexer is currently in,
+    /// along with anything left unparsed inside of it
+    ///
+    /// Returns false if the input ends before the block is closed. This is only
+    /// for recovering from errors, so callers report what was skipped.
+    pub(crate) fn skip_past_block_end(&mut self) -> bool {
+        let mut depth = 1usize;
+        for token in self.by_ref() {
+            match token {
+                Token::OpenParen => depth += 1,
+                Token::CloseParen => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return true;
+                    }
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+
     fn update_splice(&mut self) {
         self.curr_span = self.inner_lexer.span();
         self.curr_slice = self.inner_lexer.slice();
@@ -250,6 +374,19 @@ mod tests {
         assert_eq!(Some(Token::ID(test_str)), lxt.next());
     }
 
+    #[test]
+    fn lexer_spec_ids_and_keywords_test() {
+        let test_str = "DiGraph NODE Straße <<b>bold</b> <i>x</i>> -> <a";
+        let mut lxt = PeekableLexer::from(test_str);
+        assert_eq!(lxt.next(), Some(Token::Digraph));
+        assert_eq!(lxt.next(), Some(Token::Node));
+        assert_eq!(lxt.next(), Some(Token::ID("Straße")));
+        assert_eq!(lxt.next(), Some(Token::ID("<<b>bold</b> <i>x</i>>")));
+        assert_eq!(lxt.next(), Some(Token::DirectedEdge));
+        // An HTML string that isn't closed
+        assert_eq!(lxt.next(), Some(Token::Error));
+    }
+
     #[test]
     fn lexer_peek_index_1_test() {
         let solution = vec!["big", "kahuna", "electric", "boogaloo"];
@@ -258,8 +395,7 @@ mod tests {
             .map(|x| String::from(*x) + " ")
             .collect::<Vec<String>>()
             .iter()
-            .map(|x| x.chars())
-            .flatten()
+            .flat_map(|x| x.chars())
             .collect();
 
         let mut lexer_to_test = PeekableLexer::from(&test_text);
@@ -315,7 +451,7 @@ mod tests {
     #[test]
     fn lexer_slice_indexing_1_test() {
         let solution = vec!["big ", "kahuna ", "electric ", "boogaloo "];
-        let test_text: String = solution.iter().map(|x| x.chars()).flatten().collect();
+        let test_text: String = solution.iter().flat_map(|x| x.chars()).collect();
         let mut lexer_to_test = PeekableLexer::from(&test_text);
 
         for sol in solution {
@@ -328,7 +464,7 @@ mod tests {
     #[test]
     fn lexer_slice_indexing_2_test() {
         let solution = vec!["big ", "kahuna ", "electric ", "boogaloo "];
-        let test_text: String = solution.iter().map(|x| x.chars()).flatten().collect();
+        let test_text: String = solution.iter().flat_map(|x| x.chars()).collect();
         let mut lexer_to_test = PeekableLexer::from(&test_text);
 
         for sol in solution {
@@ -345,4 +481,19 @@ mod tests {
         assert_eq!(unquote_string(r#""\\a\"""#), r#"\a""#.to_string());
         assert_eq!(unquote_string(r#""\\a\"a""#), r#"\a"a"#.to_string());
     }
+
+    #[test]
+    fn lexer_skip_past_block_end_test() {
+        let mut lexer_sut = PeekableLexer::from("a { b } -> } c");
+        assert!(lexer_sut.skip_past_block_end());
+        assert_eq!(lexer_sut.next(), Some(Token::ID("c")));
+        assert!(!lexer_sut.skip_past_block_end());
+    }
+
+    #[test]
+    fn lexer_nesting_depth_test() {
+        let lexer_sut = PeekableLexer::from("a { b { c } { d } } } { { { e } } }");
+        assert_eq!(lexer_sut.nesting_depth(), 2);
+        assert_eq!(PeekableLexer::from("a b").nesting_depth(), 0);
+    }
 }
@@ -26,59 +26,552 @@
 //!  }
 //!  ```
 
-use ast_nodes::{Directed, Undirected};
-use lex::PeekableLexer;
-use parse::{Constructable, ParseOR};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::Context;
+use ast_nodes::{Directed, Statement, Undirected, ID};
+use diagnostic::Diagnostic;
+use lex::{Peekable, PeekableLexer, Token};
+use parse::Constructable;
+use visit::Visit;
 pub mod ast_nodes;
+pub mod attributes;
+pub mod diagnostic;
+pub mod events;
+pub mod fold;
+pub mod lint;
+pub mod semantic;
+pub mod visit;
+pub mod visit_mut;
 
 mod lex;
 mod parse;
+mod reader;
+
+pub use parse::{Conformance, ParseOptions};
+pub use reader::Graphs;
 
 /// DotGraph is the toplevel graph construct we parse into.
 ///
 /// Dotgraph can either be a directed graph, or an undirected graph,
 /// depending on the string input it is provided
 ///
-/// **Note:** at the time of writing, this does not handle
-/// strict support.
+/// Whether the graph is strict is only recorded here; the rules strict
+/// graphs follow are applied when [resolving](DotGraph::resolve) it.
+#[derive(Debug)]
 pub enum DotGraph {
     Undirected(Box<ast_nodes::Graph<Undirected>>),
     Directed(Box<ast_nodes::Graph<Directed>>),
 }
 
+impl DotGraph {
+    /// The name of the graph, which is empty for anonymous graphs
+    pub fn id(&self) -> &ID {
+        match self {
+            Self::Undirected(graph) => &graph.id,
+            Self::Directed(graph) => &graph.id,
+        }
+    }
+
+    /// Returns true if the graph was declared as `strict`
+    pub fn is_strict(&self) -> bool {
+        match self {
+            Self::Undirected(graph) => graph.is_strict,
+            Self::Directed(graph) => graph.is_strict,
+        }
+    }
+
+    /// Returns true if this is a `digraph`
+    pub fn is_directed(&self) -> bool {
+        matches!(self, Self::Directed(_))
+    }
+
+    /// Parses the first graph of `s` with the given options, along with
+    /// [diagnostics](DotGraph::diagnostics) about the statements that couldn't be parsed,
+    /// and warnings about the deviations from the spec that were accepted
+    ///
+    /// A malformed statement is an error, unless [`ParseOptions::recover`] is set,
+    /// in which case this returns as much of the graph as could be parsed.
+    /// The diagnostics are ordered by where they are in `s`.
+    pub fn parse_with_options(
+        s: &str,
+        options: ParseOptions,
+    ) -> anyhow::Result<(Self, Vec<Diagnostic>)> {
+        let (graph, token_stream) = DotGraph::from_lexer(PeekableLexer::with_options(s, options))?;
+        let mut diagnostics = graph.diagnostics();
+        diagnostics.extend(token_stream.warnings.to_vec());
+        diagnostics.sort_by_key(|diagnostic| diagnostic.span.start);
+        Ok((graph, diagnostics))
+    }
+
+    /// An error for every [`Error`](Statement::Error) statement of the graph
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        match self {
+            Self::Undirected(graph) => diagnostic::collect(graph),
+            Self::Directed(graph) => diagnostic::collect(graph),
+        }
+    }
+
+    /// Problems with the attributes of the graph, see [`lint::lint`]
+    pub fn lint(&self) -> Vec<Diagnostic> {
+        match self {
+            Self::Undirected(graph) => lint::lint(graph),
+            Self::Directed(graph) => lint::lint(graph),
+        }
+    }
+
+    /// Parses the first graph read from `reader`
+    ///
+    /// Since the parsed graph owns all of its data, the input is only held in
+    /// memory while it is being parsed.
+    pub fn from_reader(mut reader: impl Read) -> anyhow::Result<Self> {
+        let mut source = String::new();
+        reader
+            .read_to_string(&mut source)
+            .context("Error; couldn't read input")?;
+        Self::from_str(&source)
+    }
+
+    /// Parses the first graph of the file at `path`
+    ///
+    /// Errors mention the path of the file.
+    ///
+    /// ```
+    /// use graphviz_parser::DotGraph;
+    ///
+    /// let graph = DotGraph::from_path("samples/basic1.dot").unwrap();
+    /// assert!(graph.is_directed());
+    /// ```
+    pub fn from_path(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let mut file =
+            File::open(path).with_context(|| format!("Error; couldn't open {}", path.display()))?;
+        let size = file.metadata().map(|m| m.len() as usize).unwrap_or(0);
+        let mut source = String::with_capacity(size);
+        file.read_to_string(&mut source)
+            .with_context(|| format!("Error; couldn't read {}", path.display()))?;
+        Self::from_str(&source).with_context(|| format!("Error; couldn't parse {}", path.display()))
+    }
+
+    /// The toplevel statements of the graph
+    ///
+    /// ```
+    /// use graphviz_parser::DotGraph;
+    /// use std::str::FromStr;
+    ///
+    /// let graph = DotGraph::from_str("digraph G { a; b -> c }").unwrap();
+    /// assert_eq!(graph.statements().len(), 2);
+    /// ```
+    pub fn statements(&self) -> Statements<'_> {
+        match self {
+            Self::Undirected(graph) => Statements::Undirected(&graph.statements),
+            Self::Directed(graph) => Statements::Directed(&graph.statements),
+        }
+    }
+
+    /// Walks the graph with a visitor that handles both directions, see [`Statements::visit`]
+    pub fn visit<'a, V>(&'a self, visitor: &mut V)
+    where
+        V: Visit<'a, Directed> + Visit<'a, Undirected> + ?Sized,
+    {
+        match self {
+            Self::Undirected(graph) => visitor.visit_graph(graph),
+            Self::Directed(graph) => visitor.visit_graph(graph),
+        }
+    }
+}
+
+/// The statements of a [`DotGraph`], borrowed with their direction
+#[derive(Debug, Clone, Copy)]
+pub enum Statements<'a> {
+    Undirected(&'a [Statement<Undirected>]),
+    Directed(&'a [Statement<Directed>]),
+}
+
+impl<'a> Statements<'a> {
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Undirected(statements) => statements.len(),
+            Self::Directed(statements) => statements.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Walks every statement with a visitor, whatever the direction of the graph
+    ///
+    /// A visitor implemented for any direction can walk the statements
+    /// without matching on the variants:
+    ///
+    /// ```
+    /// use graphviz_parser::DotGraph;
+    /// use graphviz_parser::ast_nodes::Node;
+    /// use graphviz_parser::visit::{self, Visit};
+    /// use std::str::FromStr;
+    ///
+    /// struct NodeIds<'ast>(Vec<&'ast str>);
+    ///
+    /// impl<'ast, T> Visit<'ast, T> for NodeIds<'ast> {
+    ///     fn visit_node(&mut self, node: &'ast Node) {
+    ///         self.0.push(&node.id);
+    ///         visit::visit_node::<T, _>(self, node);
+    ///     }
+    /// }
+    ///
+    /// for source in ["digraph { a -> b }", "graph { a -- b }"] {
+    ///     let graph = DotGraph::from_str(source).unwrap();
+    ///     let mut ids = NodeIds(vec![]);
+    ///     graph.statements().visit(&mut ids);
+    ///     assert_eq!(ids.0, vec!["a", "b"]);
+    /// }
+    /// ```
+    pub fn visit<V>(&self, visitor: &mut V)
+    where
+        V: Visit<'a, Directed> + Visit<'a, Undirected> + ?Sized,
+    {
+        match *self {
+            Self::Undirected(statements) => {
+                for statement in statements {
+                    visitor.visit_statement(statement);
+                }
+            }
+            Self::Directed(statements) => {
+                for statement in statements {
+                    visitor.visit_statement(statement);
+                }
+            }
+        }
+    }
+}
+
+impl Constructable for DotGraph {
+    type Output = Self;
+    fn from_lexer(
+        mut token_stream: PeekableLexer,
+    ) -> anyhow::Result<(Self::Output, PeekableLexer), anyhow::Error> {
+        // Skip over empty lines and line comments before the graph
+        token_stream.clear_filler();
+        // Look past `strict` to tell which kind of graph this is, so that the
+        // errors of parsing it are reported as they are
+        let mut lookahead = token_stream.clone();
+        if lookahead.peek() == Some(&Token::Strict) {
+            lookahead.next();
+        }
+        match lookahead.peek() {
+            Some(Token::Digraph) => {
+                let (graph, token_stream) = ast_nodes::Graph::<Directed>::from_lexer(token_stream)?;
+                Ok((Self::Directed(Box::new(graph)), token_stream))
+            }
+            Some(Token::Graph) => {
+                let (graph, token_stream) =
+                    ast_nodes::Graph::<Undirected>::from_lexer(token_stream)?;
+                Ok((Self::Undirected(Box::new(graph)), token_stream))
+            }
+            _ => Err(anyhow::anyhow!(
+                "Error; couldn't parse as either directed or undirected graph"
+            )),
+        }
+    }
+}
+
 impl std::str::FromStr for DotGraph {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let peekable_lexer = PeekableLexer::from(s);
-        let dir_or_undir =
-            ParseOR::<ast_nodes::Graph<Directed>, ast_nodes::Graph<Undirected>>::from_lexer(
-                peekable_lexer,
-            )?
-            .0;
-        match dir_or_undir {
-            ParseOR {
-                t_val: None,
-                v_val: Some(undirect),
-            } => Ok(Self::Undirected(Box::new(undirect))),
-            ParseOR {
-                t_val: Some(direct),
-                v_val: None,
-            } => Ok(Self::Directed(Box::new(direct))),
-            _ => Err(anyhow::anyhow!(
-                "Error; couldn't parse as either directed or undirected graph"
-            )),
+        Ok(DotGraph::from_lexer(peekable_lexer)?.0)
+    }
+}
+
+/// Parses all graphs of an input containing several graphs one after another,
+/// as emitted by tools like `ccomps`
+///
+/// [`DotGraph::from_str`](std::str::FromStr::from_str) only parses the first
+/// graph of such an input. To parse graphs from a reader as they come in,
+/// see [`Graphs`].
+///
+/// ```
+/// let graphs = graphviz_parser::parse_all("digraph A { a -> b }\ngraph B { c -- d }").unwrap();
+/// assert_eq!(graphs.len(), 2);
+/// assert_eq!(graphs[1].id(), "B");
+/// ```
+pub fn parse_all(s: &str) -> anyhow::Result<Vec<DotGraph>> {
+    let mut token_stream = PeekableLexer::from(s);
+    let mut graphs = vec![];
+    loop {
+        token_stream.clear_filler();
+        if token_stream.peek().is_none() {
+            return Ok(graphs);
         }
+        let (graph, tstream) = DotGraph::from_lexer(token_stream)
+            .map_err(|e| e.context(format!("Error; couldn't parse graph {}", graphs.len() + 1)))?;
+        graphs.push(graph);
+        token_stream = tstream;
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::DotGraph;
+    use super::{parse_all, Conformance, DotGraph, ParseOptions};
+    use crate::ast_nodes::{mismatched_operator, Assignment, Directed, ReverseEdges, Undirected};
+    use crate::diagnostic::Severity;
+    use crate::fold::Fold;
+    use crate::visit_mut::VisitMut;
     use std::str::FromStr;
 
+    #[test]
+    fn lib_from_reader_test() {
+        let graph = DotGraph::from_reader("graph G { a -- b }".as_bytes()).unwrap();
+        assert_eq!(graph.id(), "G");
+        assert!(DotGraph::from_reader("graph G {".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn lib_from_path_test() {
+        let graph = DotGraph::from_path("samples/basic2.dot").unwrap();
+        let expected =
+            DotGraph::from_str(&std::fs::read_to_string("samples/basic2.dot").unwrap()).unwrap();
+        assert_eq!(graph.id(), expected.id());
+        assert_eq!(graph.statements().len(), expected.statements().len());
+
+        let error = DotGraph::from_path("samples/missing.dot").unwrap_err();
+        assert!(format!("{:#}", error).contains("samples/missing.dot"));
+        let error = DotGraph::from_path("Cargo.toml").unwrap_err();
+        assert!(format!("{:#}", error).contains("Cargo.toml"));
+    }
+
     #[test]
     fn lib_api_sanity_test() {
         let test_str = "graph G { A -> { B, D} }";
         let _ = DotGraph::from_str(test_str).unwrap();
     }
+
+    #[test]
+    fn lib_direction_erased_accessors_test() {
+        let graph = DotGraph::from_str("strict digraph G { a -> b; c }").unwrap();
+        assert_eq!(graph.id(), "G");
+        assert!(graph.is_strict());
+        assert!(graph.is_directed());
+        assert_eq!(graph.statements().len(), 2);
+
+        let graph = DotGraph::from_str("graph H { a -- b }").unwrap();
+        assert_eq!(graph.id(), "H");
+        assert!(!graph.is_strict());
+        assert!(!graph.is_directed());
+        assert!(!graph.statements().is_empty());
+    }
+
+    #[test]
+    fn lib_direction_erased_visit_test() {
+        use crate::ast_nodes::Edge;
+        use crate::visit::{self, Visit};
+
+        #[derive(Default)]
+        struct Counts {
+            edges: usize,
+            errors: usize,
+        }
+
+        impl<'ast, T> Visit<'ast, T> for Counts {
+            fn visit_edge(&mut self, edge: &'ast Edge<T>) {
+                self.edges += 1;
+                visit::visit_edge(self, edge);
+            }
+
+            fn visit_error(&mut self, _error: &'ast crate::ast_nodes::ErrorStatement) {
+                self.errors += 1;
+            }
+        }
+
+        let options = ParseOptions {
+            recover: true,
+            ..ParseOptions::default()
+        };
+        for source in [
+            "digraph { a -> b; ]; { c -> d } e }",
+            "graph { a -- b; ]; { c -- d } e }",
+        ] {
+            let (graph, _) = DotGraph::parse_with_options(source, options).unwrap();
+            let mut counts = Counts::default();
+            graph.visit(&mut counts);
+            assert_eq!((counts.edges, counts.errors), (2, 1), "{}", source);
+
+            let mut counts = Counts::default();
+            graph.statements().visit(&mut counts);
+            assert_eq!((counts.edges, counts.errors), (2, 1), "{}", source);
+        }
+    }
+
+    #[test]
+    fn lib_parse_all_test() {
+        let graphs = parse_all(
+            "/* components */\ndigraph A { a -> b }\n\nstrict graph B { c -- d; { e } }; digraph C {}\n",
+        )
+        .unwrap();
+        let ids: Vec<_> = graphs.iter().map(|g| g.id().as_str()).collect();
+        assert_eq!(ids, vec!["A", "B", "C"]);
+        assert!(graphs[1].is_strict());
+        assert!(!graphs[1].is_directed());
+
+        assert!(parse_all("").unwrap().is_empty());
+        assert!(parse_all("digraph A { a } digraph B { b").is_err());
+    }
+
+    #[test]
+    fn lib_from_str_only_parses_first_graph_test() {
+        let graph = DotGraph::from_str("// first\ndigraph A { a } digraph B { b }").unwrap();
+        assert_eq!(graph.id(), "A");
+    }
+
+    #[test]
+    fn lib_spec_inputs_test() {
+        let graph =
+            DotGraph::from_str("Strict Graph { Node [label = <<b>a</b>>]; x -- y }").unwrap();
+        assert!(graph.is_strict());
+        assert!(!graph.is_directed());
+        assert_eq!(graph.id(), "");
+        assert_eq!(graph.statements().len(), 2);
+        let resolved = graph.resolve();
+        let labels: Vec<_> = resolved
+            .nodes()
+            .iter()
+            .map(|n| n.attributes["label"].as_str())
+            .collect();
+        assert_eq!(labels, vec!["<<b>a</b>>", "<<b>a</b>>"]);
+    }
+
+    #[test]
+    fn lib_conformance_test() {
+        let source = "graph G { a -> b, c\n d -- e }";
+        let (graph, diagnostics) =
+            DotGraph::parse_with_options(source, ParseOptions::default()).unwrap();
+        assert_eq!(graph.statements().len(), 3);
+        assert!(diagnostics.iter().all(|d| d.severity == Severity::Warning));
+        let spans: Vec<_> = diagnostics
+            .iter()
+            .map(|d| &source[d.span.clone()])
+            .collect();
+        assert_eq!(spans, vec!["->", ","]);
+        // Leftovers aren't a deviation, but an error in either mode
+        let source = "graph G { a -> b, c\n d -- e ] }";
+        assert!(DotGraph::parse_with_options(source, ParseOptions::default()).is_err());
+
+        let strict = ParseOptions {
+            conformance: Conformance::Strict,
+            ..ParseOptions::default()
+        };
+        assert!(DotGraph::parse_with_options(source, strict).is_err());
+        assert!(DotGraph::parse_with_options("graph G { a; b }", strict).is_ok());
+
+        let options = ParseOptions {
+            recover: true,
+            ..strict
+        };
+        let (graph, diagnostics) =
+            DotGraph::parse_with_options("graph G { a, b }", options).unwrap();
+        assert_eq!(graph.statements().len(), 3);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+
+        let source = "digraph G { a -- b; c -> d }";
+        let (graph, diagnostics) = DotGraph::parse_with_options(source, options).unwrap();
+        assert_eq!(graph.statements().len(), 2);
+        assert_eq!(&source[diagnostics[0].span.clone()], "a -- b");
+        assert_eq!(diagnostics[0].message, mismatched_operator::<Directed>());
+    }
+
+    #[test]
+    fn lib_mismatched_edge_operator_test() {
+        let source = "graph G { A -> B }";
+        let graph = DotGraph::from_str(source).unwrap();
+        assert_eq!(graph.statements().len(), 1);
+
+        let (_, diagnostics) =
+            DotGraph::parse_with_options(source, ParseOptions::default()).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(&source[diagnostics[0].span.clone()], "->");
+        assert_eq!(diagnostics[0].message, mismatched_operator::<Undirected>());
+
+        let strict = ParseOptions {
+            conformance: Conformance::Strict,
+            ..ParseOptions::default()
+        };
+        let error = DotGraph::parse_with_options(source, strict).unwrap_err();
+        assert_eq!(error.to_string(), mismatched_operator::<Undirected>());
+    }
+
+    #[test]
+    fn lib_nesting_limit_test() {
+        let nested = |depth: usize| {
+            format!(
+                "digraph {{ {}a{} }}",
+                "{ ".repeat(depth - 1),
+                " }".repeat(depth - 1)
+            )
+        };
+        // Deep nesting within the limit parses in linear time
+        let graph = DotGraph::from_str(&nested(128)).unwrap();
+        assert_eq!(graph.statements().len(), 1);
+
+        let error = DotGraph::from_str(&nested(129)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Error; blocks are nested more than 128 levels deep"
+        );
+        let error = DotGraph::from_str(&"graph G { {".repeat(100_000)).unwrap_err();
+        assert!(error.to_string().contains("nested"));
+
+        let options = ParseOptions {
+            max_depth: 2,
+            ..ParseOptions::default()
+        };
+        assert!(DotGraph::parse_with_options("digraph { { a } }", options).is_ok());
+        assert!(DotGraph::parse_with_options("digraph { { { a } } }", options).is_err());
+    }
+
+    /// Turns red into blue when folding, and blue into green when visiting
+    struct Recolor;
+
+    impl Fold<Undirected> for Recolor {
+        fn fold_assignment(&mut self, assignment: Assignment) -> Assignment {
+            Assignment::new(&assignment.lhs, "blue")
+        }
+    }
+
+    impl VisitMut<Undirected> for Recolor {
+        fn visit_assignment_mut(&mut self, assignment: &mut Assignment) {
+            assignment.rhs = String::from("green");
+        }
+    }
+
+    #[test]
+    fn lib_long_edge_chain_test() {
+        let chain: Vec<_> = (0..100_000).map(|i| format!("n{}", i)).collect();
+        let graph_str = format!("strict digraph {{ {} [color = red] }}", chain.join(" -> "));
+        let graph = DotGraph::from_str(&graph_str).unwrap().resolve();
+        assert_eq!(graph.nodes().len(), 100_000);
+        assert_eq!(graph.edges().len(), 99_999);
+        assert_eq!(graph.edges()[99_998].attributes["color"], "red");
+
+        let (graph, diagnostics) =
+            DotGraph::parse_with_options(&graph_str, ParseOptions::default()).unwrap();
+        assert!(diagnostics.is_empty());
+        assert!(graph.diagnostics().is_empty());
+        assert!(graph.lint().is_empty());
+
+        let graph = match graph {
+            DotGraph::Directed(graph) => graph.into_undirected(ReverseEdges::Drop),
+            DotGraph::Undirected(_) => unreachable!(),
+        };
+        let mut graph = Recolor.fold_graph(graph);
+        Recolor.visit_graph_mut(&mut graph);
+        let graph = DotGraph::Undirected(Box::new(graph)).resolve();
+        assert_eq!(graph.edges().len(), 99_999);
+        assert_eq!(graph.edges()[0].attributes["color"], "green");
+    }
 }
@@ -0,0 +1,451 @@
+//! Checks the attributes of a graph against the [attribute schema](crate::attributes)
+//!
+//! The lint reports attributes graphviz doesn't know about, attributes set on
+//! components they don't apply to, values that aren't valid for their
+//! attribute, and edges attached to ports their node doesn't have:
+//!
+//! ```
+//! use graphviz_parser::DotGraph;
+//! use std::str::FromStr;
+//!
+//! let source = "digraph { a [colour = red, arrowhead = dot, shape = boxx] }";
+//! let diagnostics = DotGraph::from_str(source).unwrap().lint();
+//! let messages: Vec<_> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+//! assert_eq!(
+//!     messages,
+//!     vec![
+//!         "Unknown attribute `colour`, did you mean `color`?",
+//!         "`arrowhead` doesn't apply to nodes",
+//!         "Invalid value `boxx` for `shape`, expected a node shape",
+//!     ]
+//! );
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use crate::ast_nodes::{
+    Assignment, AttributeList, AttributeStatement, Edge, EdgeLHS, EdgeRHS, Graph, GraphDirection,
+    Node, Subgraph, ID,
+};
+use crate::attributes::{self, Attribute, Components, Shape, Style};
+use crate::diagnostic::Diagnostic;
+use crate::visit::{self, Visit};
+
+/// Lints the attributes of every statement of `graph`, and the ports of its
+/// edges
+///
+/// Unknown attributes, attributes on the wrong kind of component and unknown
+/// ports are warnings, since graphviz ignores them. Invalid values, including
+/// record labels that don't parse, are errors.
+pub fn lint<T: GraphDirection>(graph: &Graph<T>) -> Vec<Diagnostic> {
+    let mut ports = HashMap::new();
+    let mut invalid_labels = HashMap::new();
+    for node in graph.resolve().nodes() {
+        match node.ports() {
+            Ok(node_ports) => {
+                ports.insert(node.id.clone(), node_ports);
+            }
+            Err(error) => {
+                // Invalid shapes are reported as invalid values already
+                let is_record = node
+                    .attr::<Shape>()
+                    .is_ok_and(|shape| shape.is_some_and(|shape| shape.is_record()));
+                if is_record {
+                    let label = node.attributes.get("label").cloned().unwrap_or_default();
+                    let message = error.root_cause().to_string();
+                    invalid_labels.insert(node.id.clone(), (label, message));
+                }
+            }
+        }
+    }
+    let mut linter = Linter {
+        depth: 0,
+        ports,
+        invalid_labels,
+        reported_labels: HashSet::new(),
+        diagnostics: vec![],
+    };
+    linter.visit_graph(graph);
+    linter.diagnostics
+}
+
+struct Linter {
+    /// How many subgraphs deep the statements being linted are
+    depth: usize,
+    /// The ports the label of each node declares
+    ports: HashMap<ID, Vec<String>>,
+    /// The record labels that don't parse, and why, by the id of their node
+    invalid_labels: HashMap<ID, (ID, String)>,
+    /// The spans of the label assignments reported as invalid record labels,
+    /// since a single `node [label = ...]` can apply to many nodes
+    reported_labels: HashSet<Range<usize>>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// The name of a set of components, as used in messages
+fn component_name(components: Components) -> &'static str {
+    if components == Components::NODE {
+        "nodes"
+    } else if components == Components::EDGE {
+        "edges"
+    } else if components.contains(Components::SUBGRAPH) {
+        "subgraphs"
+    } else {
+        "graphs"
+    }
+}
+
+/// The number of single character edits it takes to turn `a` into `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// The known attribute closest to `name`, if any is close enough to be a typo
+fn suggestion(name: &str) -> Option<&'static str> {
+    attributes::ATTRIBUTES
+        .iter()
+        .map(|spec| {
+            (
+                edit_distance(&name.to_lowercase(), &spec.name.to_lowercase()),
+                spec.name,
+            )
+        })
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, name)| name)
+}
+
+impl Linter {
+    /// The components graph attributes apply to at the current depth
+    ///
+    /// Graph attributes are inherited by the clusters inside of a graph, and
+    /// whether a subgraph is a cluster can depend on its attributes, so these
+    /// are accepted anywhere graph attributes are.
+    fn graph_components(&self) -> Components {
+        if self.depth == 0 {
+            Components::GRAPH | Components::CLUSTER
+        } else {
+            Components::GRAPH | Components::SUBGRAPH | Components::CLUSTER
+        }
+    }
+
+    fn check(&mut self, assignment: &Assignment, components: Components) {
+        let span = assignment.span.clone();
+        let spec = match attributes::lookup(&assignment.lhs) {
+            Some(spec) => spec,
+            None => {
+                let message = match suggestion(&assignment.lhs) {
+                    Some(name) => format!(
+                        "Unknown attribute `{}`, did you mean `{}`?",
+                        assignment.lhs, name
+                    ),
+                    None => format!("Unknown attribute `{}`", assignment.lhs),
+                };
+                self.diagnostics.push(Diagnostic::warning(span, message));
+                return;
+            }
+        };
+        if !spec.components.intersects(components) {
+            let message = format!(
+                "`{}` doesn't apply to {}",
+                spec.name,
+                component_name(components)
+            );
+            self.diagnostics.push(Diagnostic::warning(span, message));
+        } else if !spec.types.iter().any(|ty| ty.accepts(&assignment.rhs)) {
+            let message = format!(
+                "Invalid value `{}` for `{}`, expected {}",
+                assignment.rhs,
+                spec.name,
+                spec.types[0].description()
+            );
+            self.diagnostics.push(Diagnostic::error(span, message));
+        } else if spec.name == Style::NAME {
+            // Valid styles parse, so only their components are left to check
+            let style = Style::from_value(&assignment.rhs).expect("The style was accepted");
+            for name in style.names() {
+                if !name.components().intersects(components) {
+                    let message = format!(
+                        "`{}` style doesn't apply to {}",
+                        name,
+                        component_name(components)
+                    );
+                    self.diagnostics
+                        .push(Diagnostic::warning(span.clone(), message));
+                }
+            }
+        }
+    }
+
+    fn check_port(&mut self, node: &Node) {
+        let (port, id) = match &node.port {
+            Some(port) => match &port.id {
+                Some(id) => (port, id),
+                None => return,
+            },
+            None => return,
+        };
+        let ports = match self.ports.get(&node.id) {
+            Some(ports) if !ports.contains(id) => ports,
+            _ => return,
+        };
+        let message = if ports.is_empty() {
+            format!("Node `{}` has no ports, so `{}` doesn't exist", node.id, id)
+        } else {
+            let names: Vec<_> = ports.iter().map(|port| format!("`{}`", port)).collect();
+            format!(
+                "Node `{}` has no port `{}`, its ports are {}",
+                node.id,
+                id,
+                names.join(", ")
+            )
+        };
+        self.diagnostics
+            .push(Diagnostic::warning(port.span.clone(), message));
+    }
+
+    /// Reports the `label` assignments of `attribute_list` that set the
+    /// invalid record label of the node `id`, or of any node for defaults
+    fn check_labels(&mut self, attribute_list: &AttributeList, id: Option<&ID>) {
+        for assignment in attribute_list.iter().flatten() {
+            if assignment.lhs != "label" || self.reported_labels.contains(&assignment.span) {
+                continue;
+            }
+            let invalid = self
+                .invalid_labels
+                .iter()
+                .filter(|(node, _)| match id {
+                    Some(id) => id == *node,
+                    None => true,
+                })
+                .find_map(|(_, (label, message))| (*label == assignment.rhs).then_some(message));
+            if let Some(message) = invalid {
+                let diagnostic = Diagnostic::error(assignment.span.clone(), message.clone());
+                self.diagnostics.push(diagnostic);
+                self.reported_labels.insert(assignment.span.clone());
+            }
+        }
+    }
+
+    fn check_list(&mut self, attribute_list: &AttributeList, components: Components) {
+        for assignment in attribute_list.iter().flatten() {
+            self.check(assignment, components);
+        }
+    }
+}
+
+impl<'ast, T> Visit<'ast, T> for Linter {
+    fn visit_node(&mut self, node: &'ast Node) {
+        if let Some(attribute_list) = &node.attribute_list {
+            self.check_list(attribute_list, Components::NODE);
+            self.check_labels(attribute_list, Some(&node.id));
+        }
+    }
+
+    fn visit_edge(&mut self, edge: &'ast Edge<T>) {
+        // The attributes of a chain are only kept on its outermost edge
+        self.check_list(&edge.attr_list, Components::EDGE);
+        visit::visit_edge(self, edge);
+    }
+
+    fn visit_edge_lhs(&mut self, lhs: &'ast EdgeLHS<T>) {
+        if let EdgeLHS::Node(node) = lhs {
+            self.check_port(node);
+        }
+        visit::visit_edge_lhs(self, lhs);
+    }
+
+    fn visit_edge_rhs(&mut self, rhs: &'ast EdgeRHS<T>) {
+        if let EdgeRHS::Node(node) = rhs {
+            self.check_port(node);
+        }
+        visit::visit_edge_rhs(self, rhs);
+    }
+
+    fn visit_subgraph(&mut self, subgraph: &'ast Subgraph<T>) {
+        self.depth += 1;
+        visit::visit_subgraph(self, subgraph);
+        self.depth -= 1;
+    }
+
+    fn visit_attribute_statement(&mut self, attribute: &'ast AttributeStatement) {
+        match attribute {
+            AttributeStatement::Graph(attribute_list) => {
+                self.check_list(attribute_list, self.graph_components())
+            }
+            AttributeStatement::Node(attribute_list) => {
+                self.check_list(attribute_list, Components::NODE);
+                self.check_labels(attribute_list, None);
+            }
+            AttributeStatement::Edge(attribute_list) => {
+                self.check_list(attribute_list, Components::EDGE)
+            }
+        }
+    }
+
+    // Attribute lists are checked where it's known what they apply to
+    fn visit_attribute_list(&mut self, _attribute_list: &'ast AttributeList) {}
+
+    // Only reached for `a = b` statements, which set graph attributes
+    fn visit_assignment(&mut self, assignment: &'ast Assignment) {
+        self.check(assignment, self.graph_components());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{edit_distance, lint};
+    use crate::ast_nodes::{Directed, Graph};
+    use crate::diagnostic::Severity;
+    use crate::lex::PeekableLexer;
+    use crate::parse::Constructable;
+
+    fn lint_str(s: &str) -> Vec<(String, Severity, String)> {
+        let graph = Graph::<Directed>::from_lexer(PeekableLexer::from(s))
+            .unwrap()
+            .0;
+        lint(&graph)
+            .into_iter()
+            .map(|d| (s[d.span.clone()].to_string(), d.severity, d.message))
+            .collect()
+    }
+
+    #[test]
+    fn lint_edit_distance_test() {
+        assert_eq!(edit_distance("colour", "color"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn lint_components_test() {
+        let diagnostics = lint_str(
+            "digraph {
+                rankdir = LR; color = blue; rank = same
+                node [fontsize = \"10.5.1\", penwidth = 2]
+                edge [shape = box, style = \"tapered,bold\"]
+                node [style = \"filled,tapered\", arrowsize = 2]
+                a -> b -> c [arrowhead = dot, weight = heavy]
+                subgraph s { rank = same; label = \"S\" }
+                xyzzy = 1
+            }",
+        );
+        assert_eq!(
+            diagnostics,
+            vec![
+                (
+                    "rank = same".to_string(),
+                    Severity::Warning,
+                    "`rank` doesn't apply to graphs".to_string()
+                ),
+                (
+                    "fontsize = \"10.5.1\"".to_string(),
+                    Severity::Error,
+                    "Invalid value `10.5.1` for `fontsize`, expected a double".to_string()
+                ),
+                (
+                    "shape = box".to_string(),
+                    Severity::Warning,
+                    "`shape` doesn't apply to edges".to_string()
+                ),
+                (
+                    "style = \"filled,tapered\"".to_string(),
+                    Severity::Warning,
+                    "`tapered` style doesn't apply to nodes".to_string()
+                ),
+                (
+                    "arrowsize = 2".to_string(),
+                    Severity::Warning,
+                    "`arrowsize` doesn't apply to nodes".to_string()
+                ),
+                (
+                    "weight = heavy".to_string(),
+                    Severity::Error,
+                    "Invalid value `heavy` for `weight`, expected an integer".to_string()
+                ),
+                (
+                    "xyzzy = 1".to_string(),
+                    Severity::Warning,
+                    "Unknown attribute `xyzzy`".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn lint_ports_test() {
+        let diagnostics = lint_str(
+            "digraph {
+                node [shape = record]
+                a [label = \"<f0> a|{<f1> b|c}\"]
+                b [label = <<TABLE><TR><TD PORT=\"p\">p</TD></TR></TABLE>>]
+                c [shape = box]
+                d [label = \"{a|b\"]
+                a:f1 -> b:p:s -> a:f2:n
+                c:n -> c:west
+                a:ne -> d:f0
+            }",
+        );
+        assert_eq!(
+            diagnostics,
+            vec![
+                (
+                    "label = \"{a|b\"".to_string(),
+                    Severity::Error,
+                    "Unbalanced braces in record label {a|b".to_string()
+                ),
+                (
+                    ":f2:n".to_string(),
+                    Severity::Warning,
+                    "Node `a` has no port `f2`, its ports are `f0`, `f1`".to_string()
+                ),
+                (
+                    ":west".to_string(),
+                    Severity::Warning,
+                    "Node `c` has no ports, so `west` doesn't exist".to_string()
+                ),
+            ]
+        );
+
+        let diagnostics =
+            lint_str("digraph { node [shape=record]; d [label=\"{a|b\"]; d:f0 -> e }");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].0, "label=\"{a|b\"");
+        assert_eq!(diagnostics[0].1, Severity::Error);
+
+        // A default label is reported once, however many nodes it applies to
+        let diagnostics =
+            lint_str("digraph { node [shape=record, label=\"{x\"]; a; b; c [shape=box] }");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].0, "label=\"{x\"");
+    }
+
+    #[test]
+    fn lint_samples_test() {
+        for file in [
+            "basic1",
+            "basic2",
+            "basic3",
+            "basic4",
+            "datastruct",
+            "identifiers",
+        ] {
+            let input = std::fs::read_to_string(format!("samples/{}.dot", file)).unwrap();
+            let graph = Graph::<Directed>::from_lexer(PeekableLexer::from(input.as_str()))
+                .unwrap()
+                .0;
+            assert_eq!(lint(&graph), vec![], "{}", file);
+        }
+    }
+}
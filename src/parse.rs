@@ -1,5 +1,87 @@
 use super::lex::PeekableLexer;
+use crate::diagnostic::{Deviation, Diagnostic, Warnings};
 use anyhow::Result;
+use std::ops::Range;
+
+/// Options controlling how graphs are parsed
+///
+/// ```
+/// use graphviz_parser::{DotGraph, ParseOptions};
+///
+/// let options = ParseOptions {
+///     recover: true,
+///     ..ParseOptions::default()
+/// };
+/// let (graph, diagnostics) = DotGraph::parse_with_options("digraph { a -> ; b }", options).unwrap();
+/// assert_eq!(graph.statements().len(), 2);
+/// assert_eq!(diagnostics.len(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Instead of stopping at the first malformed statement, skip over it and
+    /// keep parsing, recording it as an [`Error`](crate::ast_nodes::Statement::Error) statement
+    pub recover: bool,
+    /// How deeply blocks may be nested, counting the braces of the graph itself
+    ///
+    /// Parsing nested subgraphs recurses, so this keeps hostile input from
+    /// overflowing the stack. Deeper input is rejected before it is parsed.
+    pub max_depth: usize,
+    /// How closely the input has to follow the [language spec](https://graphviz.org/doc/info/lang.html)
+    pub conformance: Conformance,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            recover: false,
+            max_depth: 128,
+            conformance: Conformance::Lenient,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Accepts a deviation from the language spec with a warning, or rejects
+    /// it when the spec is to be followed strictly
+    pub(crate) fn deviation(
+        &self,
+        warnings: &mut Warnings,
+        span: Range<usize>,
+        message: String,
+    ) -> Result<()> {
+        match self.conformance {
+            Conformance::Lenient => {
+                warnings.push(Diagnostic::warning(span, message));
+                Ok(())
+            }
+            Conformance::Strict => Err(Deviation(message).into()),
+        }
+    }
+}
+
+/// Whether to accept input that deviates from the language spec
+///
+/// ```
+/// use graphviz_parser::{Conformance, DotGraph, ParseOptions};
+///
+/// let source = "graph G { a -> b }";
+/// let (_, diagnostics) = DotGraph::parse_with_options(source, ParseOptions::default()).unwrap();
+/// assert!(diagnostics[0].message.starts_with("`->` used in an undirected graph"));
+///
+/// let options = ParseOptions {
+///     conformance: Conformance::Strict,
+///     ..ParseOptions::default()
+/// };
+/// assert!(DotGraph::parse_with_options(source, options).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Conformance {
+    /// Accept the deviations common in real-world files, with a
+    /// [warning](crate::diagnostic::Severity::Warning) about each of them
+    Lenient,
+    /// Reject anything the spec doesn't allow
+    Strict,
+}
 
 pub(crate) trait Constructable: Sized {
     type Output;
@@ -8,11 +90,17 @@ pub(crate) trait Constructable: Sized {
     ) -> Result<(Self::Output, PeekableLexer), anyhow::Error>;
 }
 
+/// Parses either `T` or `V`, trying `T` first
+///
+/// The graph parser now peeks at the graph keyword instead, so this is only
+/// kept for its tests.
+#[cfg(test)]
 pub(crate) struct ParseOR<T: Constructable, V: Constructable> {
     pub t_val: Option<T::Output>,
     pub v_val: Option<V::Output>,
 }
 
+#[cfg(test)]
 impl<T, V> Constructable for ParseOR<T, V>
 where
     T: Constructable,
@@ -84,6 +172,16 @@ mod tests {
         assert_eq!(result.t_val.unwrap(), Assignment::new("color", "green"));
     }
 
+    #[test]
+    fn or_op_second_arm_test() {
+        let pb = PeekableLexer::from("A [color = green]");
+        let result: ParseOR<Assignment, Node> = ParseOR::from_lexer(pb).unwrap().0;
+        assert!(result.t_val.is_none());
+        assert_eq!(result.v_val.unwrap().id, "A");
+        let pb = PeekableLexer::from("[");
+        assert!(ParseOR::<Assignment, Node>::from_lexer(pb).is_err());
+    }
+
     #[test]
     fn test_ast_build_sanity1() {
         let test_str = "digraph G { subgraph t1 { A [color = green] } }";
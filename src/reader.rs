@@ -0,0 +1,210 @@
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::DotGraph;
+
+/// Tracks where graphs start and end in a stream of dot source, without parsing it
+///
+/// A graph ends at the `}` matching its first `{`. Braces inside of quoted
+/// strings, HTML strings and comments are ignored.
+#[derive(Debug, Default)]
+struct Splitter {
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    /// How many `<` of an HTML string are still open
+    html_depth: usize,
+    in_line_comment: bool,
+    in_block_comment: bool,
+    /// Set once anything other than whitespace and comments has been seen
+    has_content: bool,
+}
+
+impl Splitter {
+    /// Scans `text`, returning the index just past the end of the graph if it ends in it
+    fn scan(&mut self, text: &str) -> Option<usize> {
+        let mut chars = text.char_indices().peekable();
+        while let Some((index, ch)) = chars.next() {
+            if self.in_line_comment {
+                self.in_line_comment = ch != '\n';
+            } else if self.in_block_comment {
+                if ch == '*' && matches!(chars.peek(), Some((_, '/'))) {
+                    chars.next();
+                    self.in_block_comment = false;
+                }
+            } else if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if ch == '\\' {
+                    self.escaped = true;
+                } else if ch == '"' {
+                    self.in_string = false;
+                }
+            } else if self.html_depth > 0 {
+                // Like the lexer, only the angle brackets matter in HTML strings
+                match ch {
+                    '<' => self.html_depth += 1,
+                    '>' => self.html_depth -= 1,
+                    _ => {}
+                }
+            } else if ch == '/' && matches!(chars.peek(), Some((_, '/'))) {
+                self.in_line_comment = true;
+            } else if ch == '/' && matches!(chars.peek(), Some((_, '*'))) {
+                chars.next();
+                self.in_block_comment = true;
+            } else if !ch.is_whitespace() {
+                self.has_content = true;
+                match ch {
+                    '"' => self.in_string = true,
+                    '<' => self.html_depth = 1,
+                    '{' => self.depth += 1,
+                    '}' if self.depth > 0 => {
+                        self.depth -= 1;
+                        if self.depth == 0 {
+                            return Some(index + 1);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        None
+    }
+}
+
+/// An iterator over the graphs of a reader containing several graphs one after another
+///
+/// The input is read line by line, and each graph is parsed as soon as its
+/// closing `}` has been read, so graphs can be processed while the rest of
+/// the input is still coming in.
+///
+/// ```
+/// use graphviz_parser::Graphs;
+///
+/// let input = "digraph A { a -> b }\ngraph B {\n  c -- d\n}\n";
+/// let ids: Vec<_> = Graphs::new(input.as_bytes())
+///     .map(|graph| graph.unwrap().id().clone())
+///     .collect();
+/// assert_eq!(ids, vec!["A", "B"]);
+/// ```
+pub struct Graphs<R: BufRead> {
+    reader: R,
+    /// Input read but not yet handed to the parser
+    buffer: String,
+    /// How much of the buffer has already been scanned
+    scanned: usize,
+    splitter: Splitter,
+    done: bool,
+}
+
+impl<R: BufRead> Graphs<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: String::new(),
+            scanned: 0,
+            splitter: Splitter::default(),
+            done: false,
+        }
+    }
+
+    /// Parses the first `end` bytes of the buffer as a graph, and drops them from the buffer
+    fn take_graph(&mut self, end: usize) -> anyhow::Result<DotGraph> {
+        let source: String = self.buffer.drain(..end).collect();
+        self.scanned = 0;
+        self.splitter = Splitter::default();
+        DotGraph::from_str(&source)
+    }
+}
+
+impl<R: BufRead> Iterator for Graphs<R> {
+    type Item = anyhow::Result<DotGraph>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        loop {
+            if let Some(end) = self.splitter.scan(&self.buffer[self.scanned..]) {
+                let end = self.scanned + end;
+                return Some(self.take_graph(end));
+            }
+            self.scanned = self.buffer.len();
+
+            match self.reader.read_line(&mut self.buffer) {
+                Ok(0) => {
+                    self.done = true;
+                    return if self.splitter.depth > 0 {
+                        Some(Err(anyhow::anyhow!(
+                            "Error; input ended before the end of the graph"
+                        )))
+                    } else if self.splitter.has_content {
+                        // Let the parser report whatever is left over
+                        let end = self.buffer.len();
+                        Some(self.take_graph(end))
+                    } else {
+                        None
+                    };
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(
+                        anyhow::Error::new(e).context("Error; couldn't read input")
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Graphs;
+
+    #[test]
+    fn reader_multiple_graphs_test() {
+        let input = "/* a { */ digraph A { a -> b } graph B {\n c [label = \"}\"]\n // }\n d }\n\ndigraph C { e }";
+        let graphs: Vec<_> = Graphs::new(input.as_bytes())
+            .collect::<anyhow::Result<_>>()
+            .unwrap();
+        let ids: Vec<_> = graphs.iter().map(|g| g.id().as_str()).collect();
+        assert_eq!(ids, vec!["A", "B", "C"]);
+        assert_eq!(graphs[1].statements().len(), 2);
+    }
+
+    #[test]
+    fn reader_html_strings_test() {
+        let input = "digraph A { a [label=<x}y>] }\ndigraph B { b [label=<<b>{</b>>] }";
+        let graphs: Vec<_> = Graphs::new(input.as_bytes())
+            .collect::<anyhow::Result<_>>()
+            .unwrap();
+        let expected = crate::parse_all(input).unwrap();
+        assert_eq!(graphs.len(), expected.len());
+        for (graph, expected) in graphs.iter().zip(&expected) {
+            assert_eq!(graph.id(), expected.id());
+            assert_eq!(graph.statements().len(), 1);
+        }
+    }
+
+    #[test]
+    fn reader_empty_input_test() {
+        assert_eq!(Graphs::new("\n  // nothing\n".as_bytes()).count(), 0);
+    }
+
+    #[test]
+    fn reader_unterminated_graph_test() {
+        let mut graphs = Graphs::new("digraph A { a }\ndigraph B { b".as_bytes());
+        assert!(graphs.next().unwrap().is_ok());
+        assert!(graphs.next().unwrap().is_err());
+        assert!(graphs.next().is_none());
+    }
+
+    #[test]
+    fn reader_trailing_garbage_test() {
+        let mut graphs = Graphs::new("digraph A { a } nonsense".as_bytes());
+        assert!(graphs.next().unwrap().is_ok());
+        assert!(graphs.next().unwrap().is_err());
+        assert!(graphs.next().is_none());
+    }
+}
@@ -0,0 +1,270 @@
+use std::collections::HashSet;
+
+use super::{Attributes, SemanticGraph};
+use crate::ast_nodes::ID;
+use crate::attributes::validate::boolean;
+use crate::attributes::{self, Attribute};
+
+/// A cluster of a resolved graph
+///
+/// Clusters are subgraphs whose name starts with `cluster`, in any case, or
+/// which set the `cluster` attribute to a true boolean like `true` or `1`. Graphviz lays out the nodes of a cluster together, and
+/// draws a box around them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cluster {
+    pub id: ID,
+    /// The attributes of the cluster, including the ones it inherited from
+    /// the graphs it is nested in
+    pub attributes: Attributes,
+    /// All nodes inside of this cluster, including the ones inside of nested
+    /// subgraphs and clusters
+    pub nodes: Vec<ID>,
+    /// The index of the closest cluster this cluster is nested in
+    pub parent: Option<usize>,
+    /// The indices of the clusters directly nested in this one
+    pub children: Vec<usize>,
+}
+
+impl Cluster {
+    /// Reads the attribute `A` of the cluster, see [`attributes::get`]
+    pub fn attr<A: Attribute>(&self) -> anyhow::Result<Option<A>> {
+        attributes::get(&self.attributes)
+    }
+}
+
+/// What the resolver records about every subgraph it comes across
+///
+/// Subgraphs with the same name and parent are the same subgraph, so their
+/// records are merged.
+#[derive(Debug, Default)]
+pub(super) struct SubgraphRecord {
+    pub id: Option<ID>,
+    pub parent: Option<usize>,
+    pub attributes: Attributes,
+    pub nodes: Vec<ID>,
+}
+
+impl SubgraphRecord {
+    fn is_cluster(&self) -> bool {
+        let named_cluster = matches!(
+            self.id.as_ref().and_then(|id| id.get(..7)),
+            Some(prefix) if prefix.eq_ignore_ascii_case("cluster")
+        );
+        let marked_cluster = self
+            .attributes
+            .get("cluster")
+            .and_then(|value| boolean(value))
+            .unwrap_or(false);
+        named_cluster || marked_cluster
+    }
+}
+
+/// Builds the cluster hierarchy out of the subgraphs of a graph
+///
+/// Non-cluster subgraphs are skipped over, so a cluster nested in a plain
+/// subgraph of another cluster is still a child of that cluster.
+pub(super) fn build_clusters(records: &[SubgraphRecord]) -> Vec<Cluster> {
+    let mut cluster_of_record: Vec<Option<usize>> = vec![None; records.len()];
+    let mut clusters: Vec<Cluster> = vec![];
+    // Parents are always recorded before their children, so a single pass suffices
+    for (index, record) in records.iter().enumerate() {
+        if !record.is_cluster() {
+            continue;
+        }
+        let mut ancestor = record.parent;
+        let mut parent = None;
+        while let Some(ancestor_index) = ancestor {
+            if let Some(cluster) = cluster_of_record[ancestor_index] {
+                parent = Some(cluster);
+                break;
+            }
+            ancestor = records[ancestor_index].parent;
+        }
+
+        let cluster = clusters.len();
+        if let Some(parent) = parent {
+            clusters[parent].children.push(cluster);
+        }
+        clusters.push(Cluster {
+            id: record.id.clone().unwrap_or_default(),
+            attributes: record.attributes.clone(),
+            nodes: record.nodes.clone(),
+            parent,
+            children: vec![],
+        });
+        cluster_of_record[index] = Some(cluster);
+    }
+    clusters
+}
+
+impl SemanticGraph {
+    /// All clusters of the graph, in the order they were declared in
+    ///
+    /// ```
+    /// use graphviz_parser::DotGraph;
+    /// use std::str::FromStr;
+    ///
+    /// let graph_str = "digraph { subgraph cluster_a { label = A; a; subgraph cluster_b { b } } }";
+    /// let graph = DotGraph::from_str(graph_str).unwrap().resolve();
+    ///
+    /// let outer = &graph.clusters()[0];
+    /// assert_eq!(outer.attributes["label"], "A");
+    /// assert_eq!(outer.nodes, vec!["a", "b"]);
+    /// assert_eq!(graph.clusters()[outer.children[0]].id, "cluster_b");
+    /// ```
+    pub fn clusters(&self) -> &[Cluster] {
+        &self.clusters
+    }
+
+    /// The clusters that aren't nested in any other cluster
+    pub fn root_clusters(&self) -> impl Iterator<Item = &Cluster> {
+        self.clusters
+            .iter()
+            .filter(|cluster| cluster.parent.is_none())
+    }
+
+    /// Looks up a cluster by its name
+    pub fn cluster(&self, id: &str) -> Option<&Cluster> {
+        self.clusters.iter().find(|cluster| cluster.id == id)
+    }
+
+    /// The nodes of a cluster that aren't inside of any of its child clusters
+    pub fn own_nodes<'a>(&self, cluster: &'a Cluster) -> Vec<&'a ID> {
+        let nested: HashSet<&ID> = cluster
+            .children
+            .iter()
+            .flat_map(|child| self.clusters[*child].nodes.iter())
+            .collect();
+        cluster
+            .nodes
+            .iter()
+            .filter(|id| !nested.contains(id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast_nodes::{Directed, Graph};
+    use crate::lex::PeekableLexer;
+    use crate::parse::Constructable;
+    use crate::semantic::SemanticGraph;
+
+    fn resolve_file(f: &str) -> SemanticGraph {
+        let v = std::fs::read_to_string(f).unwrap();
+        Graph::<Directed>::from_lexer(PeekableLexer::from(&v))
+            .unwrap()
+            .0
+            .resolve()
+    }
+
+    fn resolve(s: &str) -> SemanticGraph {
+        Graph::<Directed>::from_lexer(PeekableLexer::from(s))
+            .unwrap()
+            .0
+            .resolve()
+    }
+
+    #[test]
+    fn cluster_hierarchy_basic4_test() {
+        let g = resolve_file("samples/basic4.dot");
+        let ids: Vec<_> = g.clusters().iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "cluster_p",
+                "cluster_c1",
+                "cluster_gc_1",
+                "cluster_gc_2",
+                "cluster_c2"
+            ]
+        );
+        let roots: Vec<_> = g.root_clusters().map(|c| c.id.as_str()).collect();
+        assert_eq!(roots, vec!["cluster_p"]);
+
+        let parent = g.cluster("cluster_p").unwrap();
+        assert_eq!(parent.nodes, vec!["a", "b", "c", "d", "e"]);
+        assert_eq!(parent.children, vec![1, 4]);
+        assert_eq!(parent.attributes["label"], "Parent");
+
+        let child = g.cluster("cluster_c1").unwrap();
+        assert_eq!(child.parent, Some(0));
+        assert_eq!(child.children, vec![2, 3]);
+        assert_eq!(child.attributes["label"], "Child one");
+        assert_eq!(g.own_nodes(child), vec!["a"]);
+
+        let grand_child = g.cluster("cluster_gc_2").unwrap();
+        assert_eq!(grand_child.parent, Some(1));
+        assert_eq!(grand_child.nodes, vec!["c", "d"]);
+    }
+
+    #[test]
+    fn cluster_through_plain_subgraph_test() {
+        let g = resolve(
+            "digraph { subgraph cluster_a { { rank = same; x; subgraph cluster_b { y } } z } }",
+        );
+        assert_eq!(g.clusters().len(), 2);
+        let outer = g.cluster("cluster_a").unwrap();
+        assert_eq!(outer.nodes, vec!["x", "y", "z"]);
+        assert_eq!(outer.children, vec![1]);
+        assert_eq!(g.clusters()[1].parent, Some(0));
+        assert_eq!(g.own_nodes(outer), vec!["x", "z"]);
+    }
+
+    #[test]
+    fn cluster_inherits_attributes_test() {
+        let g = resolve(
+            "digraph { color = blue\n subgraph cluster_a { style = filled\n subgraph cluster_b { color = red\n a } } }",
+        );
+        let outer = g.cluster("cluster_a").unwrap();
+        assert_eq!(outer.attributes["color"], "blue");
+        assert_eq!(outer.attributes["style"], "filled");
+        let inner = g.cluster("cluster_b").unwrap();
+        assert_eq!(inner.attributes["color"], "red");
+        assert_eq!(inner.attributes["style"], "filled");
+        assert_eq!(g.attributes()["color"], "blue");
+    }
+
+    #[test]
+    fn cluster_reopened_subgraph_test() {
+        let g = resolve(
+            "digraph { subgraph cluster_a { a; label = one } b\n subgraph cluster_a { c; color = red } }",
+        );
+        assert_eq!(g.clusters().len(), 1);
+        let cluster = &g.clusters()[0];
+        assert_eq!(cluster.nodes, vec!["a", "c"]);
+        assert_eq!(cluster.attributes["label"], "one");
+        assert_eq!(cluster.attributes["color"], "red");
+    }
+
+    #[test]
+    fn cluster_attribute_marks_cluster_test() {
+        let g = resolve("digraph { subgraph group { cluster = true; a } subgraph other { b } }");
+        assert_eq!(g.clusters().len(), 1);
+        assert_eq!(g.clusters()[0].id, "group");
+
+        let g = resolve(
+            "digraph {
+                subgraph a { cluster = yes } subgraph b { cluster = 1 }
+                subgraph c { cluster = 0 } subgraph d { cluster = no } subgraph e { cluster = maybe }
+            }",
+        );
+        let ids: Vec<_> = g.clusters().iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn cluster_name_prefix_test() {
+        let g = resolve(
+            "digraph { subgraph Cluster_x { a } subgraph CLUSTER { b } subgraph clus { c } subgraph \"é\" { d } }",
+        );
+        let ids: Vec<_> = g.clusters().iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["Cluster_x", "CLUSTER"]);
+    }
+
+    #[test]
+    fn cluster_edge_endpoint_members_test() {
+        let g = resolve("digraph { subgraph cluster_a { a -> { b c } } }");
+        assert_eq!(g.clusters()[0].nodes, vec!["a", "b", "c"]);
+    }
+}
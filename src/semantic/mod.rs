@@ -0,0 +1,172 @@
+//!
+//! The semantic module provides a resolved view of a graph: the set of nodes
+//! and edges it describes, each with the attributes that finally apply to it.
+//!
+//! Where the AST mirrors the syntax of a graphviz file, a [`SemanticGraph`]
+//! follows the rules graphviz uses to interpret it, as described
+//! [here](https://graphviz.org/doc/info/lang.html):
+//!
+//! - nodes are created the first time they are mentioned, including by edges
+//! - edges with subgraph endpoints connect every node inside of those
+//!   subgraphs, so `{a b} -> {c d}` describes four edges
+//! - `node [...]` and `edge [...]` statements set defaults for the nodes and
+//!   edges created after them, and only within the subgraph they appear in
+//! - `graph [...]` statements and `a=b` assignments set attributes of the graph
+//! - repeated node statements merge their attributes into the existing node
+//! - `strict` graphs have at most one edge between any two nodes, and edge
+//!   statements repeating an edge merge their attributes into it
+//! - subgraphs start out with the graph attributes of the graph they are
+//!   declared in, which matters for the attributes of [clusters](Cluster)
+//!
+//! ```
+//! use graphviz_parser::DotGraph;
+//! use std::str::FromStr;
+//!
+//! let graph_str = "digraph G { node [shape = box]\n a -> b\n a [color = red] }";
+//! let graph = DotGraph::from_str(graph_str).unwrap().resolve();
+//!
+//! assert_eq!(graph.nodes().len(), 2);
+//! let a = &graph.nodes()[0];
+//! assert_eq!(a.attributes["shape"], "box");
+//! assert_eq!(a.attributes["color"], "red");
+//! ```
+
+mod cluster;
+mod query;
+mod resolve;
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::ast_nodes::{Port, ID};
+use crate::attributes::html;
+use crate::attributes::record::RecordLabel;
+use crate::attributes::{self, Attribute, Label, Shape};
+
+pub use cluster::Cluster;
+
+/// The attributes that apply to a resolved graph element, by name
+pub type Attributes = BTreeMap<ID, ID>;
+
+/// A node of a resolved graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct SemanticNode {
+    pub id: ID,
+    pub attributes: Attributes,
+    /// The names of the attributes whose values were written as HTML strings
+    pub html_attributes: BTreeSet<ID>,
+}
+
+/// An edge of a resolved graph, connecting exactly two nodes
+///
+/// For undirected graphs `from` and `to` are simply the nodes in the order
+/// they were written in. The ports are the ones written on the endpoints,
+/// falling back to the `tailport` and `headport` attributes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SemanticEdge {
+    pub from: ID,
+    pub from_port: Option<Port>,
+    pub to: ID,
+    pub to_port: Option<Port>,
+    pub attributes: Attributes,
+}
+
+impl SemanticNode {
+    /// Reads the attribute `A` of the node, see [`attributes::get`]
+    pub fn attr<A: Attribute>(&self) -> anyhow::Result<Option<A>> {
+        attributes::get(&self.attributes)
+    }
+
+    /// The fields of the node's label, if its shape is `record` or `Mrecord`
+    ///
+    /// Nodes without a label get the default `\N`, which is a single field.
+    pub fn record_label(&self) -> anyhow::Result<Option<RecordLabel>> {
+        if !self.attr::<Shape>()?.is_some_and(|shape| shape.is_record()) {
+            return Ok(None);
+        }
+        let label = self
+            .attr::<Label>()?
+            .map_or(String::from("\\N"), |label| label.0);
+        label
+            .parse()
+            .map(Some)
+            .map_err(|e: anyhow::Error| e.context("Error; invalid value for label"))
+    }
+
+    /// The names of the ports the node's label declares
+    ///
+    /// Those are the `PORT`s of an HTML label, which takes precedence over
+    /// the shape, or the `<port>`s of a record label. Other nodes have none.
+    pub fn ports(&self) -> anyhow::Result<Vec<String>> {
+        if let Some(label) = self.attributes.get("label") {
+            if self.html_attributes.contains("label") {
+                return Ok(html::ports(html::markup(label)));
+            }
+        }
+        Ok(match self.record_label()? {
+            Some(record) => record.ports().into_iter().map(String::from).collect(),
+            None => vec![],
+        })
+    }
+}
+
+impl SemanticEdge {
+    /// Reads the attribute `A` of the edge, see [`attributes::get`]
+    pub fn attr<A: Attribute>(&self) -> anyhow::Result<Option<A>> {
+        attributes::get(&self.attributes)
+    }
+}
+
+/// A graph with all of its nodes, edges and attributes resolved
+///
+/// Nodes and edges are kept in the order they were created in.
+/// This is produced by [`Graph::resolve`](crate::ast_nodes::Graph::resolve)
+/// or [`DotGraph::resolve`](crate::DotGraph::resolve).
+#[derive(Debug, Clone)]
+pub struct SemanticGraph {
+    id: ID,
+    is_directed: bool,
+    is_strict: bool,
+    attributes: Attributes,
+    nodes: Vec<SemanticNode>,
+    edges: Vec<SemanticEdge>,
+    clusters: Vec<Cluster>,
+    node_index: HashMap<ID, usize>,
+    indices: query::Indices,
+}
+
+impl SemanticGraph {
+    /// The name of the graph, which is empty for anonymous graphs
+    pub fn id(&self) -> &ID {
+        &self.id
+    }
+
+    /// Returns true if the graph was a `digraph`
+    pub fn is_directed(&self) -> bool {
+        self.is_directed
+    }
+
+    /// Returns true if the graph was declared as `strict`
+    pub fn is_strict(&self) -> bool {
+        self.is_strict
+    }
+
+    /// The attributes of the toplevel graph
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    /// Reads the attribute `A` of the toplevel graph, see [`attributes::get`]
+    pub fn attr<A: Attribute>(&self) -> anyhow::Result<Option<A>> {
+        attributes::get(&self.attributes)
+    }
+
+    /// All nodes of the graph, in the order they were created in
+    pub fn nodes(&self) -> &[SemanticNode] {
+        &self.nodes
+    }
+
+    /// All edges of the graph, in the order they were created in
+    pub fn edges(&self) -> &[SemanticEdge] {
+        &self.edges
+    }
+}
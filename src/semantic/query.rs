@@ -0,0 +1,223 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{SemanticEdge, SemanticGraph, SemanticNode};
+use crate::ast_nodes::ID;
+
+/// Lookup tables over the nodes and edges of a [`SemanticGraph`], built once after resolving it
+#[derive(Debug, Clone, Default)]
+pub(super) struct Indices {
+    /// The indices of the edges leaving each node
+    outgoing: HashMap<ID, Vec<usize>>,
+    /// The indices of the edges entering each node
+    incoming: HashMap<ID, Vec<usize>>,
+    /// The indices of the nodes with each attribute name and value
+    node_attributes: HashMap<(ID, ID), Vec<usize>>,
+}
+
+impl Indices {
+    /// Indexes the nodes and edges of a graph
+    ///
+    /// For undirected graphs an edge is both outgoing and incoming for each of
+    /// its two nodes.
+    pub(super) fn build(is_directed: bool, nodes: &[SemanticNode], edges: &[SemanticEdge]) -> Self {
+        let mut indices = Self::default();
+        for (index, edge) in edges.iter().enumerate() {
+            indices
+                .outgoing
+                .entry(edge.from.clone())
+                .or_default()
+                .push(index);
+            indices
+                .incoming
+                .entry(edge.to.clone())
+                .or_default()
+                .push(index);
+            if !is_directed && edge.from != edge.to {
+                indices
+                    .outgoing
+                    .entry(edge.to.clone())
+                    .or_default()
+                    .push(index);
+                indices
+                    .incoming
+                    .entry(edge.from.clone())
+                    .or_default()
+                    .push(index);
+            }
+        }
+        for (index, node) in nodes.iter().enumerate() {
+            for (name, value) in &node.attributes {
+                indices
+                    .node_attributes
+                    .entry((name.clone(), value.clone()))
+                    .or_default()
+                    .push(index);
+            }
+        }
+        indices
+    }
+}
+
+impl SemanticGraph {
+    /// Looks up a node by its id
+    pub fn node(&self, id: &str) -> Option<&SemanticNode> {
+        self.node_index.get(id).map(|index| &self.nodes[*index])
+    }
+
+    /// The edges leaving the node `id`
+    ///
+    /// For undirected graphs these are all edges connected to the node.
+    ///
+    /// ```
+    /// use graphviz_parser::DotGraph;
+    /// use std::str::FromStr;
+    ///
+    /// let graph = DotGraph::from_str("digraph { a -> b; a -> c; c -> a }").unwrap().resolve();
+    /// let targets: Vec<_> = graph.edges_from("a").map(|e| e.to.as_str()).collect();
+    /// assert_eq!(targets, vec!["b", "c"]);
+    /// assert_eq!(graph.in_degree("a"), 1);
+    /// ```
+    pub fn edges_from<'a>(&'a self, id: &str) -> impl Iterator<Item = &'a SemanticEdge> + 'a {
+        Self::lookup(&self.indices.outgoing, id)
+            .iter()
+            .map(move |index| &self.edges[*index])
+    }
+
+    /// The edges entering the node `id`
+    ///
+    /// For undirected graphs these are all edges connected to the node.
+    pub fn edges_to<'a>(&'a self, id: &str) -> impl Iterator<Item = &'a SemanticEdge> + 'a {
+        Self::lookup(&self.indices.incoming, id)
+            .iter()
+            .map(move |index| &self.edges[*index])
+    }
+
+    /// The edges going from `from` to `to`
+    ///
+    /// For undirected graphs the order of the two nodes doesn't matter.
+    pub fn edges_between<'a>(
+        &'a self,
+        from: &'a str,
+        to: &'a str,
+    ) -> impl Iterator<Item = &'a SemanticEdge> + 'a {
+        self.edges_from(from).filter(move |edge| {
+            (edge.from == from && edge.to == to)
+                || (!self.is_directed && edge.from == to && edge.to == from)
+        })
+    }
+
+    /// The ids of all nodes connected to the node `id` by an edge, in either direction
+    pub fn neighbors(&self, id: &str) -> Vec<&ID> {
+        let mut seen = HashSet::new();
+        self.edges_from(id)
+            .chain(self.edges_to(id))
+            .map(|edge| {
+                if edge.from == id {
+                    &edge.to
+                } else {
+                    &edge.from
+                }
+            })
+            .filter(|neighbor| seen.insert(*neighbor))
+            .collect()
+    }
+
+    /// The number of edges leaving the node `id`
+    pub fn out_degree(&self, id: &str) -> usize {
+        Self::lookup(&self.indices.outgoing, id).len()
+    }
+
+    /// The number of edges entering the node `id`
+    pub fn in_degree(&self, id: &str) -> usize {
+        Self::lookup(&self.indices.incoming, id).len()
+    }
+
+    /// All nodes whose attribute `name` resolved to `value`
+    pub fn nodes_with_attr<'a>(
+        &'a self,
+        name: &str,
+        value: &str,
+    ) -> impl Iterator<Item = &'a SemanticNode> + 'a {
+        self.indices
+            .node_attributes
+            .get(&(name.to_string(), value.to_string()))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+            .iter()
+            .map(move |index| &self.nodes[*index])
+    }
+
+    fn lookup<'a>(index: &'a HashMap<ID, Vec<usize>>, id: &str) -> &'a [usize] {
+        index.get(id).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast_nodes::{Directed, Graph, Undirected};
+    use crate::lex::PeekableLexer;
+    use crate::parse::Constructable;
+    use crate::semantic::SemanticGraph;
+
+    fn resolve(s: &str) -> SemanticGraph {
+        Graph::<Directed>::from_lexer(PeekableLexer::from(s))
+            .unwrap()
+            .0
+            .resolve()
+    }
+
+    #[test]
+    fn query_node_lookup_test() {
+        let g = resolve("digraph { a [shape = box]; b -> c }");
+        assert_eq!(g.node("a").unwrap().attributes["shape"], "box");
+        assert!(g.node("c").is_some());
+        assert!(g.node("d").is_none());
+    }
+
+    #[test]
+    fn query_directed_edges_test() {
+        let g = resolve("digraph { a -> b; a -> c; c -> a; a -> b [color = red]; d }");
+        let to: Vec<_> = g.edges_from("a").map(|e| e.to.as_str()).collect();
+        assert_eq!(to, vec!["b", "c", "b"]);
+        let from: Vec<_> = g.edges_to("a").map(|e| e.from.as_str()).collect();
+        assert_eq!(from, vec!["c"]);
+        assert_eq!(g.out_degree("a"), 3);
+        assert_eq!(g.in_degree("a"), 1);
+        assert_eq!(g.in_degree("b"), 2);
+        assert_eq!(g.out_degree("d"), 0);
+        assert_eq!(g.out_degree("missing"), 0);
+        assert_eq!(g.edges_between("a", "b").count(), 2);
+        assert_eq!(g.edges_between("b", "a").count(), 0);
+        assert_eq!(g.neighbors("a"), vec!["b", "c"]);
+        assert!(g.neighbors("d").is_empty());
+    }
+
+    #[test]
+    fn query_undirected_edges_test() {
+        let g = Graph::<Undirected>::from_lexer(PeekableLexer::from(
+            "graph G { a -- b; c -- a; a -- a }",
+        ))
+        .unwrap()
+        .0
+        .resolve();
+        assert_eq!(g.out_degree("a"), 3);
+        assert_eq!(g.in_degree("a"), 3);
+        assert_eq!(g.in_degree("b"), 1);
+        assert_eq!(g.edges_between("b", "a").count(), 1);
+        assert_eq!(g.edges_between("a", "c").count(), 1);
+        assert_eq!(g.edges_between("a", "a").count(), 1);
+        assert_eq!(g.edges_between("b", "b").count(), 0);
+        assert_eq!(g.neighbors("a"), vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn query_nodes_with_attr_test() {
+        let g = resolve("digraph { node [shape = box]; a; b [shape = circle]; c }");
+        let boxes: Vec<_> = g
+            .nodes_with_attr("shape", "box")
+            .map(|n| n.id.as_str())
+            .collect();
+        assert_eq!(boxes, vec!["a", "c"]);
+        assert_eq!(g.nodes_with_attr("shape", "oval").count(), 0);
+    }
+}
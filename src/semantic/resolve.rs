@@ -0,0 +1,632 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use super::cluster::{build_clusters, SubgraphRecord};
+use super::query::Indices;
+use super::{Attributes, SemanticEdge, SemanticGraph, SemanticNode};
+use crate::ast_nodes::{
+    AttributeList, AttributeStatement, Edge, EdgeLHS, EdgeRHS, Graph, GraphDirection, Node, Port,
+    Statement, Subgraph, ID,
+};
+use crate::DotGraph;
+
+impl<T: GraphDirection> Graph<T> {
+    /// Resolves this graph into its nodes and edges, and the attributes that apply to them
+    ///
+    /// See the [semantic](crate::semantic) module for the rules this applies.
+    pub fn resolve(&self) -> SemanticGraph {
+        let mut resolver = Resolver::new(self);
+        resolver.resolve_statements(&self.statements);
+        resolver.finish()
+    }
+}
+
+impl DotGraph {
+    /// Resolves this graph into its nodes and edges, and the attributes that apply to them
+    ///
+    /// See the [semantic](crate::semantic) module for the rules this applies.
+    pub fn resolve(&self) -> SemanticGraph {
+        match self {
+            Self::Undirected(graph) => graph.resolve(),
+            Self::Directed(graph) => graph.resolve(),
+        }
+    }
+}
+
+/// The defaults in effect at a given point of the graph
+///
+/// Every subgraph starts out with a copy of the scope it is declared in,
+/// and any changes it makes are dropped again at its end.
+#[derive(Clone, Default)]
+struct Scope {
+    graph_attributes: Attributes,
+    node_defaults: Attributes,
+    /// The names of the node defaults whose values are HTML strings
+    html_node_defaults: BTreeSet<ID>,
+    edge_defaults: Attributes,
+}
+
+/// The nodes a single endpoint of an edge statement stands for
+type Endpoint = Vec<(ID, Option<Port>)>;
+
+/// The nodes mentioned inside of a subgraph, in the order they were first mentioned in
+#[derive(Default)]
+struct Members {
+    ids: Vec<ID>,
+    seen: HashSet<ID>,
+}
+
+impl Members {
+    fn insert(&mut self, id: &ID) {
+        if self.seen.insert(id.clone()) {
+            self.ids.push(id.clone());
+        }
+    }
+}
+
+struct Resolver {
+    graph: SemanticGraph,
+    scopes: Vec<Scope>,
+    members: Vec<Members>,
+    /// The edge connecting each pair of nodes, which is only tracked for strict graphs
+    edge_index: HashMap<(ID, ID), usize>,
+    subgraphs: Vec<SubgraphRecord>,
+    /// The record of each named subgraph, by the record of its parent and its name
+    subgraph_index: HashMap<(Option<usize>, ID), usize>,
+    /// The record of the subgraph currently being resolved
+    current_subgraph: Option<usize>,
+}
+
+fn apply(attributes: &mut Attributes, attribute_list: &AttributeList) {
+    for assignment in attribute_list.iter().flatten() {
+        attributes.insert(assignment.lhs.clone(), assignment.rhs.clone());
+    }
+}
+
+/// Records which of the attributes set by `attribute_list` are HTML strings
+fn apply_html(html_attributes: &mut BTreeSet<ID>, attribute_list: &AttributeList) {
+    for assignment in attribute_list.iter().flatten() {
+        if assignment.is_html {
+            html_attributes.insert(assignment.lhs.clone());
+        } else {
+            html_attributes.remove(&assignment.lhs);
+        }
+    }
+}
+
+/// Reads a `tailport` or `headport` attribute, ignoring values that aren't valid ports
+fn port_attribute(attributes: &Attributes, name: &str) -> Option<Port> {
+    attributes.get(name).and_then(|value| value.parse().ok())
+}
+
+impl Resolver {
+    fn new<T: GraphDirection>(graph: &Graph<T>) -> Self {
+        Self {
+            graph: SemanticGraph {
+                id: graph.id.clone(),
+                is_directed: T::is_directed(),
+                is_strict: graph.is_strict,
+                attributes: Attributes::new(),
+                nodes: vec![],
+                edges: vec![],
+                clusters: vec![],
+                node_index: HashMap::new(),
+                indices: Indices::default(),
+            },
+            scopes: vec![Scope::default()],
+            members: vec![Members::default()],
+            edge_index: HashMap::new(),
+            subgraphs: vec![],
+            subgraph_index: HashMap::new(),
+            current_subgraph: None,
+        }
+    }
+
+    fn finish(mut self) -> SemanticGraph {
+        if let Some(root) = self.scopes.pop() {
+            self.graph.attributes = root.graph_attributes;
+        }
+        self.graph.clusters = build_clusters(&self.subgraphs);
+        self.graph.indices =
+            Indices::build(self.graph.is_directed, &self.graph.nodes, &self.graph.edges);
+        self.graph
+    }
+
+    fn scope(&mut self) -> &mut Scope {
+        self.scopes
+            .last_mut()
+            .expect("the root scope is only removed once resolution finished")
+    }
+
+    fn resolve_statements<T>(&mut self, statements: &[Statement<T>]) {
+        for statement in statements {
+            match statement {
+                Statement::Node(node) => self.resolve_node(node),
+                Statement::Edge(edge) => self.resolve_edge(edge),
+                Statement::Attribute(attribute) => match &**attribute {
+                    AttributeStatement::Graph(attribute_list) => {
+                        apply(&mut self.scope().graph_attributes, attribute_list)
+                    }
+                    AttributeStatement::Node(attribute_list) => {
+                        let scope = self.scope();
+                        apply(&mut scope.node_defaults, attribute_list);
+                        apply_html(&mut scope.html_node_defaults, attribute_list);
+                    }
+                    AttributeStatement::Edge(attribute_list) => {
+                        apply(&mut self.scope().edge_defaults, attribute_list)
+                    }
+                },
+                Statement::Assignment(assignment) => {
+                    self.scope()
+                        .graph_attributes
+                        .insert(assignment.lhs.clone(), assignment.rhs.clone());
+                }
+                Statement::Subgraph(subgraph) => {
+                    self.resolve_subgraph(subgraph);
+                }
+                // Malformed statements don't describe anything
+                Statement::Error(_) => {}
+            }
+        }
+    }
+
+    /// Resolves the statements of a subgraph in a scope of their own, and
+    /// returns the ids of all nodes mentioned inside of it
+    fn resolve_subgraph<T>(&mut self, subgraph: &Subgraph<T>) -> Vec<ID> {
+        let record = self.subgraph_record(&subgraph.id);
+        let parent = self.current_subgraph.replace(record);
+        let scope = self.scope().clone();
+        self.scopes.push(scope);
+        self.members.push(Members::default());
+
+        self.resolve_statements(&subgraph.statements);
+
+        self.current_subgraph = parent;
+        let scope = self.scopes.pop().unwrap_or_default();
+        let members = self.members.pop().unwrap_or_default().ids;
+        // Nodes of a nested subgraph are members of the enclosing one as well
+        if let Some(parent) = self.members.last_mut() {
+            for id in &members {
+                parent.insert(id);
+            }
+        }
+        let record = &mut self.subgraphs[record];
+        record.attributes.extend(scope.graph_attributes);
+        if record.nodes.is_empty() {
+            record.nodes = members.clone();
+        } else {
+            // The subgraph was reopened, so only add the nodes it didn't have yet
+            let known: HashSet<ID> = record.nodes.iter().cloned().collect();
+            record
+                .nodes
+                .extend(members.iter().filter(|id| !known.contains(*id)).cloned());
+        }
+        members
+    }
+
+    /// Returns the record of a subgraph declared in the current subgraph,
+    /// reusing the existing one if a subgraph of that name was declared before
+    fn subgraph_record(&mut self, id: &Option<ID>) -> usize {
+        let key = id.as_ref().map(|id| (self.current_subgraph, id.clone()));
+        if let Some(index) = key.as_ref().and_then(|key| self.subgraph_index.get(key)) {
+            return *index;
+        }
+        let index = self.subgraphs.len();
+        self.subgraphs.push(SubgraphRecord {
+            id: id.clone(),
+            parent: self.current_subgraph,
+            ..SubgraphRecord::default()
+        });
+        if let Some(key) = key {
+            self.subgraph_index.insert(key, index);
+        }
+        index
+    }
+
+    /// Returns the index of the node `id`, creating it with the current node defaults if needed
+    fn declare_node(&mut self, id: &ID) -> usize {
+        if let Some(members) = self.members.last_mut() {
+            members.insert(id);
+        }
+        if let Some(index) = self.graph.node_index.get(id) {
+            return *index;
+        }
+        let scope = self.scope();
+        let attributes = scope.node_defaults.clone();
+        let html_attributes = scope.html_node_defaults.clone();
+        let index = self.graph.nodes.len();
+        self.graph.nodes.push(SemanticNode {
+            id: id.clone(),
+            attributes,
+            html_attributes,
+        });
+        self.graph.node_index.insert(id.clone(), index);
+        index
+    }
+
+    fn resolve_node(&mut self, node: &Node) {
+        let index = self.declare_node(&node.id);
+        if let Some(attribute_list) = &node.attribute_list {
+            let node = &mut self.graph.nodes[index];
+            apply(&mut node.attributes, attribute_list);
+            apply_html(&mut node.html_attributes, attribute_list);
+        }
+    }
+
+    fn resolve_edge<T>(&mut self, edge: &Edge<T>) {
+        let mut endpoints = vec![self.resolve_edge_lhs(&edge.lhs)];
+        let mut rhs = &*edge.rhs;
+        loop {
+            match rhs {
+                EdgeRHS::Edge(next) => {
+                    endpoints.push(self.resolve_edge_lhs(&next.lhs));
+                    rhs = &*next.rhs;
+                }
+                EdgeRHS::Node(node) => {
+                    endpoints.push(self.resolve_endpoint_node(node));
+                    break;
+                }
+                EdgeRHS::Subgraph(subgraph) => {
+                    endpoints.push(self.resolve_endpoint_subgraph(subgraph));
+                    break;
+                }
+            }
+        }
+
+        let defaults = self.scope().edge_defaults.clone();
+        for pair in endpoints.windows(2) {
+            for (from, from_port) in &pair[0] {
+                for (to, to_port) in &pair[1] {
+                    self.add_edge(
+                        SemanticEdge {
+                            from: from.clone(),
+                            from_port: from_port.clone(),
+                            to: to.clone(),
+                            to_port: to_port.clone(),
+                            attributes: Attributes::new(),
+                        },
+                        &defaults,
+                        &edge.attr_list,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Adds a new edge to the graph
+    ///
+    /// In a strict graph there is at most one edge between two nodes, so an
+    /// edge connecting two already connected nodes is identified with the
+    /// existing edge instead, and only the attributes given in its edge
+    /// statement are applied to it. For undirected graphs the order of the two
+    /// nodes doesn't matter, and the ports of an edge written the other way
+    /// around are swapped to match the existing edge. Self-loops are allowed, but are deduplicated the
+    /// same way as any other edge.
+    fn add_edge(
+        &mut self,
+        mut edge: SemanticEdge,
+        defaults: &Attributes,
+        attribute_list: &AttributeList,
+    ) {
+        if self.graph.is_strict {
+            let key = if self.graph.is_directed || edge.from <= edge.to {
+                (edge.from.clone(), edge.to.clone())
+            } else {
+                (edge.to.clone(), edge.from.clone())
+            };
+            if let Some(index) = self.edge_index.get(&key) {
+                let existing = &mut self.graph.edges[*index];
+                if existing.from != edge.from {
+                    // An undirected edge written the other way around, whose
+                    // tail is the head of the existing edge
+                    std::mem::swap(&mut edge.from_port, &mut edge.to_port);
+                    for assignment in attribute_list.iter().flatten() {
+                        let name = match assignment.lhs.as_str() {
+                            "tailport" => "headport",
+                            "headport" => "tailport",
+                            name => name,
+                        };
+                        existing
+                            .attributes
+                            .insert(name.to_string(), assignment.rhs.clone());
+                    }
+                } else {
+                    apply(&mut existing.attributes, attribute_list);
+                }
+                if edge.from_port.is_some() {
+                    existing.from_port = edge.from_port;
+                }
+                if edge.to_port.is_some() {
+                    existing.to_port = edge.to_port;
+                }
+                return;
+            }
+            self.edge_index.insert(key, self.graph.edges.len());
+        }
+        edge.attributes = defaults.clone();
+        apply(&mut edge.attributes, attribute_list);
+        // Ports written on the endpoints take precedence over the port attributes
+        if edge.from_port.is_none() {
+            edge.from_port = port_attribute(&edge.attributes, "tailport");
+        }
+        if edge.to_port.is_none() {
+            edge.to_port = port_attribute(&edge.attributes, "headport");
+        }
+        self.graph.edges.push(edge);
+    }
+
+    fn resolve_edge_lhs<T>(&mut self, lhs: &EdgeLHS<T>) -> Endpoint {
+        match lhs {
+            EdgeLHS::Node(node) => self.resolve_endpoint_node(node),
+            EdgeLHS::Subgraph(subgraph) => self.resolve_endpoint_subgraph(subgraph),
+        }
+    }
+
+    fn resolve_endpoint_node(&mut self, node: &Node) -> Endpoint {
+        self.declare_node(&node.id);
+        vec![(node.id.clone(), node.port.clone())]
+    }
+
+    /// A subgraph endpoint stands for all nodes inside of it, so that `{a b} -> {c d}`
+    /// connects each of `a` and `b` with each of `c` and `d`
+    fn resolve_endpoint_subgraph<T>(&mut self, subgraph: &Subgraph<T>) -> Endpoint {
+        self.resolve_subgraph(subgraph)
+            .into_iter()
+            .map(|id| (id, None))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast_nodes::{CompassPoint, Directed, Graph, Undirected};
+    use crate::lex::PeekableLexer;
+    use crate::parse::Constructable;
+    use crate::semantic::SemanticGraph;
+
+    fn resolve(s: &str) -> SemanticGraph {
+        Graph::<Directed>::from_lexer(PeekableLexer::from(s))
+            .unwrap()
+            .0
+            .resolve()
+    }
+
+    fn node_attr<'a>(g: &'a SemanticGraph, id: &str, attr: &str) -> Option<&'a str> {
+        let node = g.nodes().iter().find(|n| n.id == id).unwrap();
+        node.attributes.get(attr).map(|v| v.as_str())
+    }
+
+    #[test]
+    fn resolve_implicit_nodes_test() {
+        let g = resolve("digraph G { a -> b -> c\n d }");
+        let ids: Vec<_> = g.nodes().iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b", "c", "d"]);
+        let edges: Vec<_> = g
+            .edges()
+            .iter()
+            .map(|e| (e.from.as_str(), e.to.as_str()))
+            .collect();
+        assert_eq!(edges, vec![("a", "b"), ("b", "c")]);
+    }
+
+    #[test]
+    fn resolve_node_defaults_by_declaration_order_test() {
+        let g = resolve("digraph G { a\n node [shape = box]\n b\n a -> c }");
+        assert_eq!(node_attr(&g, "a", "shape"), None);
+        assert_eq!(node_attr(&g, "b", "shape"), Some("box"));
+        assert_eq!(node_attr(&g, "c", "shape"), Some("box"));
+    }
+
+    #[test]
+    fn resolve_defaults_scoped_by_subgraph_test() {
+        let g = resolve(
+            "digraph G { node [color = red]\n subgraph s { node [color = blue]\n edge [style = dashed]\n a -> b }\n c -> d }",
+        );
+        assert_eq!(node_attr(&g, "a", "color"), Some("blue"));
+        assert_eq!(node_attr(&g, "c", "color"), Some("red"));
+        assert_eq!(g.edges()[0].attributes["style"], "dashed");
+        assert!(!g.edges()[1].attributes.contains_key("style"));
+    }
+
+    #[test]
+    fn resolve_repeated_nodes_merge_test() {
+        let g = resolve("digraph G { a [color = red, shape = box]\n a [color = blue]\n a }");
+        assert_eq!(g.nodes().len(), 1);
+        assert_eq!(node_attr(&g, "a", "color"), Some("blue"));
+        assert_eq!(node_attr(&g, "a", "shape"), Some("box"));
+    }
+
+    #[test]
+    fn resolve_graph_attributes_test() {
+        let g = resolve(
+            "digraph G { rankdir = LR\n graph [label = top]\n subgraph s { label = inner } }",
+        );
+        assert_eq!(g.attributes()["rankdir"], "LR");
+        assert_eq!(g.attributes()["label"], "top");
+    }
+
+    #[test]
+    fn resolve_edge_attributes_test() {
+        let g = resolve(
+            "digraph G { edge [color = grey]\n a -> b [color = red, penwidth = 2]\n b -> c }",
+        );
+        assert_eq!(g.edges()[0].attributes["color"], "red");
+        assert_eq!(g.edges()[0].attributes["penwidth"], "2");
+        assert_eq!(g.edges()[1].attributes["color"], "grey");
+    }
+
+    #[test]
+    fn resolve_subgraph_endpoints_cross_product_test() {
+        let g = resolve("digraph G { {a b} -> {c d} }");
+        let edges: Vec<_> = g
+            .edges()
+            .iter()
+            .map(|e| (e.from.as_str(), e.to.as_str()))
+            .collect();
+        assert_eq!(edges, vec![("a", "c"), ("a", "d"), ("b", "c"), ("b", "d")]);
+    }
+
+    #[test]
+    fn resolve_subgraph_endpoints_in_chain_test() {
+        let g = resolve("digraph G { a -> subgraph s { b; c [shape = box] } -> d }");
+        let edges: Vec<_> = g
+            .edges()
+            .iter()
+            .map(|e| (e.from.as_str(), e.to.as_str()))
+            .collect();
+        assert_eq!(edges, vec![("a", "b"), ("a", "c"), ("b", "d"), ("c", "d")]);
+        assert_eq!(node_attr(&g, "c", "shape"), Some("box"));
+    }
+
+    #[test]
+    fn resolve_nested_subgraph_endpoints_test() {
+        let g = resolve("digraph G { { a { b -> x } } -> c }");
+        let ids: Vec<_> = g.nodes().iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b", "x", "c"]);
+        let edges: Vec<_> = g
+            .edges()
+            .iter()
+            .map(|e| (e.from.as_str(), e.to.as_str()))
+            .collect();
+        assert_eq!(edges, vec![("b", "x"), ("a", "c"), ("b", "c"), ("x", "c")]);
+    }
+
+    fn resolve_undirected(s: &str) -> SemanticGraph {
+        Graph::<Undirected>::from_lexer(PeekableLexer::from(s))
+            .unwrap()
+            .0
+            .resolve()
+    }
+
+    #[test]
+    fn resolve_strict_merges_duplicate_edges_test() {
+        let g = resolve("strict digraph { a -> b; a -> b [color=red] }");
+        assert_eq!(g.edges().len(), 1);
+        assert_eq!(g.edges()[0].attributes["color"], "red");
+    }
+
+    #[test]
+    fn resolve_strict_directed_keeps_reverse_edges_test() {
+        let g = resolve("strict digraph { a -> b; b -> a }");
+        assert_eq!(g.edges().len(), 2);
+    }
+
+    #[test]
+    fn resolve_strict_undirected_merges_reverse_edges_test() {
+        let g = resolve_undirected("strict graph G { a -- b [color=red]; b -- a [style=bold] }");
+        assert_eq!(g.edges().len(), 1);
+        assert_eq!(g.edges()[0].from, "a");
+        assert_eq!(g.edges()[0].attributes["color"], "red");
+        assert_eq!(g.edges()[0].attributes["style"], "bold");
+    }
+
+    #[test]
+    fn resolve_strict_undirected_reverse_ports_test() {
+        let g = resolve_undirected("strict graph { a:n -- b:s; b:e -- a:w }");
+        assert_eq!(g.edges().len(), 1);
+        let edge = &g.edges()[0];
+        assert_eq!((edge.from.as_str(), edge.to.as_str()), ("a", "b"));
+        let from = edge.from_port.as_ref().unwrap();
+        let to = edge.to_port.as_ref().unwrap();
+        assert_eq!(from.compass_point, Some(CompassPoint::West));
+        assert_eq!(to.compass_point, Some(CompassPoint::East));
+
+        let g = resolve_undirected("strict graph { a -- b; b -- a [tailport=e, headport=w] }");
+        let edge = &g.edges()[0];
+        assert_eq!(edge.attributes["tailport"], "w");
+        assert_eq!(edge.attributes["headport"], "e");
+    }
+
+    #[test]
+    fn resolve_strict_self_loops_test() {
+        let g = resolve("strict digraph { a -> a; a -> a [color=red]; a -> b }");
+        assert_eq!(g.edges().len(), 2);
+        assert_eq!(g.edges()[0].to, "a");
+        assert_eq!(g.edges()[0].attributes["color"], "red");
+    }
+
+    #[test]
+    fn resolve_strict_does_not_reapply_defaults_test() {
+        let g = resolve("strict digraph { edge [color=blue]\n a -> b\n edge [color=red, style=dashed]\n a -> b [weight=2] }");
+        assert_eq!(g.edges().len(), 1);
+        assert_eq!(g.edges()[0].attributes["color"], "blue");
+        assert_eq!(g.edges()[0].attributes["weight"], "2");
+        assert!(!g.edges()[0].attributes.contains_key("style"));
+    }
+
+    #[test]
+    fn resolve_strict_subgraph_endpoints_test() {
+        let g = resolve("strict digraph { a -> {b c}; {a} -> b }");
+        assert_eq!(g.edges().len(), 2);
+    }
+
+    #[test]
+    fn resolve_non_strict_keeps_multi_edges_test() {
+        let g = resolve("digraph { a -> b; a -> b [color=red]; a -> a; a -> a }");
+        assert_eq!(g.edges().len(), 4);
+    }
+
+    #[test]
+    fn resolve_undirected_test() {
+        let g = Graph::<Undirected>::from_lexer(PeekableLexer::from("graph G { a -- b }"))
+            .unwrap()
+            .0
+            .resolve();
+        assert!(!g.is_directed());
+        assert_eq!(g.edges().len(), 1);
+    }
+
+    #[test]
+    fn resolve_edge_ports_test() {
+        let g = resolve(
+            "digraph { edge [headport = \"f1:w\"]\n a:n -> b\n a -> b:e [tailport = \"f0:up\"] }",
+        );
+        let first = &g.edges()[0];
+        let from_port = first.from_port.as_ref().unwrap();
+        assert_eq!(from_port.id, None);
+        assert_eq!(from_port.compass_point, Some(CompassPoint::North));
+        let to_port = first.to_port.as_ref().unwrap();
+        assert_eq!(to_port.id.as_deref(), Some("f1"));
+        assert_eq!(to_port.compass_point, Some(CompassPoint::West));
+
+        let second = &g.edges()[1];
+        assert_eq!(second.from_port, None);
+        let to_port = second.to_port.as_ref().unwrap();
+        assert_eq!(to_port.compass_point, Some(CompassPoint::East));
+    }
+
+    #[test]
+    fn resolve_record_labels_test() {
+        let input = std::fs::read_to_string("samples/datastruct.dot").unwrap();
+        let g = resolve(&input);
+        let node = g.nodes().iter().find(|n| n.id == "node1").unwrap();
+        let label = node.record_label().unwrap().unwrap();
+        assert_eq!(label.ports(), vec!["f0", "f1", "f2"]);
+
+        let g =
+            resolve("digraph { a [shape = Mrecord]\n b\n c [shape = record, label = \"{a|b\"] }");
+        let label = g.nodes()[0].record_label().unwrap().unwrap();
+        assert_eq!(label.to_string(), "\\N");
+        assert_eq!(g.nodes()[1].record_label().unwrap(), None);
+        assert!(g.nodes()[2].record_label().is_err());
+    }
+
+    #[test]
+    fn resolve_html_labels_test() {
+        let g = resolve(
+            "digraph {
+                node [label = <<b PORT=\"p\">b</b>>]
+                a
+                b [label = \"<<b PORT='q'>b</b>>\"]
+                node [label = \"<f0> x\", shape = record]
+                c
+            }",
+        );
+        assert_eq!(g.nodes()[0].ports().unwrap(), vec!["p"]);
+        // A quoted string that looks like HTML is a record label here
+        let b = &g.nodes()[1];
+        assert_eq!(b.attributes["label"], "<<b PORT='q'>b</b>>");
+        assert!(b.html_attributes.is_empty());
+        assert_eq!(b.ports().unwrap(), Vec::<String>::new());
+        assert_eq!(g.nodes()[2].ports().unwrap(), vec!["f0"]);
+    }
+}
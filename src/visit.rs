@@ -0,0 +1,298 @@
+//! Read-only traversal of the AST
+//!
+//! The [`Visit`] trait has one method per AST node type. Every method has a
+//! default implementation that simply walks into the children of the node, so
+//! an implementation only needs to override the methods for the nodes it cares
+//! about. The walking logic itself lives in the free functions of this module
+//! (`visit_graph`, `visit_edge`, ...), which lets an overridden method still
+//! descend into the children once it is done with a node.
+//!
+//! As an example, the following collects the ids of every node mentioned
+//! anywhere in a graph, including inside of subgraphs and edges:
+//!
+//! ```
+//! use graphviz_parser::DotGraph;
+//! use graphviz_parser::ast_nodes::{Directed, Node};
+//! use graphviz_parser::visit::{self, Visit};
+//! use std::str::FromStr;
+//!
+//! struct NodeIds<'ast>(Vec<&'ast str>);
+//!
+//! impl<'ast> Visit<'ast, Directed> for NodeIds<'ast> {
+//!     fn visit_node(&mut self, node: &'ast Node) {
+//!         self.0.push(&node.id);
+//!         visit::visit_node(self, node);
+//!     }
+//! }
+//!
+//! if let DotGraph::Directed(graph) = DotGraph::from_str("digraph { a -> { b c } }").unwrap() {
+//!     let mut ids = NodeIds(vec![]);
+//!     ids.visit_graph(&graph);
+//!     assert_eq!(ids.0, vec!["a", "b", "c"]);
+//! }
+//! ```
+
+use crate::ast_nodes::{
+    Assignment, AttributeList, AttributeStatement, Edge, EdgeLHS, EdgeRHS, ErrorStatement, Graph,
+    Node, Port, Statement, Subgraph,
+};
+
+/// A visitor over a borrowed AST, parameterized by the direction `T` of the graph
+///
+/// The `'ast` lifetime allows implementations to hold on to references into
+/// the tree they are visiting.
+pub trait Visit<'ast, T> {
+    fn visit_graph(&mut self, graph: &'ast Graph<T>) {
+        visit_graph(self, graph)
+    }
+
+    fn visit_statement(&mut self, statement: &'ast Statement<T>) {
+        visit_statement(self, statement)
+    }
+
+    fn visit_node(&mut self, node: &'ast Node) {
+        visit_node(self, node)
+    }
+
+    fn visit_port(&mut self, _port: &'ast Port) {}
+
+    fn visit_edge(&mut self, edge: &'ast Edge<T>) {
+        visit_edge(self, edge)
+    }
+
+    fn visit_edge_lhs(&mut self, lhs: &'ast EdgeLHS<T>) {
+        visit_edge_lhs(self, lhs)
+    }
+
+    fn visit_edge_rhs(&mut self, rhs: &'ast EdgeRHS<T>) {
+        visit_edge_rhs(self, rhs)
+    }
+
+    fn visit_subgraph(&mut self, subgraph: &'ast Subgraph<T>) {
+        visit_subgraph(self, subgraph)
+    }
+
+    fn visit_attribute_statement(&mut self, attribute: &'ast AttributeStatement) {
+        visit_attribute_statement(self, attribute)
+    }
+
+    fn visit_attribute_list(&mut self, attribute_list: &'ast AttributeList) {
+        visit_attribute_list(self, attribute_list)
+    }
+
+    fn visit_assignment(&mut self, _assignment: &'ast Assignment) {}
+
+    fn visit_error(&mut self, _error: &'ast ErrorStatement) {}
+}
+
+pub fn visit_graph<'ast, T, V>(v: &mut V, graph: &'ast Graph<T>)
+where
+    V: Visit<'ast, T> + ?Sized,
+{
+    for statement in &graph.statements {
+        v.visit_statement(statement);
+    }
+}
+
+pub fn visit_statement<'ast, T, V>(v: &mut V, statement: &'ast Statement<T>)
+where
+    V: Visit<'ast, T> + ?Sized,
+{
+    match statement {
+        Statement::Node(node) => v.visit_node(node),
+        Statement::Edge(edge) => v.visit_edge(edge),
+        Statement::Attribute(attribute) => v.visit_attribute_statement(attribute),
+        Statement::Assignment(assignment) => v.visit_assignment(assignment),
+        Statement::Subgraph(subgraph) => v.visit_subgraph(subgraph),
+        Statement::Error(error) => v.visit_error(error),
+    }
+}
+
+pub fn visit_node<'ast, T, V>(v: &mut V, node: &'ast Node)
+where
+    V: Visit<'ast, T> + ?Sized,
+{
+    if let Some(port) = &node.port {
+        v.visit_port(port);
+    }
+    if let Some(attribute_list) = &node.attribute_list {
+        v.visit_attribute_list(attribute_list);
+    }
+}
+
+/// Walks an edge chain like `a -> b -> c` in a loop, so that long chains
+/// can't overflow the stack
+///
+/// The first endpoint is visited with `visit_edge_lhs`, and then the right
+/// hand side of every edge of the chain with `visit_edge_rhs`, in order. The
+/// attribute lists are visited last, starting from the outermost edge.
+pub fn visit_edge<'ast, T, V>(v: &mut V, edge: &'ast Edge<T>)
+where
+    V: Visit<'ast, T> + ?Sized,
+{
+    v.visit_edge_lhs(&edge.lhs);
+    let mut attribute_lists = vec![];
+    let mut edge = edge;
+    loop {
+        attribute_lists.push(&edge.attr_list);
+        v.visit_edge_rhs(&edge.rhs);
+        match &*edge.rhs {
+            EdgeRHS::Edge(next) => edge = next,
+            _ => break,
+        }
+    }
+    for attribute_list in attribute_lists {
+        v.visit_attribute_list(attribute_list);
+    }
+}
+
+pub fn visit_edge_lhs<'ast, T, V>(v: &mut V, lhs: &'ast EdgeLHS<T>)
+where
+    V: Visit<'ast, T> + ?Sized,
+{
+    match lhs {
+        EdgeLHS::Node(node) => v.visit_node(node),
+        EdgeLHS::Subgraph(subgraph) => v.visit_subgraph(subgraph),
+    }
+}
+
+/// Visits the endpoint directly following an edge operator
+///
+/// For an [`EdgeRHS::Edge`] that is the first endpoint of the rest of the
+/// chain, which is walked by [`visit_edge`] itself.
+pub fn visit_edge_rhs<'ast, T, V>(v: &mut V, rhs: &'ast EdgeRHS<T>)
+where
+    V: Visit<'ast, T> + ?Sized,
+{
+    match rhs {
+        EdgeRHS::Edge(edge) => v.visit_edge_lhs(&edge.lhs),
+        EdgeRHS::Node(node) => v.visit_node(node),
+        EdgeRHS::Subgraph(subgraph) => v.visit_subgraph(subgraph),
+    }
+}
+
+pub fn visit_subgraph<'ast, T, V>(v: &mut V, subgraph: &'ast Subgraph<T>)
+where
+    V: Visit<'ast, T> + ?Sized,
+{
+    for statement in &subgraph.statements {
+        v.visit_statement(statement);
+    }
+}
+
+pub fn visit_attribute_statement<'ast, T, V>(v: &mut V, attribute: &'ast AttributeStatement)
+where
+    V: Visit<'ast, T> + ?Sized,
+{
+    match attribute {
+        AttributeStatement::Graph(attribute_list)
+        | AttributeStatement::Node(attribute_list)
+        | AttributeStatement::Edge(attribute_list) => v.visit_attribute_list(attribute_list),
+    }
+}
+
+pub fn visit_attribute_list<'ast, T, V>(v: &mut V, attribute_list: &'ast AttributeList)
+where
+    V: Visit<'ast, T> + ?Sized,
+{
+    for assignment in attribute_list.iter().flatten() {
+        v.visit_assignment(assignment);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Visit;
+    use crate::ast_nodes::{
+        Assignment, Directed, EdgeLHS, EdgeRHS, Graph, Node, Statement, Subgraph,
+    };
+    use crate::lex::PeekableLexer;
+    use crate::parse::Constructable;
+
+    fn parse(s: &str) -> Graph<Directed> {
+        Graph::<Directed>::from_lexer(PeekableLexer::from(s))
+            .unwrap()
+            .0
+    }
+
+    #[derive(Default)]
+    struct Counter {
+        subgraphs: usize,
+        assignments: Vec<(String, String)>,
+    }
+
+    impl<'ast> Visit<'ast, Directed> for Counter {
+        fn visit_subgraph(&mut self, subgraph: &'ast Subgraph<Directed>) {
+            self.subgraphs += 1;
+            super::visit_subgraph(self, subgraph);
+        }
+
+        fn visit_assignment(&mut self, assignment: &'ast Assignment) {
+            self.assignments
+                .push((assignment.lhs.clone(), assignment.rhs.clone()));
+        }
+    }
+
+    /// Records the order in which the parts of edges are visited
+    #[derive(Default)]
+    struct EdgeOrder(Vec<String>);
+
+    impl<'ast> Visit<'ast, Directed> for EdgeOrder {
+        fn visit_node(&mut self, node: &'ast Node) {
+            self.0.push(node.id.clone());
+        }
+
+        fn visit_edge_lhs(&mut self, lhs: &'ast EdgeLHS<Directed>) {
+            self.0.push("lhs".to_string());
+            super::visit_edge_lhs(self, lhs);
+        }
+
+        fn visit_edge_rhs(&mut self, rhs: &'ast EdgeRHS<Directed>) {
+            self.0.push("rhs".to_string());
+            super::visit_edge_rhs(self, rhs);
+        }
+
+        fn visit_assignment(&mut self, assignment: &'ast Assignment) {
+            self.0.push(assignment.lhs.clone());
+        }
+    }
+
+    #[test]
+    fn visit_edge_order_test() {
+        let mut g = parse("digraph G { a -> b -> c [color = red] }");
+        if let Statement::Edge(edge) = &mut g.statements[0] {
+            if let EdgeRHS::Edge(next) = &mut *edge.rhs {
+                next.attr_list.push(vec![Assignment::new("style", "bold")]);
+            }
+        }
+        let mut order = EdgeOrder::default();
+        order.visit_graph(&g);
+        assert_eq!(
+            order.0,
+            vec!["lhs", "a", "rhs", "lhs", "b", "rhs", "c", "color", "style"]
+        );
+    }
+
+    #[test]
+    fn visit_nested_subgraphs_test() {
+        let g = parse("digraph G { subgraph a { { b } -> c } d -> { e } }");
+        let mut counter = Counter::default();
+        counter.visit_graph(&g);
+        assert_eq!(counter.subgraphs, 3);
+    }
+
+    #[test]
+    fn visit_assignments_test() {
+        let g = parse("digraph G { rank = same\n a [color = red]\n node [shape = box] }");
+        let mut counter = Counter::default();
+        counter.visit_graph(&g);
+        assert_eq!(
+            counter.assignments,
+            vec![
+                ("rank".to_string(), "same".to_string()),
+                ("color".to_string(), "red".to_string()),
+                ("shape".to_string(), "box".to_string()),
+            ]
+        );
+    }
+}
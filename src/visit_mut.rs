@@ -0,0 +1,283 @@
+//! In-place traversal and modification of the AST
+//!
+//! [`VisitMut`] mirrors [`Visit`](crate::visit::Visit), but hands out mutable
+//! references so that a pass can rewrite the tree as it walks it. Just like
+//! with `Visit`, every method defaults to descending into the children of the
+//! node through the free function of the same name in this module.
+//!
+//! For example, the following recolors every red element of a graph:
+//!
+//! ```
+//! use graphviz_parser::DotGraph;
+//! use graphviz_parser::ast_nodes::{Assignment, Directed};
+//! use graphviz_parser::visit_mut::VisitMut;
+//! use std::str::FromStr;
+//!
+//! struct Recolor;
+//!
+//! impl VisitMut<Directed> for Recolor {
+//!     fn visit_assignment_mut(&mut self, assignment: &mut Assignment) {
+//!         if assignment.lhs == "color" && assignment.rhs == "red" {
+//!             assignment.rhs = String::from("blue");
+//!         }
+//!     }
+//! }
+//!
+//! let graph_str = "digraph { a [color = red] }";
+//! if let DotGraph::Directed(mut graph) = DotGraph::from_str(graph_str).unwrap() {
+//!     Recolor.visit_graph_mut(&mut graph);
+//!     let resolved = DotGraph::Directed(graph).resolve();
+//!     assert_eq!(resolved.nodes()[0].attributes["color"], "blue");
+//! } else {
+//!     unreachable!()
+//! }
+//! ```
+
+use crate::ast_nodes::{
+    Assignment, AttributeList, AttributeStatement, Edge, EdgeLHS, EdgeRHS, ErrorStatement, Graph,
+    Node, Port, Statement, Subgraph,
+};
+
+/// A visitor over a mutably borrowed AST, parameterized by the direction `T` of the graph
+pub trait VisitMut<T> {
+    fn visit_graph_mut(&mut self, graph: &mut Graph<T>) {
+        visit_graph_mut(self, graph)
+    }
+
+    fn visit_statement_mut(&mut self, statement: &mut Statement<T>) {
+        visit_statement_mut(self, statement)
+    }
+
+    fn visit_node_mut(&mut self, node: &mut Node) {
+        visit_node_mut(self, node)
+    }
+
+    fn visit_port_mut(&mut self, _port: &mut Port) {}
+
+    fn visit_edge_mut(&mut self, edge: &mut Edge<T>) {
+        visit_edge_mut(self, edge)
+    }
+
+    fn visit_edge_lhs_mut(&mut self, lhs: &mut EdgeLHS<T>) {
+        visit_edge_lhs_mut(self, lhs)
+    }
+
+    fn visit_edge_rhs_mut(&mut self, rhs: &mut EdgeRHS<T>) {
+        visit_edge_rhs_mut(self, rhs)
+    }
+
+    fn visit_subgraph_mut(&mut self, subgraph: &mut Subgraph<T>) {
+        visit_subgraph_mut(self, subgraph)
+    }
+
+    fn visit_attribute_statement_mut(&mut self, attribute: &mut AttributeStatement) {
+        visit_attribute_statement_mut(self, attribute)
+    }
+
+    fn visit_attribute_list_mut(&mut self, attribute_list: &mut AttributeList) {
+        visit_attribute_list_mut(self, attribute_list)
+    }
+
+    fn visit_assignment_mut(&mut self, _assignment: &mut Assignment) {}
+
+    fn visit_error_mut(&mut self, _error: &mut ErrorStatement) {}
+}
+
+pub fn visit_graph_mut<T, V>(v: &mut V, graph: &mut Graph<T>)
+where
+    V: VisitMut<T> + ?Sized,
+{
+    for statement in &mut graph.statements {
+        v.visit_statement_mut(statement);
+    }
+}
+
+pub fn visit_statement_mut<T, V>(v: &mut V, statement: &mut Statement<T>)
+where
+    V: VisitMut<T> + ?Sized,
+{
+    match statement {
+        Statement::Node(node) => v.visit_node_mut(node),
+        Statement::Edge(edge) => v.visit_edge_mut(edge),
+        Statement::Attribute(attribute) => v.visit_attribute_statement_mut(attribute),
+        Statement::Assignment(assignment) => v.visit_assignment_mut(assignment),
+        Statement::Subgraph(subgraph) => v.visit_subgraph_mut(subgraph),
+        Statement::Error(error) => v.visit_error_mut(error),
+    }
+}
+
+pub fn visit_node_mut<T, V>(v: &mut V, node: &mut Node)
+where
+    V: VisitMut<T> + ?Sized,
+{
+    if let Some(port) = &mut node.port {
+        v.visit_port_mut(port);
+    }
+    if let Some(attribute_list) = &mut node.attribute_list {
+        v.visit_attribute_list_mut(attribute_list);
+    }
+}
+
+/// Walks an edge chain in a loop, in the same order as [`visit::visit_edge`](crate::visit::visit_edge)
+pub fn visit_edge_mut<T, V>(v: &mut V, edge: &mut Edge<T>)
+where
+    V: VisitMut<T> + ?Sized,
+{
+    v.visit_edge_lhs_mut(&mut edge.lhs);
+    let mut attribute_lists = vec![];
+    let mut edge = edge;
+    loop {
+        let Edge { rhs, attr_list, .. } = edge;
+        attribute_lists.push(attr_list);
+        v.visit_edge_rhs_mut(rhs);
+        match &mut **rhs {
+            EdgeRHS::Edge(next) => edge = next,
+            _ => break,
+        }
+    }
+    for attribute_list in attribute_lists {
+        v.visit_attribute_list_mut(attribute_list);
+    }
+}
+
+pub fn visit_edge_lhs_mut<T, V>(v: &mut V, lhs: &mut EdgeLHS<T>)
+where
+    V: VisitMut<T> + ?Sized,
+{
+    match lhs {
+        EdgeLHS::Node(node) => v.visit_node_mut(node),
+        EdgeLHS::Subgraph(subgraph) => v.visit_subgraph_mut(subgraph),
+    }
+}
+
+/// Visits the endpoint directly following an edge operator, like
+/// [`visit::visit_edge_rhs`](crate::visit::visit_edge_rhs)
+pub fn visit_edge_rhs_mut<T, V>(v: &mut V, rhs: &mut EdgeRHS<T>)
+where
+    V: VisitMut<T> + ?Sized,
+{
+    match rhs {
+        EdgeRHS::Edge(edge) => v.visit_edge_lhs_mut(&mut edge.lhs),
+        EdgeRHS::Node(node) => v.visit_node_mut(node),
+        EdgeRHS::Subgraph(subgraph) => v.visit_subgraph_mut(subgraph),
+    }
+}
+
+pub fn visit_subgraph_mut<T, V>(v: &mut V, subgraph: &mut Subgraph<T>)
+where
+    V: VisitMut<T> + ?Sized,
+{
+    for statement in &mut subgraph.statements {
+        v.visit_statement_mut(statement);
+    }
+}
+
+pub fn visit_attribute_statement_mut<T, V>(v: &mut V, attribute: &mut AttributeStatement)
+where
+    V: VisitMut<T> + ?Sized,
+{
+    match attribute {
+        AttributeStatement::Graph(attribute_list)
+        | AttributeStatement::Node(attribute_list)
+        | AttributeStatement::Edge(attribute_list) => v.visit_attribute_list_mut(attribute_list),
+    }
+}
+
+pub fn visit_attribute_list_mut<T, V>(v: &mut V, attribute_list: &mut AttributeList)
+where
+    V: VisitMut<T> + ?Sized,
+{
+    for assignment in attribute_list.iter_mut().flatten() {
+        v.visit_assignment_mut(assignment);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VisitMut;
+    use crate::ast_nodes::{Directed, EdgeLHS, EdgeRHS, Graph, Node, Statement};
+    use crate::lex::PeekableLexer;
+    use crate::parse::Constructable;
+
+    struct Prefix;
+
+    impl VisitMut<Directed> for Prefix {
+        fn visit_node_mut(&mut self, node: &mut Node) {
+            node.id = format!("ns_{}", node.id);
+            super::visit_node_mut(self, node);
+        }
+    }
+
+    #[test]
+    fn visit_mut_rename_nodes_test() {
+        let test_str = "digraph G { a\n b -> { c } }";
+        let mut g = Graph::<Directed>::from_lexer(PeekableLexer::from(test_str))
+            .unwrap()
+            .0;
+        Prefix.visit_graph_mut(&mut g);
+
+        if let Statement::Node(node) = &g.statements[0] {
+            assert_eq!(node.id, "ns_a");
+        } else {
+            unreachable!()
+        }
+        if let Statement::Edge(edge) = &g.statements[1] {
+            if let EdgeLHS::Node(node) = &edge.lhs {
+                assert_eq!(node.id, "ns_b");
+            } else {
+                unreachable!()
+            }
+            if let EdgeRHS::Subgraph(subgraph) = &*edge.rhs {
+                assert!(matches!(&subgraph.statements[0], Statement::Node(n) if n.id == "ns_c"));
+            } else {
+                unreachable!()
+            }
+        } else {
+            unreachable!()
+        }
+    }
+
+    /// Marks the endpoints following an edge operator
+    struct MarkHeads;
+
+    impl VisitMut<Directed> for MarkHeads {
+        fn visit_edge_rhs_mut(&mut self, rhs: &mut EdgeRHS<Directed>) {
+            match rhs {
+                EdgeRHS::Edge(edge) => {
+                    if let EdgeLHS::Node(node) = &mut edge.lhs {
+                        node.id = format!("head_{}", node.id);
+                    }
+                }
+                EdgeRHS::Node(node) => node.id = format!("head_{}", node.id),
+                EdgeRHS::Subgraph(_) => {}
+            }
+        }
+    }
+
+    #[test]
+    fn visit_mut_edge_chain_heads_test() {
+        let test_str = "digraph G { a -> b -> c }";
+        let mut g = Graph::<Directed>::from_lexer(PeekableLexer::from(test_str))
+            .unwrap()
+            .0;
+        MarkHeads.visit_graph_mut(&mut g);
+
+        let mut ids = vec![];
+        if let Statement::Edge(edge) = &g.statements[0] {
+            if let EdgeLHS::Node(node) = &edge.lhs {
+                ids.push(node.id.as_str());
+            }
+            let mut rhs = &*edge.rhs;
+            while let EdgeRHS::Edge(next) = rhs {
+                if let EdgeLHS::Node(node) = &next.lhs {
+                    ids.push(node.id.as_str());
+                }
+                rhs = &*next.rhs;
+            }
+            if let EdgeRHS::Node(node) = rhs {
+                ids.push(node.id.as_str());
+            }
+        }
+        assert_eq!(ids, vec!["a", "head_b", "head_c"]);
+    }
+}